@@ -1,5 +1,5 @@
 mod allocation;
 mod app_state;
 
-pub use allocation::Allocation;
+pub use allocation::{Allocation, DaoAllocation};
 pub use app_state::AppState;
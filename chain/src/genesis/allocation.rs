@@ -75,3 +75,38 @@ impl Allocation {
 impl DomainType for Allocation {
     type Proto = pb::genesis_app_state::Allocation;
 }
+
+/// A genesis allocation deposited into the DAO, rather than into a note.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::genesis_app_state::DaoAllocation",
+    into = "pb::genesis_app_state::DaoAllocation"
+)]
+pub struct DaoAllocation {
+    pub amount: u64,
+    pub denom: String,
+}
+
+impl From<DaoAllocation> for pb::genesis_app_state::DaoAllocation {
+    fn from(a: DaoAllocation) -> Self {
+        pb::genesis_app_state::DaoAllocation {
+            amount: a.amount,
+            denom: a.denom,
+        }
+    }
+}
+
+impl TryFrom<pb::genesis_app_state::DaoAllocation> for DaoAllocation {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::genesis_app_state::DaoAllocation) -> Result<Self, Self::Error> {
+        Ok(DaoAllocation {
+            amount: msg.amount,
+            denom: msg.denom,
+        })
+    }
+}
+
+impl DomainType for DaoAllocation {
+    type Proto = pb::genesis_app_state::DaoAllocation;
+}
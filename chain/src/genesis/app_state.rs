@@ -1,7 +1,7 @@
 use penumbra_proto::{core::chain::v1alpha1 as pb, core::stake::v1alpha1 as pb_stake, DomainType};
 use serde::{Deserialize, Serialize};
 
-use super::Allocation;
+use super::{Allocation, DaoAllocation};
 use crate::params::ChainParameters;
 
 /// The application state at genesis.
@@ -14,6 +14,8 @@ pub struct AppState {
     pub validators: Vec<pb_stake::Validator>,
     /// The initial token allocations.
     pub allocations: Vec<Allocation>,
+    /// The initial balance of the DAO.
+    pub dao_allocations: Vec<DaoAllocation>,
 }
 
 impl Default for AppState {
@@ -39,6 +41,7 @@ impl Default for AppState {
                     address: crate::test_keys::ADDRESS_1_STR.parse().unwrap(),
                 },
             ],
+            dao_allocations: Default::default(),
         }
     }
 }
@@ -48,6 +51,7 @@ impl From<AppState> for pb::GenesisAppState {
         pb::GenesisAppState {
             validators: a.validators.into_iter().map(Into::into).collect(),
             allocations: a.allocations.into_iter().map(Into::into).collect(),
+            dao_allocations: a.dao_allocations.into_iter().map(Into::into).collect(),
             chain_params: Some(a.chain_params.into()),
         }
     }
@@ -70,6 +74,11 @@ impl TryFrom<pb::GenesisAppState> for AppState {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            dao_allocations: msg
+                .dao_allocations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -9,6 +9,8 @@ pub enum NoteSource {
     Unknown,
     Genesis,
     FundingStreamReward { epoch_index: u64 },
+    DaoOutput { height: u64 },
+    Ics20Transfer { sequence: u64 },
 }
 
 impl Default for NoteSource {
@@ -35,6 +37,18 @@ impl NoteSource {
                 bytes[24..].copy_from_slice(&epoch_index.to_le_bytes());
                 bytes
             }
+            Self::DaoOutput { height } => {
+                let mut bytes = [0u8; 32];
+                bytes[CODE_INDEX] = 3;
+                bytes[24..].copy_from_slice(&height.to_le_bytes());
+                bytes
+            }
+            Self::Ics20Transfer { sequence } => {
+                let mut bytes = [0u8; 32];
+                bytes[CODE_INDEX] = 4;
+                bytes[24..].copy_from_slice(&sequence.to_le_bytes());
+                bytes
+            }
         }
     }
 }
@@ -53,6 +67,17 @@ impl TryFrom<[u8; 32]> for NoteSource {
                         u64::from_le_bytes(epoch_bytes.try_into().expect("slice is of length 8"));
                     Ok(Self::FundingStreamReward { epoch_index })
                 }
+                (3, height_bytes) => {
+                    let height =
+                        u64::from_le_bytes(height_bytes.try_into().expect("slice is of length 8"));
+                    Ok(Self::DaoOutput { height })
+                }
+                (4, sequence_bytes) => {
+                    let sequence = u64::from_le_bytes(
+                        sequence_bytes.try_into().expect("slice is of length 8"),
+                    );
+                    Ok(Self::Ics20Transfer { sequence })
+                }
                 (code, data) => Err(anyhow!(
                     "unknown note source with code {} and data {:?}",
                     code,
@@ -108,6 +133,12 @@ impl std::fmt::Debug for NoteSource {
                 "NoteSource::FundingStreamReward({})",
                 epoch_index
             )),
+            NoteSource::DaoOutput { height } => {
+                f.write_fmt(format_args!("NoteSource::DaoOutput({})", height))
+            }
+            NoteSource::Ics20Transfer { sequence } => {
+                f.write_fmt(format_args!("NoteSource::Ics20Transfer({})", sequence))
+            }
         }
     }
 }
@@ -9,6 +9,7 @@ use penumbra_transaction::{Action, Transaction};
 use super::ActionHandler;
 
 mod delegate;
+mod delegator_vote;
 mod ibc_action;
 mod ics20;
 mod output;
@@ -31,6 +32,7 @@ impl ActionHandler for Action {
             Action::UndelegateClaim(action) => action.check_stateless(context),
             Action::ValidatorDefinition(action) => action.check_stateless(context),
             Action::ValidatorVote(action) => action.check_stateless(context),
+            Action::DelegatorVote(action) => action.check_stateless(context),
             Action::PositionClose(action) => action.check_stateless(context),
             Action::PositionOpen(action) => action.check_stateless(context),
             Action::PositionRewardClaim(action) => action.check_stateless(context),
@@ -55,6 +57,7 @@ impl ActionHandler for Action {
             Action::UndelegateClaim(action) => action.check_stateful(state).await,
             Action::ValidatorDefinition(action) => action.check_stateful(state).await,
             Action::ValidatorVote(action) => action.check_stateful(state).await,
+            Action::DelegatorVote(action) => action.check_stateful(state).await,
             Action::PositionClose(action) => action.check_stateful(state).await,
             Action::PositionOpen(action) => action.check_stateful(state).await,
             Action::PositionRewardClaim(action) => action.check_stateful(state).await,
@@ -86,6 +89,7 @@ impl ActionHandler for Action {
             Action::UndelegateClaim(action) => action.execute(state).await,
             Action::ValidatorDefinition(action) => action.execute(state).await,
             Action::ValidatorVote(action) => action.execute(state).await,
+            Action::DelegatorVote(action) => action.execute(state).await,
             Action::PositionClose(action) => action.execute(state).await,
             Action::PositionOpen(action) => action.execute(state).await,
             Action::PositionRewardClaim(action) => action.execute(state).await,
@@ -2,36 +2,54 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_crypto::dex::lp::{position, LpNft};
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::PositionClose, Transaction};
 use tracing::instrument;
 
-use crate::action_handler::ActionHandler;
+use crate::{
+    action_handler::ActionHandler,
+    dex::{PositionManager, PositionRead},
+    shielded_pool::SupplyWrite,
+};
 
 #[async_trait]
 /// Debits an opened position NFT and credits a closed position NFT.
 impl ActionHandler for PositionClose {
     #[instrument(name = "position_close", skip(self, _context))]
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+        // There are no stateless checks specific to this action.
+        Ok(())
     }
 
-    #[instrument(name = "position_close", skip(self, _state))]
-    async fn check_stateful<S: StateRead>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_close", skip(self, state))]
+    async fn check_stateful<S: StateRead>(&self, state: Arc<S>) -> Result<()> {
+        let metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("could not find position {}", self.position_id))?;
+
+        if metadata.state != position::State::Opened {
+            return Err(anyhow::anyhow!(
+                "can only close opened positions, but position {} is {}",
+                self.position_id,
+                metadata.state
+            ));
+        }
+
+        Ok(())
     }
 
-    #[instrument(name = "position_close", skip(self, _state))]
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_close", skip(self, state))]
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The state is checked again here, in case another action in the
+        // same transaction already changed it.
+        state.position_close(&self.position_id).await?;
+
+        // Register the LP NFT's denom, so clients can look it up later.
+        let lpnft = LpNft::new(self.position_id, position::State::Closed);
+        state.register_denom(&lpnft.denom()).await?;
+
+        Ok(())
     }
 }
@@ -6,33 +6,43 @@ use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::PositionOpen, Transaction};
 use tracing::instrument;
 
-use crate::action_handler::ActionHandler;
+use crate::{
+    action_handler::ActionHandler,
+    dex::{PositionManager, PositionRead},
+    shielded_pool::SupplyWrite,
+};
 
 #[async_trait]
 /// Debits the initial reserves and credits an opened position NFT.
 impl ActionHandler for PositionOpen {
     #[instrument(name = "position_open", skip(self, _context))]
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+        // We limit the sizes of reserve amounts and trading function
+        // coefficients, to leave headroom for intermediate calculations.
+        self.initial_reserves.check_bounds()?;
+        self.position.check_bounds()?;
+
+        if self.initial_reserves.r1 == 0u64.into() && self.initial_reserves.r2 == 0u64.into() {
+            return Err(anyhow::anyhow!("cannot open a position with no reserves"));
+        }
+
+        Ok(())
     }
 
-    #[instrument(name = "position_open", skip(self, _state))]
-    async fn check_stateful<S: StateRead>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_open", skip(self, state))]
+    async fn check_stateful<S: StateRead>(&self, state: Arc<S>) -> Result<()> {
+        // Check that the position's nonce hasn't been used, to ensure its ID is unique.
+        state.check_nonce_unused(&self.position).await
     }
 
-    #[instrument(name = "position_open", skip(self, _state))]
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // let position = self.position;
-        // let initial_reserves = self.initial_reserves;
-        // let lpnft = state.position_open(position, initial_reserves).await?;
-        // TODO: implement
+    #[instrument(name = "position_open", skip(self, state))]
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let lpnft = state
+            .position_open(self.position.clone(), self.initial_reserves.clone())
+            .await?;
+
+        // Register the LP NFT's denom, so clients can look it up later.
+        state.register_denom(&lpnft.denom()).await?;
 
         Ok(())
     }
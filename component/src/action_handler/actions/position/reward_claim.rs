@@ -2,36 +2,69 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_crypto::{
+    dex::lp::{position, LpNft},
+    Balance, Fr, Zero,
+};
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::PositionRewardClaim, Transaction};
 use tracing::instrument;
 
-use crate::action_handler::ActionHandler;
+use crate::{
+    action_handler::ActionHandler,
+    dex::{PositionManager, PositionRead},
+    shielded_pool::SupplyWrite,
+};
 
 #[async_trait]
 /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
 impl ActionHandler for PositionRewardClaim {
     #[instrument(name = "position_reward_claim", skip(self, _context))]
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+        // There are no stateless checks specific to this action.
+        Ok(())
     }
 
-    #[instrument(name = "position_reward_claim", skip(self, _state))]
-    async fn check_stateful<S: StateRead>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_reward_claim", skip(self, state))]
+    async fn check_stateful<S: StateRead>(&self, state: Arc<S>) -> Result<()> {
+        let metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("could not find position {}", self.position_id))?;
+
+        if metadata.state != position::State::Withdrawn {
+            return Err(anyhow::anyhow!(
+                "can only claim rewards for withdrawn positions, but position {} is {}",
+                self.position_id,
+                metadata.state
+            ));
+        }
+
+        // There are no liquidity incentives yet, so the rewards for every
+        // position are zero.
+        // TODO: recompute the position's actual rewards once incentives exist.
+        let expected_rewards_commitment = Balance::zero().commit(Fr::zero());
+
+        if self.rewards_commitment != expected_rewards_commitment {
+            return Err(anyhow::anyhow!(
+                "rewards commitment for position {} does not match its rewards",
+                self.position_id,
+            ));
+        }
+
+        Ok(())
     }
 
-    #[instrument(name = "position_reward_claim", skip(self, _state))]
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_reward_claim", skip(self, state))]
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The state is checked again here, in case another action in the
+        // same transaction already claimed this position's rewards.
+        state.position_reward_claim(&self.position_id).await?;
+
+        // Register the LP NFT's denom, so clients can look it up later.
+        let lpnft = LpNft::new(self.position_id, position::State::Claimed);
+        state.register_denom(&lpnft.denom()).await?;
+
+        Ok(())
     }
 }
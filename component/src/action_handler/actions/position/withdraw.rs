@@ -2,36 +2,73 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use penumbra_crypto::{
+    dex::lp::{position, LpNft},
+    Fr, Zero,
+};
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::PositionWithdraw, Transaction};
 use tracing::instrument;
 
-use crate::action_handler::ActionHandler;
+use crate::{
+    action_handler::ActionHandler,
+    dex::{PositionManager, PositionRead},
+    shielded_pool::SupplyWrite,
+};
 
 #[async_trait]
 /// Debits a closed position NFT and credits a withdrawn position NFT and the final reserves.
 impl ActionHandler for PositionWithdraw {
     #[instrument(name = "position_withdraw", skip(self, _context))]
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+        // There are no stateless checks specific to this action.
+        Ok(())
     }
 
-    #[instrument(name = "position_withdraw", skip(self, _state))]
-    async fn check_stateful<S: StateRead>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_withdraw", skip(self, state))]
+    async fn check_stateful<S: StateRead>(&self, state: Arc<S>) -> Result<()> {
+        let metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("could not find position {}", self.position_id))?;
+
+        if metadata.state != position::State::Closed {
+            return Err(anyhow::anyhow!(
+                "can only withdraw from closed positions, but position {} is {}",
+                self.position_id,
+                metadata.state
+            ));
+        }
+
+        // The withdrawal's contribution to the transaction's balance must be
+        // exactly the position's final reserves.  Since the commitment is
+        // transparent, we can check it by recomputing it from the chain state.
+        let expected_reserves_commitment = metadata
+            .reserves
+            .balance(&metadata.position.phi.pair)
+            .commit(Fr::zero());
+
+        if self.reserves_commitment != expected_reserves_commitment {
+            return Err(anyhow::anyhow!(
+                "reserves commitment for position {} does not match its final reserves",
+                self.position_id,
+            ));
+        }
+
+        Ok(())
     }
 
-    #[instrument(name = "position_withdraw", skip(self, _state))]
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp actions not supported yet"))
+    #[instrument(name = "position_withdraw", skip(self, state))]
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The state is checked again here, in case another action in the
+        // same transaction already withdrew this position.
+        let reserves = state.position_withdraw(&self.position_id).await?;
+        tracing::debug!(?reserves, "withdrew position reserves");
+
+        // Register the LP NFT's denom, so clients can look it up later.
+        let lpnft = LpNft::new(self.position_id, position::State::Withdrawn);
+        state.register_denom(&lpnft.denom()).await?;
+
+        Ok(())
     }
 }
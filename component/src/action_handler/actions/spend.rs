@@ -51,7 +51,7 @@ impl ActionHandler for Spend {
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
         let source = state.object_get("source").unwrap_or_default();
 
-        state.spend_nullifier(self.body.nullifier, source).await;
+        state.spend_nullifier(self.body.nullifier, source).await?;
 
         Ok(())
     }
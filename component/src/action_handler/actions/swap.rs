@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use crate::{
+    dex::{StateReadExt as _, StateWriteExt as _},
     shielded_pool::NoteManager,
-    stubdex::{StateReadExt as _, StateWriteExt as _},
 };
 use anyhow::Result;
 
@@ -10,6 +10,7 @@ use async_trait::async_trait;
 
 use penumbra_chain::sync::StatePayload;
 use penumbra_crypto::MockFlowCiphertext;
+use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::Swap, IsAction, Transaction};
 use tracing::instrument;
@@ -21,9 +22,10 @@ impl ActionHandler for Swap {
     #[instrument(name = "swap", skip(self, _context))]
     async fn check_stateless(&self, _context: Arc<Transaction>) -> Result<()> {
         self.proof.verify(
-            self.body.fee_commitment,
-            self.body.payload.commitment,
+            &SWAP_PROOF_VERIFICATION_KEY,
             self.balance_commitment(),
+            self.body.payload.commitment,
+            self.body.fee_commitment,
         )?;
 
         Ok(())
@@ -3,14 +3,15 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::{sync::StatePayload, StateReadExt as _};
+use penumbra_proof_params::SWAPCLAIM_PROOF_VERIFICATION_KEY;
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::{action::SwapClaim, Transaction};
 use tracing::instrument;
 
 use crate::{
     action_handler::ActionHandler,
+    dex::StateReadExt as _,
     shielded_pool::{NoteManager, StateReadExt as _},
-    stubdex::StateReadExt as _,
 };
 
 #[async_trait]
@@ -19,6 +20,7 @@ impl ActionHandler for SwapClaim {
     async fn check_stateless(&self, context: Arc<Transaction>) -> Result<()> {
         self.proof
             .verify(
+                &SWAPCLAIM_PROOF_VERIFICATION_KEY,
                 context.anchor,
                 self.body.nullifier,
                 self.body.output_data,
@@ -86,7 +88,7 @@ impl ActionHandler for SwapClaim {
             ))
             .await;
 
-        state.spend_nullifier(self.body.nullifier, source).await;
+        state.spend_nullifier(self.body.nullifier, source).await?;
 
         Ok(())
     }
@@ -15,7 +15,7 @@ use super::ActionHandler;
 mod stateful;
 mod stateless;
 
-use stateless::{no_duplicate_nullifiers, valid_binding_signature};
+use stateless::{no_duplicate_nullifiers, no_duplicate_votes, valid_binding_signature};
 
 #[async_trait]
 impl ActionHandler for Transaction {
@@ -24,6 +24,7 @@ impl ActionHandler for Transaction {
 
         valid_binding_signature(self)?;
         no_duplicate_nullifiers(self)?;
+        no_duplicate_votes(self)?;
 
         // TODO: these can all be parallel tasks
         for action in self.actions() {
@@ -33,3 +33,21 @@ pub(super) fn no_duplicate_nullifiers(tx: &Transaction) -> Result<()> {
 
     Ok(())
 }
+
+pub(super) fn no_duplicate_votes(tx: &Transaction) -> Result<()> {
+    // Disallow multiple `DelegatorVote`s on the same proposal with the same `Nullifier`.
+    // As with spends, the check against the chain state can't catch these, because the
+    // nullifiers are only recorded as having voted when the transaction is executed.
+    let mut voted_nullifiers = BTreeSet::new();
+    for vote in tx.delegator_votes() {
+        if !voted_nullifiers.insert((vote.body.proposal, vote.body.nullifier)) {
+            return Err(anyhow::anyhow!(
+                "Duplicate nullifier {} voting on proposal {} in transaction",
+                vote.body.nullifier,
+                vote.body.proposal
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -16,7 +16,6 @@ use crate::governance::Governance;
 use crate::ibc::IBCComponent;
 use crate::shielded_pool::ShieldedPool;
 use crate::stake::component::{Staking, ValidatorUpdates};
-use crate::stubdex::StubDex;
 use crate::Component;
 
 pub mod state_key;
@@ -61,7 +60,6 @@ impl App {
         Staking::init_chain(&mut state_tx, app_state).await;
         IBCComponent::init_chain(&mut state_tx, app_state).await;
         Dex::init_chain(&mut state_tx, app_state).await;
-        StubDex::init_chain(&mut state_tx, app_state).await;
         Governance::init_chain(&mut state_tx, app_state).await;
         // Shielded pool always executes last.
         ShieldedPool::init_chain(&mut state_tx, app_state).await;
@@ -86,7 +84,6 @@ impl App {
 
         Staking::begin_block(&mut state_tx, begin_block).await;
         IBCComponent::begin_block(&mut state_tx, begin_block).await;
-        StubDex::begin_block(&mut state_tx, begin_block).await;
         Dex::begin_block(&mut state_tx, begin_block).await;
         // Temporarily disabled until #1938 is resolved.
         // Governance::begin_block(&mut state_tx, begin_block).await;
@@ -134,10 +131,8 @@ impl App {
 
         Staking::end_block(&mut state_tx, end_block).await;
         IBCComponent::end_block(&mut state_tx, end_block).await;
-        StubDex::end_block(&mut state_tx, end_block).await;
         Dex::end_block(&mut state_tx, end_block).await;
-        // Temporarily disabled until #1938 is resolved.
-        // Governance::end_block(&mut state_tx, end_block).await;
+        Governance::end_block(&mut state_tx, end_block).await;
 
         // Shielded pool always executes last.
         ShieldedPool::end_block(&mut state_tx, end_block).await;
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_crypto::{
+    dex::{
+        execution::{Path, SwapExecution, Trade},
+        lp::{position, BareTradingFunction, Reserves, TradingFunction},
+        BatchSwapOutputData, DirectedTradingPair, TradingPair,
+    },
+    Amount,
+};
+
+use super::{router::Router, PositionManager, PositionRead, StateWriteExt};
+
+/// Position reserves changed by fills which haven't been written to the state yet.
+type Staged = BTreeMap<position::Id, (position::Metadata, Reserves)>;
+
+/// Clears batched swaps against the liquidity positions in the chain state.
+#[async_trait]
+pub(super) trait BatchSwap: StateWriteExt {
+    /// Executes the batch of swaps `(delta_1, delta_2)` in `trading_pair`,
+    /// routing each direction along the path through the position graph
+    /// which gives the most output.
+    ///
+    /// A batch is either filled completely or not at all: if either direction
+    /// can't be filled by the available liquidity, no position is touched and
+    /// the returned output data is marked as unsuccessful, so that swappers
+    /// can claim back their inputs. Otherwise, the execution of each direction
+    /// is recorded for queries.
+    async fn execute_batch_swap(
+        &mut self,
+        height: u64,
+        trading_pair: TradingPair,
+        (delta_1, delta_2): (u64, u64),
+    ) -> Result<BatchSwapOutputData> {
+        // Fills are staged here, so that the second direction trades against
+        // the reserves left by the first, and so that nothing is written
+        // unless the whole batch clears.
+        let mut staged = Staged::new();
+
+        let pair_12 = DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2());
+        let pair_21 = DirectedTradingPair::new(trading_pair.asset_2(), trading_pair.asset_1());
+        let fill_12 = self.fill_direction(pair_12, delta_1, &mut staged).await?;
+        let fill_21 = self.fill_direction(pair_21, delta_2, &mut staged).await?;
+
+        let (lambda_1, lambda_2, success) = match (fill_21, fill_12) {
+            (Some((lambda_1, execution_21)), Some((lambda_2, execution_12))) => {
+                for (metadata, reserves) in staged.into_values() {
+                    self.position_update_reserves(metadata, reserves);
+                }
+                for (pair, execution) in [(pair_12, execution_12), (pair_21, execution_21)] {
+                    if !execution.trades.is_empty() {
+                        self.set_swap_execution(height, &pair, execution);
+                    }
+                }
+                (lambda_1, lambda_2, true)
+            }
+            _ => (0, 0, false),
+        };
+
+        Ok(BatchSwapOutputData {
+            height,
+            trading_pair,
+            delta_1,
+            delta_2,
+            lambda_1,
+            lambda_2,
+            success,
+        })
+    }
+
+    /// Fills `input` of `pair.start` for `pair.end` along the candidate path
+    /// giving the most output, preferring shorter paths on ties, and records
+    /// the new reserves of the positions it trades against in `staged`.
+    ///
+    /// Returns the total output and the trades made along each hop, or `None`
+    /// if no path can fill the input completely.
+    async fn fill_direction(
+        &self,
+        pair: DirectedTradingPair,
+        input: u64,
+        staged: &mut Staged,
+    ) -> Result<Option<(u64, SwapExecution)>> {
+        if input == 0 {
+            return Ok(Some((0, SwapExecution::default())));
+        }
+
+        let mut best: Option<(Amount, Staged, Vec<Trade>)> = None;
+        for path in self.candidate_paths(&pair).await? {
+            let mut trial = staged.clone();
+            if let Some((output, trades)) = self.fill_path(&path, input.into(), &mut trial).await? {
+                tracing::debug!(route = ?path.route(), ?output, "simulated fill along path");
+                if best
+                    .as_ref()
+                    .map_or(true, |(best_output, _, _)| output > *best_output)
+                {
+                    best = Some((output, trial, trades));
+                }
+            }
+        }
+
+        let (output, trial, trades) = match best {
+            Some(best) => best,
+            None => {
+                tracing::debug!(?pair, ?input, "not enough liquidity to fill batch");
+                return Ok(None);
+            }
+        };
+        let output = match u64::try_from(u128::from(output)) {
+            Ok(output) => output,
+            Err(_) => return Ok(None),
+        };
+        *staged = trial;
+        Ok(Some((output, SwapExecution { trades })))
+    }
+
+    /// Fills `input` along each hop of `path` in turn, each hop trading the
+    /// output of the previous one.
+    ///
+    /// Each hop is recorded with the effective price it was filled at, as a
+    /// fee-free trading function giving out `end_amount` of the hop's end
+    /// asset for `start_amount` of its start asset, oriented like
+    /// [`directed_phi`]. The fees paid are already reflected in the amounts.
+    async fn fill_path(
+        &self,
+        path: &Path,
+        input: Amount,
+        staged: &mut Staged,
+    ) -> Result<Option<(Amount, Vec<Trade>)>> {
+        let mut amount = input;
+        let mut trades = Vec::with_capacity(path.hops());
+        for hop in path.route().windows(2) {
+            let hop = DirectedTradingPair::new(hop[0], hop[1]);
+            let output = match self.fill_hop(hop, amount, staged).await? {
+                Some(output) => output,
+                None => return Ok(None),
+            };
+            let effective_phi = TradingFunction {
+                component: BareTradingFunction::new(0, amount, output),
+                pair: hop.to_canonical(),
+            };
+            trades.push(Trade {
+                path: Path::new(hop.start, hop.end, effective_phi)?,
+                start_amount: amount,
+                end_amount: output,
+            });
+            amount = output;
+        }
+        Ok(Some((amount, trades)))
+    }
+
+    /// Fills `input` of `pair.start` against the positions giving out
+    /// `pair.end`, best price first, recording the new reserves of each
+    /// position in `staged`.
+    ///
+    /// Returns the total output, or `None` if the input couldn't be filled
+    /// completely.
+    async fn fill_hop(
+        &self,
+        pair: DirectedTradingPair,
+        input: Amount,
+        staged: &mut Staged,
+    ) -> Result<Option<Amount>> {
+        // Start from the indexed positions, with any fills staged so far
+        // applied, and add staged positions in this pair which gained
+        // reserves of the output asset from earlier fills.
+        let mut candidates = BTreeMap::new();
+        for metadata in self.positions_by_price(&pair).await? {
+            let id = metadata.position.id();
+            let reserves = staged
+                .get(&id)
+                .map(|(_, reserves)| reserves.clone())
+                .unwrap_or_else(|| metadata.reserves.clone());
+            candidates.insert(id, (metadata, reserves));
+        }
+        for (id, (metadata, reserves)) in staged.iter() {
+            if metadata.position.phi.pair == pair.to_canonical()
+                && output_reserves(&pair, metadata, reserves) != 0u64.into()
+            {
+                candidates
+                    .entry(*id)
+                    .or_insert_with(|| (metadata.clone(), reserves.clone()));
+            }
+        }
+
+        let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+        candidates.sort_by(|(_, (a, _)), (_, (b, _))| {
+            directed_phi(&pair, b).cmp_fill_rate(&directed_phi(&pair, a))
+        });
+
+        let mut remaining = input;
+        let mut output = Amount::from(0u64);
+        for (id, (metadata, mut reserves)) in candidates {
+            if remaining == 0u64.into() {
+                break;
+            }
+
+            let phi = directed_phi(&pair, &metadata);
+            let (consumed, filled) =
+                phi.fill(remaining, output_reserves(&pair, &metadata, &reserves));
+            if filled == 0u64.into() {
+                // Don't take input without giving anything out for it.
+                continue;
+            }
+            tracing::debug!(?id, ?consumed, ?filled, "filled against position");
+
+            if pair.start == metadata.position.phi.pair.asset_1() {
+                reserves.r1 = reserves.r1 + consumed;
+                reserves.r2 = reserves.r2 - filled;
+            } else {
+                reserves.r2 = reserves.r2 + consumed;
+                reserves.r1 = reserves.r1 - filled;
+            }
+            remaining = remaining - consumed;
+            output = output + filled;
+            staged.insert(id, (metadata, reserves));
+        }
+
+        if remaining == 0u64.into() && output != 0u64.into() {
+            Ok(Some(output))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<T: StateWriteExt> BatchSwap for T {}
+
+/// The trading function of a position, oriented so that it gives out `pair.end`
+/// (as asset 1) in exchange for `pair.start` (as asset 2).
+pub(super) fn directed_phi(
+    pair: &DirectedTradingPair,
+    metadata: &position::Metadata,
+) -> BareTradingFunction {
+    let phi = &metadata.position.phi;
+    if pair.end == phi.pair.asset_1() {
+        phi.component.clone()
+    } else {
+        phi.component.flip()
+    }
+}
+
+/// The reserves of `pair.end` a position has available to give out.
+fn output_reserves(
+    pair: &DirectedTradingPair,
+    metadata: &position::Metadata,
+    reserves: &Reserves,
+) -> Amount {
+    if pair.end == metadata.position.phi.pair.asset_1() {
+        reserves.r1
+    } else {
+        reserves.r2
+    }
+}
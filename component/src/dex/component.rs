@@ -1,10 +1,21 @@
+use std::collections::BTreeMap;
+
+use crate::shielded_pool::{StateReadExt as _, StateWriteExt as _};
 use crate::Component;
+use anyhow::Result;
 use async_trait::async_trait;
 use penumbra_chain::genesis;
+use penumbra_crypto::{
+    dex::{execution::SwapExecution, BatchSwapOutputData, DirectedTradingPair, TradingPair},
+    SwapFlow,
+};
+use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_storage::{StateRead, StateWrite};
 use tendermint::abci;
 use tracing::instrument;
 
+use super::{batch_swap::BatchSwap, state_key};
+
 pub struct Dex {}
 
 #[async_trait]
@@ -15,18 +26,96 @@ impl Component for Dex {
     #[instrument(name = "dex", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite>(_state: S, _begin_block: &abci::request::BeginBlock) {}
 
-    #[instrument(name = "dex", skip(_state, _end_block))]
-    async fn end_block<S: StateWrite>(_state: S, _end_block: &abci::request::EndBlock) {}
+    #[instrument(name = "dex", skip(state, end_block))]
+    async fn end_block<S: StateWrite>(mut state: S, end_block: &abci::request::EndBlock) {
+        // For each batch swap during the block, clear it against the open
+        // liquidity positions and record the output data.
+        for (trading_pair, swap_flows) in state.swap_flows() {
+            let (delta_1, delta_2) = (swap_flows.0.mock_decrypt(), swap_flows.1.mock_decrypt());
+            tracing::debug!(?delta_1, ?delta_2, ?trading_pair);
+
+            let output_data = state
+                .execute_batch_swap(
+                    end_block.height.try_into().unwrap(),
+                    trading_pair,
+                    (delta_1, delta_2),
+                )
+                .await
+                .expect("batch swap execution must succeed");
+            tracing::debug!(?output_data);
+            state.set_output_data(output_data);
+        }
+    }
 }
 
 /// Extension trait providing read access to dex data.
 #[async_trait]
-pub trait StateReadExt: StateRead {}
+pub trait StateReadExt: StateRead {
+    async fn output_data(
+        &self,
+        height: u64,
+        trading_pair: TradingPair,
+    ) -> Result<Option<BatchSwapOutputData>> {
+        self.get(&state_key::output_data(height, trading_pair))
+            .await
+    }
+
+    /// Gets the execution of one direction of the batch swap in a trading
+    /// pair at the given height, if it was filled.
+    async fn swap_execution(
+        &self,
+        height: u64,
+        trading_pair: &DirectedTradingPair,
+    ) -> Result<Option<SwapExecution>> {
+        self.get(&state_key::swap_execution(height, trading_pair))
+            .await
+    }
+
+    // Get the swap flow for the given trading pair accumulated in this block so far.
+    fn swap_flow(&self, pair: &TradingPair) -> SwapFlow {
+        self.swap_flows().get(pair).cloned().unwrap_or_default()
+    }
+
+    fn swap_flows(&self) -> BTreeMap<TradingPair, SwapFlow> {
+        self.object_get::<BTreeMap<TradingPair, SwapFlow>>(state_key::swap_flows())
+            .unwrap_or_default()
+    }
+}
 
 impl<T: StateRead> StateReadExt for T {}
 
 /// Extension trait providing write access to dex data.
 #[async_trait]
-pub trait StateWriteExt: StateWrite + StateReadExt {}
+pub trait StateWriteExt: StateWrite + StateReadExt {
+    fn set_output_data(&mut self, output_data: BatchSwapOutputData) {
+        // Write the output data to the state under a known key, for querying, ...
+        let height = output_data.height;
+        let trading_pair = output_data.trading_pair;
+        self.put(state_key::output_data(height, trading_pair), output_data);
+        // ... and also add it to the compact block to be pushed out to clients.
+        let mut compact_block = self.stub_compact_block();
+        compact_block.swap_outputs.insert(trading_pair, output_data);
+        self.stub_put_compact_block(compact_block);
+    }
+
+    fn set_swap_execution(
+        &mut self,
+        height: u64,
+        trading_pair: &DirectedTradingPair,
+        swap_execution: SwapExecution,
+    ) {
+        self.put(
+            state_key::swap_execution(height, trading_pair),
+            swap_execution,
+        );
+    }
+
+    fn put_swap_flow(&mut self, trading_pair: &TradingPair, swap_flow: SwapFlow) {
+        // TODO: replace with IM struct later
+        let mut swap_flows = self.swap_flows();
+        swap_flows.insert(*trading_pair, swap_flow);
+        self.object_put(state_key::swap_flows(), swap_flows)
+    }
+}
 
 impl<T: StateWrite> StateWriteExt for T {}
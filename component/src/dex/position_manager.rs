@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_crypto::dex::{
     lp::{
         position::{self, Position},
@@ -29,6 +30,33 @@ pub trait PositionRead: StateRead {
         }
         Ok(())
     }
+
+    /// Returns the opened positions that can be traded against in the given
+    /// direction, that is, those with reserves of `pair.end` to give out in
+    /// exchange for `pair.start`, in order of effective price.
+    async fn positions_by_price(
+        &self,
+        pair: &DirectedTradingPair,
+    ) -> Result<Vec<position::Metadata>> {
+        let prefix = state_key::internal::price_index::prefix(pair);
+        let ids = self
+            .nonconsensus_prefix_raw(&prefix)
+            .map(|entry| position::Id::decode(entry?.1.as_slice()))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut positions = Vec::with_capacity(ids.len());
+        for id in ids {
+            let metadata = self
+                .position_by_id(&id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("indexed position {} not found", id))?;
+            if metadata.state == position::State::Opened {
+                positions.push(metadata);
+            }
+        }
+        Ok(positions)
+    }
 }
 impl<T: StateRead + ?Sized> PositionRead for T {}
 
@@ -81,14 +109,58 @@ pub trait PositionManager: StateWrite + PositionRead {
         Ok(())
     }
 
-    /// Marks an existing closed position as withdrawn in the chain state.
-    async fn position_withdraw(&mut self, _id: &position::Id) {
-        todo!()
+    /// Records the new reserves of an opened position after it has been traded
+    /// against, re-indexing it for the directions it can now trade in.
+    fn position_update_reserves(&mut self, mut metadata: position::Metadata, reserves: Reserves) {
+        let id = metadata.position.id();
+        self.deindex_position(&metadata.position);
+        metadata.reserves = reserves;
+        self.index_position(&metadata);
+        self.put_position(&id, metadata);
+    }
+
+    /// Marks an existing closed position as withdrawn in the chain state,
+    /// returning the final reserves that were withdrawn from it.
+    async fn position_withdraw(&mut self, id: &position::Id) -> Result<Reserves> {
+        let mut metadata = self
+            .position_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("could not find position with id {}", id))?;
+        if metadata.state != position::State::Closed {
+            return Err(anyhow::anyhow!(
+                "attempted to withdraw position {} with state {}",
+                id,
+                metadata.state
+            ));
+        }
+
+        // The reserves leave the position, to be recorded in the shielded
+        // pool by the outputs of the withdrawing transaction.
+        let reserves = std::mem::replace(&mut metadata.reserves, Reserves::zero());
+        metadata.state = position::State::Withdrawn;
+        self.put_position(id, metadata);
+
+        Ok(reserves)
     }
 
     /// Marks an existing withdrawn position as claimed in the chain state.
-    async fn position_reward_claim(&mut self, _id: &position::Id) {
-        todo!()
+    async fn position_reward_claim(&mut self, id: &position::Id) -> Result<()> {
+        let mut metadata = self
+            .position_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("could not find position with id {}", id))?;
+        if metadata.state != position::State::Withdrawn {
+            return Err(anyhow::anyhow!(
+                "attempted to claim rewards for position {} with state {}",
+                id,
+                metadata.state
+            ));
+        }
+
+        metadata.state = position::State::Claimed;
+        self.put_position(id, metadata);
+
+        Ok(())
     }
 }
 
@@ -97,9 +169,10 @@ impl<T: StateWrite + ?Sized> PositionManager for T {}
 #[async_trait]
 trait Inner: StateWrite {
     fn put_position(&mut self, id: &position::Id, metadata: position::Metadata) -> LpNft {
+        let state = metadata.state;
         self.put(state_key::position_by_id(id), metadata);
 
-        LpNft::new(*id, position::State::Opened)
+        LpNft::new(*id, state)
     }
 
     fn record_position_nonce(&mut self, nonce: [u8; 32]) {
@@ -108,43 +181,48 @@ trait Inner: StateWrite {
 
     fn index_position(&mut self, metadata: &position::Metadata) {
         let (pair, phi) = (metadata.position.phi.pair, &metadata.position.phi);
-        let id_bytes = metadata.position.id().encode_to_vec();
+        let id = metadata.position.id();
+        let id_bytes = id.encode_to_vec();
         if metadata.reserves.r1 != 0u64.into() {
             // Index this position for trades FROM asset 2 TO asset 1, since the position has asset 1 to give out.
             let pair = DirectedTradingPair {
-                start: pair.asset_1(),
-                end: pair.asset_2(),
+                start: pair.asset_2(),
+                end: pair.asset_1(),
             };
             let phi = phi.component.clone();
             self.nonconsensus_put_raw(
-                state_key::internal::price_index::key(&pair, &phi),
+                state_key::internal::price_index::key(&pair, &phi, &id),
                 id_bytes.clone(),
             );
         }
         if metadata.reserves.r2 != 0u64.into() {
             // Index this position for trades FROM asset 1 TO asset 2, since the position has asset 2 to give out.
             let pair = DirectedTradingPair {
-                start: pair.asset_2(),
-                end: pair.asset_1(),
+                start: pair.asset_1(),
+                end: pair.asset_2(),
             };
             let phi = phi.component.flip();
-            self.nonconsensus_put_raw(state_key::internal::price_index::key(&pair, &phi), id_bytes);
+            self.nonconsensus_put_raw(
+                state_key::internal::price_index::key(&pair, &phi, &id),
+                id_bytes,
+            );
         }
     }
 
     fn deindex_position(&mut self, position: &Position) {
-        let pair12 = DirectedTradingPair {
-            start: position.phi.pair.asset_1(),
-            end: position.phi.pair.asset_2(),
-        };
-        let phi12 = position.phi.component.clone();
+        let id = position.id();
         let pair21 = DirectedTradingPair {
             start: position.phi.pair.asset_2(),
             end: position.phi.pair.asset_1(),
         };
-        let phi21 = position.phi.component.flip();
-        self.nonconsensus_delete(state_key::internal::price_index::key(&pair12, &phi12));
-        self.nonconsensus_delete(state_key::internal::price_index::key(&pair21, &phi21));
+        let phi21 = position.phi.component.clone();
+        let pair12 = DirectedTradingPair {
+            start: position.phi.pair.asset_1(),
+            end: position.phi.pair.asset_2(),
+        };
+        let phi12 = position.phi.component.flip();
+        self.nonconsensus_delete(state_key::internal::price_index::key(&pair21, &phi21, &id));
+        self.nonconsensus_delete(state_key::internal::price_index::key(&pair12, &phi12, &id));
     }
 }
 impl<T: StateWrite + ?Sized> Inner for T {}
@@ -0,0 +1,137 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
+use penumbra_crypto::{
+    asset,
+    dex::{
+        execution::Path,
+        lp::{position, TradingFunction},
+        DirectedTradingPair,
+    },
+};
+use penumbra_storage::StateRead;
+
+use super::{batch_swap::directed_phi, state_key, PositionRead};
+
+/// The maximum number of hops a routed swap may take through the position graph.
+pub(super) const MAX_HOPS: usize = 3;
+
+/// The maximum number of neighbors of an asset considered when extending a
+/// path, keeping those with the best prices.
+pub(super) const MAX_NEIGHBORS: usize = 4;
+
+/// The maximum number of paths considered for a single routed swap.
+pub(super) const MAX_CANDIDATE_PATHS: usize = 8;
+
+/// Searches the graph of liquidity positions for routes between assets.
+///
+/// The graph has an edge from asset A to asset B whenever an open position
+/// has reserves of B to give out in exchange for A, as recorded in the price
+/// index.
+///
+/// Routing runs in `Dex::end_block`, so the search is bounded: each path is
+/// only extended through the [`MAX_NEIGHBORS`] best-priced neighbors of its
+/// end, and at most [`MAX_CANDIDATE_PATHS`] paths are returned.
+#[async_trait]
+pub(super) trait Router: StateRead {
+    /// Returns the paths from `pair.start` to `pair.end` of at most
+    /// [`MAX_HOPS`] hops which don't visit an asset twice, with shorter paths
+    /// first.
+    ///
+    /// Each hop of a path is composed from the best-priced position on it.
+    async fn candidate_paths(&self, pair: &DirectedTradingPair) -> Result<Vec<Path>> {
+        let mut paths = Vec::new();
+        let mut frontier: Vec<Option<Path>> = vec![None];
+
+        for _ in 0..MAX_HOPS {
+            let mut next_frontier = Vec::new();
+            for path in frontier {
+                let from = path.as_ref().map(Path::end).unwrap_or(pair.start);
+                for (to, phi) in self.neighbors(&from, &pair.end).await? {
+                    if to == pair.start
+                        || path
+                            .as_ref()
+                            .map_or(false, |path| path.route().contains(&to))
+                    {
+                        continue;
+                    }
+                    let extended = match &path {
+                        Some(path) => {
+                            let mut path = path.clone();
+                            path.extend(phi);
+                            path
+                        }
+                        None => Path::new(from, to, phi)?,
+                    };
+                    if to == pair.end {
+                        paths.push(extended);
+                        if paths.len() == MAX_CANDIDATE_PATHS {
+                            return Ok(paths);
+                        }
+                    } else {
+                        next_frontier.push(Some(extended));
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        Ok(paths)
+    }
+
+    /// Returns up to [`MAX_NEIGHBORS`] assets which positions give out in
+    /// exchange for `start`, with the trading function of the best-priced
+    /// position for each, best prices first.
+    ///
+    /// The `target` asset is always returned if it's a neighbor, since it
+    /// completes a path.
+    async fn neighbors(
+        &self,
+        start: &asset::Id,
+        target: &asset::Id,
+    ) -> Result<Vec<(asset::Id, TradingFunction)>> {
+        let prefix = state_key::internal::price_index::start_prefix(start);
+        let ends = self
+            .nonconsensus_prefix_raw(&prefix)
+            .map(|entry| -> Result<asset::Id> {
+                let (key, _) = entry?;
+                Ok(state_key::internal::price_index::pair_from_key(&key)?.end)
+            })
+            .try_collect::<BTreeSet<_>>()
+            .await?;
+
+        let mut neighbors = Vec::new();
+        for end in ends {
+            let hop = DirectedTradingPair::new(*start, end);
+            if let Some(best) = self.best_position(&hop).await? {
+                neighbors.push((hop, best));
+            }
+        }
+        // The target sorts first, and the sort is stable, so price ties are
+        // broken by asset ID.
+        neighbors.sort_by(|(hop_a, a), (hop_b, b)| {
+            (hop_b.end == *target)
+                .cmp(&(hop_a.end == *target))
+                .then_with(|| directed_phi(hop_b, b).cmp_fill_rate(&directed_phi(hop_a, a)))
+        });
+        Ok(neighbors
+            .into_iter()
+            .take(MAX_NEIGHBORS)
+            .map(|(hop, best)| (hop.end, best.position.phi))
+            .collect())
+    }
+
+    /// Returns the best-priced open position for trades along `pair`.
+    async fn best_position(
+        &self,
+        pair: &DirectedTradingPair,
+    ) -> Result<Option<position::Metadata>> {
+        let mut positions = self.positions_by_price(pair).await?;
+        positions.sort_by(|a, b| directed_phi(pair, b).cmp_fill_rate(&directed_phi(pair, a)));
+        Ok(positions.into_iter().next())
+    }
+}
+
+impl<T: StateRead + ?Sized> Router for T {}
@@ -14,25 +14,79 @@ pub fn position_by_id(id: &position::Id) -> String {
     format!("dex/position/{}", id)
 }
 
+pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
+    format!(
+        "dex/output/{}/{}/{}",
+        height,
+        &trading_pair.asset_1(),
+        &trading_pair.asset_2()
+    )
+}
+
+pub fn swap_execution(height: u64, trading_pair: &DirectedTradingPair) -> String {
+    format!(
+        "dex/swap_execution/{}/{}/{}",
+        height, &trading_pair.start, &trading_pair.end
+    )
+}
+
+pub fn swap_flows() -> &'static str {
+    "dex/swap_flows"
+}
+
 /// Encompasses non-consensus state keys.
 pub(crate) mod internal {
     use super::*;
     use penumbra_crypto::dex::lp::BareTradingFunction;
 
     pub mod price_index {
+        use std::ops::Range;
+
         use super::*;
+        use penumbra_crypto::asset;
+
+        /// The bytes of an index key holding the start asset of its pair.
+        const START: Range<usize> = 7..7 + 32;
+        /// The bytes of an index key holding the end asset of its pair.
+        const END: Range<usize> = 7 + 32..7 + 32 + 32;
+
+        /// Prefix for the positions trading away from `start`, to any asset.
+        pub fn start_prefix(start: &asset::Id) -> [u8; 39] {
+            let mut key = [0u8; 39];
+            key[0..7].copy_from_slice(b"dex/pi/");
+            key[START].copy_from_slice(&start.to_bytes());
+            key
+        }
+
         pub fn prefix(pair: &DirectedTradingPair) -> [u8; 71] {
             let mut key = [0u8; 71];
             key[0..7].copy_from_slice(b"dex/pi/");
-            key[7..7 + 32].copy_from_slice(&pair.start.to_bytes());
-            key[7 + 32..7 + 32 + 32].copy_from_slice(&pair.end.to_bytes());
+            key[START].copy_from_slice(&pair.start.to_bytes());
+            key[END].copy_from_slice(&pair.end.to_bytes());
             key
         }
 
-        pub fn key(pair: &DirectedTradingPair, btf: &BareTradingFunction) -> Vec<u8> {
-            let mut key = [0u8; 103];
+        /// Decodes the trading pair from an index key, the inverse of [`prefix`].
+        pub fn pair_from_key(key: &[u8]) -> anyhow::Result<DirectedTradingPair> {
+            if key.len() < END.end || !key.starts_with(b"dex/pi/") {
+                return Err(anyhow::anyhow!("malformed price index key"));
+            }
+            let start: asset::Id = <[u8; 32]>::try_from(&key[START])?.try_into()?;
+            let end: asset::Id = <[u8; 32]>::try_from(&key[END])?.try_into()?;
+            Ok(DirectedTradingPair::new(start, end))
+        }
+
+        /// The position ID is appended so that positions with the same price
+        /// don't overwrite each other in the index.
+        pub fn key(
+            pair: &DirectedTradingPair,
+            btf: &BareTradingFunction,
+            id: &position::Id,
+        ) -> Vec<u8> {
+            let mut key = [0u8; 135];
             key[0..71].copy_from_slice(&prefix(pair));
             key[71..103].copy_from_slice(&btf.effective_price_key_bytes());
+            key[103..135].copy_from_slice(&id.0);
             key.to_vec()
         }
     }
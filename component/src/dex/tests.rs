@@ -1 +1,524 @@
+use std::{ops::Deref, sync::Arc};
 
+use penumbra_chain::{
+    test_keys, CompactBlock, StatePayload, StateReadExt as _, StateWriteExt as _,
+};
+use penumbra_crypto::{
+    asset,
+    dex::{
+        lp::{
+            position::{self, Position},
+            BareTradingFunction, LpNft, Reserves, TradingFunction,
+        },
+        swap::SwapPlaintext,
+        DirectedTradingPair, TradingPair,
+    },
+    note,
+    transaction::Fee,
+    Address, Amount, Balance, Fq, Fr, Zero,
+};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, StateWrite, TempStorage};
+use penumbra_tct as tct;
+use penumbra_transaction::{
+    action::{PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw},
+    plan::{SwapClaimPlan, SwapPlan},
+    Transaction,
+};
+use rand_core::SeedableRng;
+use tendermint::abci;
+
+use crate::{shielded_pool::ShieldedPool, ActionHandler, Component, MockClient, TempStorageExt};
+
+use super::{
+    batch_swap::BatchSwap,
+    router::{Router, MAX_CANDIDATE_PATHS, MAX_HOPS, MAX_NEIGHBORS},
+    Dex, PositionManager, PositionRead, StateReadExt as _,
+};
+
+#[tokio::test]
+async fn position_lifecycle() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::REGISTRY.parse_unit("gm");
+    let gn = asset::REGISTRY.parse_unit("gn");
+    let pair = TradingPair::new(gm.id(), gn.id());
+
+    let position = Position {
+        phi: TradingFunction::new(pair, 1u32, 1u64.into(), 1u64.into()),
+        nonce: [1u8; 32],
+    };
+    let id = position.id();
+    let initial_reserves = Reserves {
+        r1: 100u64.into(),
+        r2: 50u64.into(),
+    };
+
+    // We don't use the context in the position action handlers, so use a dummy one.
+    let dummy_context = Arc::new(Transaction::default());
+
+    // 1. Open the position.
+    let open = PositionOpen {
+        position: position.clone(),
+        initial_reserves: initial_reserves.clone(),
+    };
+    open.check_stateless(dummy_context.clone()).await?;
+    open.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    open.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    let metadata = state.position_by_id(&id).await?.expect("position exists");
+    assert_eq!(metadata.state, position::State::Opened);
+    assert_eq!(metadata.reserves, initial_reserves);
+
+    // Reusing the nonce for another position is rejected.
+    assert!(open.check_stateful(state.clone()).await.is_err());
+
+    // 2. Withdrawing before closing the position is rejected.
+    let withdraw = PositionWithdraw {
+        position_id: id,
+        reserves_commitment: initial_reserves.balance(&pair).commit(Fr::zero()),
+    };
+    assert!(withdraw.check_stateful(state.clone()).await.is_err());
+
+    // 3. Close the position.
+    let close = PositionClose { position_id: id };
+    close.check_stateless(dummy_context.clone()).await?;
+    close.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    close.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    let metadata = state.position_by_id(&id).await?.expect("position exists");
+    assert_eq!(metadata.state, position::State::Closed);
+    assert!(close.check_stateful(state.clone()).await.is_err());
+
+    // 4. Withdraw the position's reserves. A withdrawal claiming the wrong
+    // reserves is rejected.
+    let bad_withdraw = PositionWithdraw {
+        position_id: id,
+        reserves_commitment: Reserves {
+            r1: 1000u64.into(),
+            r2: 50u64.into(),
+        }
+        .balance(&pair)
+        .commit(Fr::zero()),
+    };
+    assert!(bad_withdraw.check_stateful(state.clone()).await.is_err());
+
+    withdraw.check_stateless(dummy_context.clone()).await?;
+    withdraw.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    withdraw.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    let metadata = state.position_by_id(&id).await?.expect("position exists");
+    assert_eq!(metadata.state, position::State::Withdrawn);
+    assert_eq!(metadata.reserves, Reserves::zero());
+    assert!(withdraw.check_stateful(state.clone()).await.is_err());
+
+    // 5. Claim the position's (empty) rewards.
+    let claim = PositionRewardClaim {
+        position_id: id,
+        rewards_commitment: Balance::zero().commit(Fr::zero()),
+    };
+    claim.check_stateless(dummy_context.clone()).await?;
+    claim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    let metadata = state.position_by_id(&id).await?.expect("position exists");
+    assert_eq!(metadata.state, position::State::Claimed);
+    assert!(claim.check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn swap_and_swap_claim() -> anyhow::Result<()> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let height = 1;
+
+    // 1. Simulate BeginBlock
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(height);
+    state_tx.apply();
+
+    // 2. Open a position offering asset 2 for asset 1 at 1:1
+
+    let gm = asset::REGISTRY.parse_unit("gm");
+    let gn = asset::REGISTRY.parse_unit("gn");
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+
+    let position = Position {
+        phi: TradingFunction::new(trading_pair, 0u32, 1u64.into(), 1u64.into()),
+        nonce: [2u8; 32],
+    };
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .position_open(
+            position.clone(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: 1_000_000u64.into(),
+            },
+        )
+        .await?;
+    state_tx.apply();
+
+    // 3. Create a Swap action
+
+    let delta_1 = Amount::from(100_000u64);
+    let delta_2 = Amount::from(0u64);
+    let fee = Fee::default();
+    let claim_address: Address = *test_keys::ADDRESS_0;
+
+    let plaintext =
+        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+
+    let swap_plan = SwapPlan::new(&mut rng, plaintext.clone());
+    let swap = swap_plan.swap(&test_keys::FULL_VIEWING_KEY);
+
+    // 4. Simulate execution of the Swap action
+
+    // We don't use the context in the Swap::check_stateless impl, so use a dummy one.
+    let dummy_context = Arc::new(Transaction::default());
+    swap.check_stateless(dummy_context.clone()).await?;
+    swap.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    swap.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // 5. Execute EndBlock (where the swap is actually executed)
+
+    let end_block = abci::request::EndBlock {
+        height: height.try_into().unwrap(),
+    };
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    // Execute EndBlock for the Dex, to actually execute the swaps...
+    Dex::end_block(&mut state_tx, &end_block).await;
+    // ... and for the ShieldedPool, to correctly write out the SCT with the data we'll use next.
+    ShieldedPool::end_block(&mut state_tx, &end_block).await;
+    state_tx.apply();
+
+    // 6. Create a SwapClaim action
+
+    // To do this, we need to have an auth path for the swap nft note, which
+    // means we have to synchronize a client's view of the test chain's SCT
+    // state.
+
+    let epoch_duration = state.get_epoch_duration().await?;
+    let mut client = MockClient::new(test_keys::FULL_VIEWING_KEY.clone(), epoch_duration);
+    // TODO: generalize StateRead/StateWrite impls from impl for &S to impl for Deref<Target=S>
+    client.sync_to(1, state.deref()).await?;
+
+    let output_data = state.output_data(height, trading_pair).await?.unwrap();
+    assert!(output_data.success);
+    assert_eq!(output_data.lambda_2, 100_000);
+
+    // The position took in asset 1 and gave out asset 2.
+    let metadata = state
+        .position_by_id(&position.id())
+        .await?
+        .expect("position exists");
+    assert_eq!(
+        metadata.reserves,
+        Reserves {
+            r1: 100_000u64.into(),
+            r2: 900_000u64.into(),
+        }
+    );
+
+    let commitment = swap.body.payload.commitment;
+    let swap_auth_path = client.witness(commitment).unwrap();
+    let detected_plaintext = client.swap_by_commitment(&commitment).unwrap();
+    assert_eq!(plaintext, detected_plaintext);
+
+    let claim_plan = SwapClaimPlan {
+        swap_plaintext: plaintext,
+        position: swap_auth_path.position(),
+        output_data,
+        epoch_duration,
+    };
+    let anchor = client.latest_height_and_sct_root().1;
+    let claim = claim_plan.swap_claim(&test_keys::FULL_VIEWING_KEY, &swap_auth_path, anchor);
+
+    // 7. Execute the SwapClaim action
+
+    // The SwapClaim ActionHandler uses the transaction's anchor to check proofs:
+    let context = Arc::new(Transaction {
+        anchor,
+        ..Default::default()
+    });
+
+    claim.check_stateless(context).await?;
+    claim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn swap_with_nonzero_fee() -> anyhow::Result<()> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let height = 1;
+
+    // 1. Simulate BeginBlock
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(height);
+    state_tx.apply();
+
+    // 2. Create a Swap action
+
+    let gm = asset::REGISTRY.parse_unit("gm");
+    let gn = asset::REGISTRY.parse_unit("gn");
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+
+    let delta_1 = Amount::from(100_000u64);
+    let delta_2 = Amount::from(0u64);
+    let fee = Fee::from_staking_token_amount(Amount::from(1u64));
+    let claim_address: Address = *test_keys::ADDRESS_0;
+
+    let plaintext =
+        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+
+    let swap_plan = SwapPlan::new(&mut rng, plaintext.clone());
+    let swap = swap_plan.swap(&test_keys::FULL_VIEWING_KEY);
+
+    // 3. Simulate execution of the Swap action
+
+    // We don't use the context in the Swap::check_stateless impl, so use a dummy one.
+    let dummy_context = Arc::new(Transaction::default());
+    swap.check_stateless(dummy_context.clone()).await?;
+    swap.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    swap.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // 4. Execute EndBlock (where the swap is actually executed)
+
+    let end_block = abci::request::EndBlock {
+        height: height.try_into().unwrap(),
+    };
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    // Execute EndBlock for the Dex, to actually execute the swaps...
+    Dex::end_block(&mut state_tx, &end_block).await;
+    ShieldedPool::end_block(&mut state_tx, &end_block).await;
+    state_tx.apply();
+
+    // There's no liquidity for the pair, so the batch fails and the inputs
+    // can be claimed back.
+    let output_data = state.output_data(height, trading_pair).await?.unwrap();
+    assert!(!output_data.success);
+    assert_eq!(output_data.pro_rata_outputs((100_000, 0)), (100_000, 0));
+
+    Ok(())
+}
+
+/// Opens a position giving out `amount` of `give` in exchange for `take` at
+/// 1:1, with the given fee.
+async fn open_offer<S: StateWrite>(
+    mut state: S,
+    give: asset::Id,
+    take: asset::Id,
+    amount: u64,
+    fee: u32,
+    nonce: u8,
+) -> anyhow::Result<Position> {
+    let pair = TradingPair::new(give, take);
+    let reserves = if pair.asset_1() == give {
+        Reserves {
+            r1: amount.into(),
+            r2: 0u64.into(),
+        }
+    } else {
+        Reserves {
+            r1: 0u64.into(),
+            r2: amount.into(),
+        }
+    };
+    let position = Position {
+        phi: TradingFunction::new(pair, fee, 1u64.into(), 1u64.into()),
+        nonce: [nonce; 32],
+    };
+    state.position_open(position.clone(), reserves).await?;
+    Ok(position)
+}
+
+#[tokio::test]
+async fn multi_hop_route() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::REGISTRY.parse_unit("gm").id();
+    let gn = asset::REGISTRY.parse_unit("gn").id();
+    let penumbra = asset::REGISTRY.parse_unit("penumbra").id();
+
+    // A direct gm -> gn position with a 1% fee, and a fee-free route through
+    // penumbra, which gives more output.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let direct = open_offer(&mut state_tx, gn, gm, 1_000, 100, 1).await?;
+    let hop_1 = open_offer(&mut state_tx, penumbra, gm, 1_000, 0, 2).await?;
+    let hop_2 = open_offer(&mut state_tx, gn, penumbra, 1_000, 0, 3).await?;
+    state_tx.apply();
+
+    let trading_pair = TradingPair::new(gm, gn);
+    let delta = if trading_pair.asset_1() == gm {
+        (100, 0)
+    } else {
+        (0, 100)
+    };
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let output_data = state_tx.execute_batch_swap(1, trading_pair, delta).await?;
+    state_tx.apply();
+
+    assert!(output_data.success);
+    let lambda = if trading_pair.asset_1() == gm {
+        output_data.lambda_2
+    } else {
+        output_data.lambda_1
+    };
+    assert_eq!(lambda, 100);
+
+    // The swap was routed through penumbra, leaving the direct position untouched.
+    let execution = state
+        .swap_execution(1, &DirectedTradingPair::new(gm, gn))
+        .await?
+        .expect("swap execution was recorded");
+    assert_eq!(execution.trades.len(), 2);
+    assert_eq!(execution.trades[0].path.route(), &[gm, penumbra]);
+    assert_eq!(execution.trades[0].end_amount, 100u64.into());
+    assert_eq!(execution.trades[1].path.route(), &[penumbra, gn]);
+    assert_eq!(execution.trades[1].end_amount, 100u64.into());
+    // Each hop records the price it was filled at.
+    for trade in &execution.trades {
+        assert_eq!(
+            trade.path.phi(),
+            &BareTradingFunction::new(0, trade.start_amount, trade.end_amount)
+        );
+    }
+
+    for (position, filled) in [(direct, false), (hop_1, true), (hop_2, true)] {
+        let metadata = state
+            .position_by_id(&position.id())
+            .await?
+            .expect("position exists");
+        let total = u128::from(metadata.reserves.r1) + u128::from(metadata.reserves.r2);
+        let untouched = metadata.reserves.r1 == 0u64.into() || metadata.reserves.r2 == 0u64.into();
+        assert_eq!(total, 1_000);
+        assert_eq!(untouched, !filled);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn routing_through_many_assets_is_bounded() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::REGISTRY.parse_unit("gm").id();
+    let gn = asset::REGISTRY.parse_unit("gn").id();
+    let assets = (0..12)
+        .map(|i| {
+            asset::REGISTRY
+                .parse_unit(&format!("test_asset_{}", i))
+                .id()
+        })
+        .collect::<Vec<_>>();
+
+    // Every intermediate asset can be reached from gm, with worse prices for
+    // later assets, and every pair of assets is connected, so the number of
+    // paths grows exponentially with the number of hops.
+    let mut offers = vec![(gn, gm, 500)];
+    for (i, asset) in assets.iter().enumerate() {
+        offers.push((*asset, gm, 10 * i as u32));
+        offers.push((gn, *asset, 0));
+        for other in assets.iter().filter(|other| *other != asset) {
+            offers.push((*other, *asset, 0));
+        }
+    }
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    for (nonce, (give, take, fee)) in offers.into_iter().enumerate() {
+        open_offer(&mut state_tx, give, take, 1_000, fee, nonce as u8).await?;
+    }
+    state_tx.apply();
+
+    // Only the best-priced neighbors are considered, along with the target.
+    let neighbors = state.neighbors(&gm, &gn).await?;
+    assert_eq!(neighbors.len(), MAX_NEIGHBORS);
+    assert_eq!(neighbors[0].0, gn);
+    assert_eq!(
+        neighbors[1..]
+            .iter()
+            .map(|(asset, _)| *asset)
+            .collect::<Vec<_>>(),
+        assets[..MAX_NEIGHBORS - 1]
+    );
+
+    let paths = state
+        .candidate_paths(&DirectedTradingPair::new(gm, gn))
+        .await?;
+    assert_eq!(paths.len(), MAX_CANDIDATE_PATHS);
+    // Shorter paths come first, starting with the direct one.
+    assert_eq!(paths[0].route(), &[gm, gn]);
+    for path in &paths {
+        assert!(path.route().len() <= MAX_HOPS + 1);
+        let first_hop = path.route()[1];
+        assert!(first_hop == gn || assets[..MAX_NEIGHBORS - 1].contains(&first_hop));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mock_client_scans_position_payloads() -> anyhow::Result<()> {
+    let gm = asset::REGISTRY.parse_unit("gm");
+    let gn = asset::REGISTRY.parse_unit("gn");
+    let position = Position {
+        phi: TradingFunction::new(
+            TradingPair::new(gm.id(), gn.id()),
+            1u32,
+            1u64.into(),
+            1u64.into(),
+        ),
+        nonce: [2u8; 32],
+    };
+    let commitment = note::Commitment(Fq::from(1u64));
+    let block = CompactBlock {
+        height: 0,
+        state_payloads: vec![StatePayload::Position {
+            lpnft: LpNft::new(position.id(), position::State::Opened),
+            commitment,
+        }],
+        ..Default::default()
+    };
+
+    let mut client = MockClient::new(test_keys::FULL_VIEWING_KEY.clone(), 10);
+    client.scan_block(block)?;
+
+    // The position isn't one the client was expecting, so its commitment is
+    // forgotten, but the client's tree still tracks the chain's.
+    let mut sct = tct::Tree::default();
+    sct.insert(tct::Witness::Forget, commitment)?;
+    sct.end_block()?;
+    assert_eq!(client.latest_height_and_sct_root(), (0, sct.root()));
+    assert!(client.witness(commitment).is_none());
+
+    Ok(())
+}
@@ -1,13 +1,46 @@
 use anyhow::{Context as _, Result};
 
 use super::proposal::{self, chain_params};
+use crate::{shielded_pool::SupplyRead, stake::StateReadExt as _};
+use penumbra_crypto::{
+    stake::{DelegationToken, IdentityKey},
+    Fr, Value, Zero,
+};
+use penumbra_proof_params::DELEGATORVOTE_PROOF_VERIFICATION_KEY;
+use penumbra_storage::StateRead;
 use penumbra_transaction::action::{
-    ProposalSubmit, ProposalWithdraw, ValidatorVote, ValidatorVoteBody,
+    DelegatorVote, DelegatorVoteBody, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+    ValidatorVoteBody,
 };
 
+/// Find the validator whose delegation tokens make up the given value, failing if the value is not
+/// denominated in the delegation token of a known validator.
+pub async fn delegation_token_validator<S: StateRead>(
+    state: S,
+    value: &Value,
+) -> Result<IdentityKey> {
+    let denom = state
+        .denom_by_asset(&value.asset_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("unknown asset {}", value.asset_id))?;
+    let identity_key = DelegationToken::try_from(denom)
+        .context("delegator votes must be cast with delegation tokens")?
+        .validator();
+
+    if state.validator(&identity_key).await?.is_none() {
+        anyhow::bail!("validator {} does not exist", identity_key);
+    }
+
+    Ok(identity_key)
+}
+
 pub mod stateless {
     use penumbra_proto::DomainType;
-    use penumbra_transaction::action::{Proposal, ProposalDepositClaim};
+    use penumbra_transaction::{
+        action::{Proposal, ProposalDepositClaim},
+        plan::{ActionPlan, TransactionPlan},
+        Transaction,
+    };
 
     use super::*;
 
@@ -49,17 +82,44 @@ pub mod stateless {
                 }
             }
             DaoSpend {
-                schedule_transactions: _,
-                cancel_transactions: _,
+                schedule_transactions,
+                cancel_transactions: _, // cancellations can only be checked against the state
             } => {
-                // TODO: check that scheduled transactions are valid without any witness or auth data
-                anyhow::bail!("DAO spend proposals are not yet supported")
+                for (_, transaction) in schedule_transactions {
+                    let plan = proposal::scheduled_transaction_plan(transaction)
+                        .context("invalid scheduled transaction")?;
+                    dao_transaction_plan(&plan)?;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Check that a transaction plan can be executed by the DAO, without any witness or
+    /// authorization data.
+    ///
+    /// The DAO has no spending key, so the only transactions it can execute are those which pay
+    /// out of its balance into new notes, which the chain mints directly when the transaction is
+    /// executed.
+    fn dao_transaction_plan(plan: &TransactionPlan) -> Result<()> {
+        for action in &plan.actions {
+            if !matches!(action, ActionPlan::Output(_)) {
+                anyhow::bail!("DAO transactions may only contain outputs");
+            }
+        }
+        if plan.fee.0.amount.value() != 0 {
+            anyhow::bail!("DAO transactions cannot pay fees");
+        }
+        if !plan.clue_plans.is_empty() {
+            anyhow::bail!("DAO transactions cannot include clues");
+        }
+        if plan.memo_plan.is_some() {
+            anyhow::bail!("DAO transactions cannot include a memo");
+        }
+        Ok(())
+    }
+
     pub fn proposal_withdraw(proposal_withdraw: &ProposalWithdraw) -> Result<()> {
         // Enforce a maximum length on proposal withdrawal reasons; 80 characters seems reasonable.
         const PROPOSAL_WITHDRAWAL_REASON_LIMIT: usize = 80;
@@ -87,6 +147,40 @@ pub mod stateless {
         Ok(())
     }
 
+    pub fn delegator_vote(
+        DelegatorVote {
+            body,
+            auth_sig,
+            proof,
+        }: &DelegatorVote,
+        context: &Transaction,
+    ) -> Result<()> {
+        // Check the vote authorization signature using the randomized spend key:
+        let effect_hash = context.transaction_body().effect_hash();
+        body.rk
+            .verify(effect_hash.as_ref(), auth_sig)
+            .context("delegator vote auth signature failed to verify")?;
+
+        // Check that the proof verifies, which shows that the voter controls a note with the
+        // claimed value, committed before voting on the proposal started:
+        proof
+            .verify(
+                &DELEGATORVOTE_PROOF_VERIFICATION_KEY,
+                context.anchor,
+                body.value.commit(Fr::zero()),
+                body.nullifier,
+                body.rk,
+                body.start_position,
+            )
+            .context("a delegator vote proof did not verify")?;
+
+        // This is stateless verification, so we still need to check that the proposal being voted
+        // on exists and started at the claimed position, and that this note hasn't voted on it
+        // already.
+
+        Ok(())
+    }
+
     pub fn proposal_deposit_claim(
         ProposalDepositClaim {
             // None of these fields can be meaningfully checked without reference to the state
@@ -101,12 +195,14 @@ pub mod stateless {
 }
 
 pub mod stateful {
+    use std::collections::BTreeMap;
+
     use super::super::StateReadExt as _;
     use super::*;
-    use crate::stake::StateReadExt as _;
+    use crate::shielded_pool::StateReadExt as _;
     use penumbra_chain::StateReadExt as _;
-    use penumbra_crypto::{stake::IdentityKey, Amount, GovernanceKey, STAKING_TOKEN_DENOM};
-    use penumbra_storage::StateRead;
+    use penumbra_crypto::{Amount, GovernanceKey, Nullifier, STAKING_TOKEN_DENOM};
+    use penumbra_tct as tct;
     use penumbra_transaction::action::{ProposalDepositClaim, ProposalPayload};
 
     pub async fn proposal_submit<S: StateRead>(
@@ -158,13 +254,54 @@ pub mod stateful {
                 schedule_transactions,
                 cancel_transactions,
             } => {
-                for (effective_height, _) in schedule_transactions.iter() {
+                let chain_id = state.get_chain_id().await?;
+                for (effective_height, transaction) in schedule_transactions.iter() {
                     height_in_future_of_voting_end(&state, *effective_height).await?;
+
+                    // This was checked statelessly, so it must decode:
+                    let plan = proposal::scheduled_transaction_plan(transaction)?;
+                    if plan.chain_id != chain_id {
+                        anyhow::bail!(
+                            "scheduled transaction has chain ID {}, expected {}",
+                            plan.chain_id,
+                            chain_id
+                        );
+                    }
+                    if plan.expiry_height != 0 && plan.expiry_height < *effective_height {
+                        anyhow::bail!(
+                            "scheduled transaction expires at height {}, before it is executed at height {}",
+                            plan.expiry_height,
+                            effective_height
+                        );
+                    }
                 }
-                for (scheduled_height, _) in cancel_transactions.iter() {
+
+                // Each cancellation removes one scheduled transaction, so there must be at least
+                // as many matching scheduled transactions as there are cancellations for them.
+                let mut cancellations = BTreeMap::<(u64, &[u8]), usize>::new();
+                for (scheduled_height, effect_hash) in cancel_transactions.iter() {
                     height_in_future_of_voting_end(&state, *scheduled_height).await?;
+                    *cancellations
+                        .entry((*scheduled_height, effect_hash.as_ref()))
+                        .or_default() += 1;
+                }
+                for ((scheduled_height, effect_hash), count) in cancellations {
+                    let scheduled = state
+                        .scheduled_dao_transactions(scheduled_height)
+                        .await?
+                        .into_iter()
+                        .filter(|(_, plan)| plan.dao_effect_hash().as_ref() == effect_hash)
+                        .count();
+                    if scheduled < count {
+                        anyhow::bail!(
+                            "cannot cancel {} transactions with effect hash {} at height {}, only {} are scheduled",
+                            count,
+                            hex::encode(effect_hash),
+                            scheduled_height,
+                            scheduled
+                        );
+                    }
                 }
-                // TODO: check that all transactions to cancel exist already and match auth hash
             }
         }
 
@@ -205,6 +342,99 @@ pub mod stateful {
         Ok(())
     }
 
+    pub async fn delegator_vote<S: StateRead>(
+        state: S,
+        DelegatorVote {
+            body:
+                DelegatorVoteBody {
+                    proposal,
+                    start_position,
+                    vote: _, // All votes are valid, so we don't need to do anything with this
+                    value,
+                    nullifier,
+                    rk: _, // We already checked this in stateless verification
+                },
+            auth_sig: _, // We already checked this in stateless verification
+            proof: _,    // We already checked this in stateless verification
+        }: &DelegatorVote,
+    ) -> Result<()> {
+        proposal_voteable(&state, *proposal).await?;
+        start_position_matches_proposal(&state, *proposal, *start_position).await?;
+        nullifier_has_not_voted(&state, *proposal, *nullifier).await?;
+        nullifier_unspent_before_voting_start(&state, *proposal, *nullifier).await?;
+        delegation_token_validator(&state, value).await?;
+        Ok(())
+    }
+
+    async fn start_position_matches_proposal<S: StateRead>(
+        state: S,
+        proposal_id: u64,
+        start_position: tct::Position,
+    ) -> Result<()> {
+        let proposal_start_position = state
+            .proposal_voting_start_position(proposal_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} has no start position", proposal_id))?;
+
+        if start_position != proposal_start_position {
+            anyhow::bail!(
+                "delegator vote start position {:?} does not match the start position {:?} of proposal {}",
+                start_position,
+                proposal_start_position,
+                proposal_id
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn nullifier_has_not_voted<S: StateRead>(
+        state: S,
+        proposal_id: u64,
+        nullifier: Nullifier,
+    ) -> Result<()> {
+        if state
+            .has_voted_with_nullifier(proposal_id, nullifier)
+            .await?
+        {
+            anyhow::bail!(
+                "nullifier {} has already been used to vote on proposal {}",
+                nullifier,
+                proposal_id
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn nullifier_unspent_before_voting_start<S: StateRead>(
+        state: S,
+        proposal_id: u64,
+        nullifier: Nullifier,
+    ) -> Result<()> {
+        // A note spent before voting started could have been used to create another note that
+        // was also committed before voting started, which would let the same stake vote twice.
+        // Spending a note after voting started is fine, since the new notes can't vote.
+        let voting_start = state
+            .proposal_voting_start(proposal_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("proposal {} has no voting start", proposal_id))?;
+
+        if let Some(spend_height) = state.nullifier_spend_height(nullifier).await? {
+            if spend_height < voting_start {
+                anyhow::bail!(
+                    "nullifier {} was spent at height {}, before voting on proposal {} started at height {}",
+                    nullifier,
+                    spend_height,
+                    proposal_id,
+                    voting_start
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     async fn proposal_voteable<S: StateRead>(state: S, proposal_id: u64) -> Result<()> {
         if let Some(proposal_state) = state.proposal_state(proposal_id).await? {
             use proposal::State::*;
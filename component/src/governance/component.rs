@@ -30,11 +30,13 @@ impl Component for Governance {
     #[instrument(name = "governance", skip(state, _end_block))]
     async fn end_block<S: StateWrite>(mut state: S, _end_block: &abci::request::EndBlock) {
         // TODO: compute intermediate tallies at epoch boundaries (with threshold delegator voting)
-        execute::enact_all_passed_proposals(&mut state)
-            .await
-            .expect("failed to enact proposals");
-        execute::enact_pending_parameter_changes(&mut state)
-            .await
-            .expect("failed to enact parameter changes");
+        // Failing to read the state needed for tallying shouldn't halt the chain: the
+        // proposals remain unfinished, and are tallied again at the next block.
+        if let Err(error) = execute::enact_all_passed_proposals(&mut state).await {
+            tracing::error!(?error, "failed to enact proposals");
+        }
+        if let Err(error) = execute::enact_pending_parameter_changes(&mut state).await {
+            tracing::error!(?error, "failed to enact parameter changes");
+        }
     }
 }
@@ -4,6 +4,7 @@ use crate::{
 };
 
 use super::{
+    check::delegation_token_validator,
     proposal::{self, chain_params},
     tally,
     view::StateWriteExt as _,
@@ -12,10 +13,10 @@ use super::{
 use anyhow::{Context, Result};
 use penumbra_chain::{StateReadExt as _, StateWriteExt};
 use penumbra_crypto::ProposalNft;
-use penumbra_storage::StateWrite;
+use penumbra_storage::{StateDelta, StateWrite};
 use penumbra_transaction::action::{
-    ProposalDepositClaim, ProposalPayload, ProposalSubmit, ProposalWithdraw, ValidatorVote,
-    ValidatorVoteBody,
+    DelegatorVote, DelegatorVoteBody, ProposalDepositClaim, ProposalPayload, ProposalSubmit,
+    ProposalWithdraw, ValidatorVote, ValidatorVoteBody,
 };
 use tracing::instrument;
 
@@ -63,6 +64,18 @@ pub async fn proposal_submit<S: StateWrite>(
         .await;
     state.put_proposal_voting_end(proposal_id, voting_end).await;
 
+    // Delegators can vote with any delegation note that was committed before the start of this
+    // block, so record the position of the start of the block as the start of voting
+    let (epoch, block, _) = state
+        .stub_state_commitment_tree()
+        .await
+        .position()
+        .context("state commitment tree is full")?
+        .into();
+    state
+        .put_proposal_voting_start_position(proposal_id, (epoch, block, 0).into())
+        .await;
+
     // If there was a proposal submitted, ensure we track this so that clients
     // can retain state needed to vote as delegators
     let mut compact_block = state.stub_compact_block();
@@ -169,8 +182,33 @@ pub async fn proposal_deposit_claim<S: StateWrite>(
     Ok(())
 }
 
-// TODO: fill in when delegator votes happen
-// pub async fn delegator_vote(state: &State, delegator_vote: &DelegatorVote) {}
+#[instrument(skip(state))]
+pub async fn delegator_vote<S: StateWrite>(
+    mut state: S,
+    DelegatorVote {
+        auth_sig: _,
+        proof: _,
+        body:
+            DelegatorVoteBody {
+                proposal,
+                start_position: _,
+                vote,
+                value,
+                nullifier,
+                rk: _,
+            },
+    }: &DelegatorVote,
+) -> Result<()> {
+    let identity_key = delegation_token_validator(&state, value).await?;
+
+    state
+        .cast_delegator_vote(*proposal, identity_key, *vote, *nullifier, value.amount)
+        .await?;
+
+    tracing::debug!(proposal = %proposal, validator = %identity_key, "cast delegator vote");
+
+    Ok(())
+}
 
 #[instrument(skip(state))]
 pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<()> {
@@ -188,7 +226,6 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
         .await
         .context("can get unfinished proposals")?
     {
-        // TODO: tally delegator votes
         if let Some(outcome) = parameters
             .tally(&state, circumstance, proposal_id)
             .await
@@ -196,9 +233,21 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
         {
             tracing::debug!(proposal = %proposal_id, outcome = ?outcome, "proposal voting finished");
 
-            // If the proposal passes, enact it now
+            // If the proposal passes, enact it now, in a fork of the state so that a proposal
+            // which fails to enact has no effect at all, rather than halting the chain
             if outcome.is_passed() {
-                enact_proposal(&mut state, proposal_id).await?;
+                let mut delta = StateDelta::new(&mut state);
+                match enact_proposal(&mut delta, proposal_id).await {
+                    Ok(()) => {
+                        let (state, events) = delta.apply();
+                        for event in events {
+                            state.record(event);
+                        }
+                    }
+                    Err(error) => {
+                        tracing::warn!(proposal = %proposal_id, ?error, "failed to enact passed proposal");
+                    }
+                }
             }
 
             // Log the result
@@ -291,16 +340,31 @@ async fn enact_proposal<S: StateWrite>(mut state: S, proposal_id: u64) -> Result
             state.put_chain_params(new_params);
         }
         ProposalPayload::DaoSpend {
-            schedule_transactions: _,
-            cancel_transactions: _,
+            schedule_transactions,
+            cancel_transactions,
         } => {
-            // TODO: schedule transaction cancellations by removing the first matching one from the
-            // front of the schedule for their effective block
-            // TODO: schedule new transactions by appending them to the end of the schedule for their
-            // effective block
-            // TODO: don't forget to fill in the part in the shielded pool where the transactions
-            // actually get included in a block
-            todo!("implement daospend execution")
+            // Cancellations are processed first, so that a proposal can replace a scheduled
+            // transaction with an identical one without cancelling its own.
+            for (scheduled_height, effect_hash) in cancel_transactions {
+                // Other proposals may have cancelled the same transaction in the meantime, so
+                // there's no guarantee it still exists; if it doesn't, there's nothing to do.
+                if !state
+                    .cancel_dao_transaction(scheduled_height, effect_hash)
+                    .await?
+                {
+                    tracing::info!(proposal = %proposal_id, %scheduled_height, ?effect_hash, "DAO transaction to cancel was not scheduled");
+                }
+            }
+
+            // New transactions are appended to the end of the schedule for their height; the
+            // shielded pool executes them in order when that height is reached.
+            for (execute_at_height, transaction) in schedule_transactions {
+                let plan = proposal::scheduled_transaction_plan(&transaction)
+                    .context("scheduled transaction is a transaction plan")?;
+                state
+                    .schedule_dao_transaction(execute_at_height, plan)
+                    .await?;
+            }
         }
     }
 
@@ -12,3 +12,7 @@ pub mod tally;
 pub use self::metrics::register_metrics;
 pub use component::Governance;
 pub use view::StateReadExt;
+pub(crate) use view::StateWriteExt;
+
+#[cfg(test)]
+mod tests;
@@ -1,4 +1,5 @@
-use penumbra_crypto::stake::IdentityKey;
+use penumbra_crypto::{stake::IdentityKey, Nullifier};
+use penumbra_transaction::action::Vote;
 
 pub fn next_proposal_id() -> &'static str {
     "governance/next_proposal_id"
@@ -24,6 +25,10 @@ pub fn proposal_voting_end(proposal_id: u64) -> String {
     format!("governance/proposal/{}/voting_end", proposal_id)
 }
 
+pub fn proposal_voting_start_position(proposal_id: u64) -> String {
+    format!("governance/proposal/{}/voting_start_position", proposal_id)
+}
+
 pub fn unfinished_proposals() -> &'static str {
     "governance/unfinished_proposals"
 }
@@ -38,3 +43,34 @@ pub fn validator_vote(proposal_id: u64, identity_key: IdentityKey) -> String {
         proposal_id, identity_key
     )
 }
+
+pub fn voted_nullifier_lookup(proposal_id: u64, nullifier: &Nullifier) -> String {
+    format!(
+        "governance/proposal/{}/voted_nullifiers/{}",
+        proposal_id, nullifier
+    )
+}
+
+pub fn delegator_vote_tallies(proposal_id: u64) -> String {
+    format!("governance/proposal/{}/delegator_vote_tally/", proposal_id)
+}
+
+pub fn delegator_vote_tally(proposal_id: u64, identity_key: IdentityKey, vote: Vote) -> String {
+    format!(
+        "governance/proposal/{}/delegator_vote_tally/{}/{}",
+        proposal_id, identity_key, vote
+    )
+}
+
+pub fn scheduled_dao_transactions(height: u64) -> String {
+    format!("governance/scheduled_dao_transactions/{}/", height)
+}
+
+pub fn scheduled_dao_transaction(height: u64, index: u64) -> String {
+    // The index is zero-padded so that the transactions scheduled for a height
+    // are iterated in the order they were scheduled.
+    format!(
+        "governance/scheduled_dao_transactions/{}/{:020}",
+        height, index
+    )
+}
@@ -1,6 +1,9 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use num_rational::Ratio;
 use penumbra_chain::params::ChainParameters;
+use penumbra_crypto::stake::IdentityKey;
 use penumbra_transaction::action::Vote;
 
 use super::{proposal::Withdrawn, StateReadExt as _};
@@ -110,8 +113,10 @@ impl Tally {
             return None;
         }
 
-        // Check to see if we've met quorum
-        if Ratio::new(self.total(), self.circumstance.total_voting_power) < parameters.valid_quorum
+        // Check to see if we've met quorum (the `.max(1)` prevents a divide-by-zero error when
+        // there is no voting power at all, in which case quorum can never be met)
+        if Ratio::new(self.total(), self.circumstance.total_voting_power.max(1))
+            < parameters.valid_quorum
         {
             return Some(Outcome::Failed {
                 withdrawn: self.withdrawn,
@@ -119,7 +124,7 @@ impl Tally {
         }
 
         // Check to see if it has been vetoed
-        if Ratio::new(self.no_with_veto, self.total()) > parameters.veto_threshold {
+        if Ratio::new(self.no_with_veto, self.total().max(1)) > parameters.veto_threshold {
             return Some(Outcome::Vetoed {
                 withdrawn: self.withdrawn,
             });
@@ -127,21 +132,21 @@ impl Tally {
 
         // Calculate the current yes/total-without-abstain ratio, which will be used to determine if
         // the proposal has yet passed
-        let ratio_without_abstain = Ratio::new(self.yes, self.total_without_abstain().min(1));
-        // ^ in the above, the `.min(1)` is to prevent a divide-by-zero error when the only votes
+        let ratio_without_abstain = Ratio::new(self.yes, self.total_without_abstain().max(1));
+        // ^ in the above, the `.max(1)` is to prevent a divide-by-zero error when the only votes
         // cast are abstains -- this results in a 0:1 ratio in that case, which will never pass, as
         // desired in that situation
 
         // Different logic is used to determine pass threshold depending on emergency/not
         if self.emergency && before_end {
             // A 2/3 supermajority is required to pass an emergency proposal before it ends normally
-            if Ratio::new(2, 3) < ratio_without_abstain {
+            if ratio_without_abstain < Ratio::new(2, 3) {
                 // We might yet reach 2/3 supermajority, but we're not there yet
                 return None;
             }
         } else {
             // Otherwise, the ratio is whatever is specified in the parameters
-            if parameters.pass_threshold < ratio_without_abstain {
+            if ratio_without_abstain <= parameters.pass_threshold {
                 // The proposal has failed at this point because it's non-emergency, so we are not
                 // evaluating it mid-proposal
                 return Some(Outcome::Failed {
@@ -192,6 +197,31 @@ impl Parameters {
         // Initialize a tally for this proposal
         let mut tally = Tally::new(circumstance, ending_block, withdrawn, emergency);
 
+        // Delegators who vote override their validator's vote for the stake they delegated, so
+        // first count the delegators' votes, keeping track of how much of each validator's power
+        // they have claimed. Delegators can never claim more power than their validator has.
+        let base_rate = state.current_base_rate().await?;
+        let mut delegated_power = BTreeMap::<IdentityKey, u64>::new();
+        for (identity_key, vote, delegation_amount) in
+            state.delegator_vote_tallies(proposal_id).await?
+        {
+            let rate = state
+                .current_validator_rate(&identity_key)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("missing rate data for {}", identity_key))?;
+            let validator_power = state
+                .validator_power(&identity_key)
+                .await?
+                .unwrap_or_default();
+            let claimed = delegated_power.entry(identity_key).or_default();
+            let power = rate
+                .voting_power(delegation_amount, &base_rate)
+                .min(validator_power.saturating_sub(*claimed));
+            *claimed += power;
+            tally.add(vote, power);
+        }
+
+        // Then count the validators' votes, with whatever power their delegators didn't claim.
         for identity_key in state.voting_validators(proposal_id).await? {
             let vote = state
                 .validator_vote(proposal_id, identity_key)
@@ -201,7 +231,11 @@ impl Parameters {
                 .validator_power(&identity_key)
                 .await?
                 .expect("validator has a power");
-            tally.add(vote, power);
+            let claimed = delegated_power
+                .get(&identity_key)
+                .copied()
+                .unwrap_or_default();
+            tally.add(vote, power.saturating_sub(claimed));
         }
 
         Ok(tally.evaluate(self))
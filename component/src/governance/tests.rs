@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use penumbra_chain::{genesis, test_keys, StateReadExt as _, StateWriteExt as _};
+use penumbra_crypto::{
+    keys::{SpendKey, SpendKeyBytes},
+    stake::{DelegationToken, IdentityKey},
+    GovernanceKey, Value, STAKING_TOKEN_ASSET_ID,
+};
+use penumbra_proto::DomainType;
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+use penumbra_transaction::{
+    action::{
+        ProposalKind, ProposalPayload, ProposalSubmit, ValidatorVote, ValidatorVoteBody, Vote,
+    },
+    plan::{OutputPlan, TransactionPlan},
+    Transaction,
+};
+use rand_core::SeedableRng;
+use tendermint::abci;
+
+use crate::{
+    shielded_pool::{DaoRead, ShieldedPool, SupplyRead},
+    stake::{validator::Validator, FundingStreams},
+    ActionHandler, Component, TempStorageExt,
+};
+
+use super::{
+    proposal::{Outcome, State},
+    Governance, StateReadExt as _,
+};
+
+#[tokio::test]
+async fn passed_dao_spend_proposal_is_executed() -> anyhow::Result<()> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+
+    // A single genesis validator, which holds all the voting power.
+    let validator_key = SpendKey::from(SpendKeyBytes([1u8; 32]));
+    let identity_key = IdentityKey(*validator_key.full_viewing_key().spend_verification_key());
+    let governance_key = GovernanceKey(identity_key.0);
+    let validator = Validator {
+        identity_key,
+        governance_key,
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(
+            &ed25519_consensus::SigningKey::new(&mut rng)
+                .verification_key()
+                .to_bytes(),
+        )
+        .expect("valid ed25519 key"),
+        name: "test validator".to_string(),
+        website: String::new(),
+        description: String::new(),
+        enabled: true,
+        funding_streams: FundingStreams::new(),
+        sequence_number: 0,
+    };
+
+    // The DAO is funded at genesis, rather than by depositing into it by hand.
+    let app_state = genesis::AppState {
+        validators: vec![validator.into()],
+        allocations: vec![genesis::Allocation {
+            amount: 1000,
+            denom: DelegationToken::from(&identity_key).denom().to_string(),
+            address: *test_keys::ADDRESS_0,
+        }],
+        dao_allocations: vec![genesis::DaoAllocation {
+            amount: 10,
+            denom: "penumbra".to_string(),
+        }],
+        ..Default::default()
+    };
+    let storage = TempStorage::new().await?.apply_genesis(app_state).await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    assert_eq!(
+        state.dao_balance(&STAKING_TOKEN_ASSET_ID).await?,
+        10_000_000u64.into()
+    );
+    let supply_before = state
+        .token_supply(&STAKING_TOKEN_ASSET_ID)
+        .await?
+        .unwrap_or(0);
+
+    // We don't use the context in the governance action handlers, so use a dummy one.
+    let dummy_context = Arc::new(Transaction::default());
+
+    // 1. Propose that the DAO pays out 1 penumbra when voting ends.
+    let chain_id = state.get_chain_id().await?;
+    let chain_params = state.get_chain_params().await?;
+    let voting_end = state.get_block_height().await? + chain_params.proposal_voting_blocks;
+    let proposal_id = state.next_proposal_id().await?;
+
+    let plan = TransactionPlan {
+        actions: vec![OutputPlan::new(
+            &mut rng,
+            Value {
+                amount: 1_000_000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            *test_keys::ADDRESS_1,
+        )
+        .into()],
+        chain_id: chain_id.clone(),
+        ..Default::default()
+    };
+    let mut proposal = ProposalKind::DaoSpend.template_proposal(chain_id, proposal_id);
+    match &mut proposal.payload {
+        ProposalPayload::DaoSpend {
+            schedule_transactions,
+            cancel_transactions,
+        } => {
+            let (execute_at_height, transaction) = &mut schedule_transactions[0];
+            *execute_at_height = voting_end;
+            transaction.value = plan.encode_to_vec().into();
+            cancel_transactions.clear();
+        }
+        _ => unreachable!("template is a DAO spend proposal"),
+    }
+    let submit = ProposalSubmit {
+        proposal,
+        deposit_amount: chain_params.proposal_deposit_amount,
+    };
+    submit.check_stateless(dummy_context.clone()).await?;
+    submit.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    submit.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // 2. The validator votes for it.
+    let body = ValidatorVoteBody {
+        proposal: proposal_id,
+        vote: Vote::Yes,
+        identity_key,
+        governance_key,
+    };
+    let auth_sig = validator_key
+        .spend_auth_key()
+        .sign(&mut rng, &body.encode_to_vec());
+    let vote = ValidatorVote { body, auth_sig };
+    vote.check_stateless(dummy_context.clone()).await?;
+    vote.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    vote.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // 3. At the end of voting, the proposal passes and is enacted, and the
+    // shielded pool executes the transaction it scheduled.
+    let end_block = abci::request::EndBlock {
+        height: voting_end.try_into().unwrap(),
+    };
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(voting_end);
+    Governance::end_block(&mut state_tx, &end_block).await;
+    ShieldedPool::end_block(&mut state_tx, &end_block).await;
+    state_tx.apply();
+
+    assert!(matches!(
+        state.proposal_state(proposal_id).await?,
+        Some(State::Finished {
+            outcome: Outcome::Passed
+        })
+    ));
+    assert!(state
+        .scheduled_dao_transactions(voting_end)
+        .await?
+        .is_empty());
+    assert_eq!(
+        state.dao_balance(&STAKING_TOKEN_ASSET_ID).await?,
+        9_000_000u64.into()
+    );
+    // Paying out of the DAO doesn't change the total supply.
+    assert_eq!(
+        state
+            .token_supply(&STAKING_TOKEN_ASSET_ID)
+            .await?
+            .unwrap_or(0),
+        supply_before
+    );
+
+    Ok(())
+}
@@ -2,11 +2,16 @@ use std::{collections::BTreeSet, pin::Pin, str::FromStr};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use futures::{Stream, StreamExt};
-use penumbra_crypto::{asset::Amount, stake::IdentityKey};
+use futures::{Stream, StreamExt, TryStreamExt};
+use penumbra_crypto::{asset::Amount, stake::IdentityKey, Nullifier};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_storage::{StateRead, StateWrite};
-use penumbra_transaction::action::{Proposal, ProposalPayload, Vote};
+use penumbra_tct as tct;
+use penumbra_transaction::{
+    action::{Proposal, ProposalPayload, Vote},
+    plan::TransactionPlan,
+    EffectHash,
+};
 
 use super::{
     proposal::{self, ProposalList},
@@ -92,6 +97,50 @@ pub trait StateReadExt: StateRead + crate::stake::StateReadExt {
             .await?)
     }
 
+    /// Get the position of the state commitment tree when voting started for a given proposal.
+    ///
+    /// Delegators can vote with any delegation note committed before this position.
+    async fn proposal_voting_start_position(
+        &self,
+        proposal_id: u64,
+    ) -> Result<Option<tct::Position>> {
+        Ok(self
+            .get_proto::<u64>(&state_key::proposal_voting_start_position(proposal_id))
+            .await?
+            .map(Into::into))
+    }
+
+    /// Check whether a nullifier has already been used to vote on a proposal.
+    async fn has_voted_with_nullifier(
+        &self,
+        proposal_id: u64,
+        nullifier: Nullifier,
+    ) -> Result<bool> {
+        Ok(self
+            .get_proto::<u64>(&state_key::voted_nullifier_lookup(proposal_id, &nullifier))
+            .await?
+            .is_some())
+    }
+
+    /// Get the total amount of each validator's delegation tokens cast by delegators for each vote
+    /// on a proposal.
+    async fn delegator_vote_tallies(
+        &self,
+        proposal_id: u64,
+    ) -> Result<Vec<(IdentityKey, Vote, u64)>> {
+        let prefix = state_key::delegator_vote_tallies(proposal_id);
+        self.prefix_proto::<u64>(&prefix)
+            .map(|r| {
+                let (key, amount) = r?;
+                let mut parts = key.rsplit('/');
+                let vote = parts.next().context("invalid key")?.parse()?;
+                let identity_key = parts.next().context("invalid key")?.parse()?;
+                Ok((identity_key, vote, amount))
+            })
+            .try_collect()
+            .await
+    }
+
     /// Get the total voting power across all validators.
     async fn total_voting_power(&self) -> Result<u64> {
         let mut total = 0;
@@ -105,6 +154,25 @@ pub trait StateReadExt: StateRead + crate::stake::StateReadExt {
 
         Ok(total)
     }
+
+    /// Get the DAO transactions scheduled for execution at a given height, in the order they were
+    /// scheduled, along with the index of each in the schedule for that height.
+    async fn scheduled_dao_transactions(&self, height: u64) -> Result<Vec<(u64, TransactionPlan)>> {
+        let prefix = state_key::scheduled_dao_transactions(height);
+        self.prefix::<TransactionPlan>(&prefix)
+            .map(|r| {
+                let (key, plan) = r?;
+                let index = key
+                    .rsplit('/')
+                    .next()
+                    .context("invalid key")?
+                    .parse()
+                    .context("invalid scheduled transaction index")?;
+                Ok((index, plan))
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + crate::stake::StateReadExt + ?Sized> StateReadExt for T {}
@@ -193,6 +261,34 @@ pub trait StateWriteExt: StateWrite {
         self.put(state_key::validator_vote(proposal_id, identity_key), vote);
     }
 
+    /// Record a delegator vote for a proposal, cast with the delegation note with the given nullifier.
+    async fn cast_delegator_vote(
+        &mut self,
+        proposal_id: u64,
+        identity_key: IdentityKey,
+        vote: Vote,
+        nullifier: Nullifier,
+        delegation_amount: Amount,
+    ) -> Result<()> {
+        // Record the nullifier as having voted, so the same note can't vote twice
+        self.put_proto(
+            state_key::voted_nullifier_lookup(proposal_id, &nullifier),
+            delegation_amount.value(),
+        );
+
+        // Add the delegation tokens to the running tally for that validator and vote
+        let key = state_key::delegator_vote_tally(proposal_id, identity_key, vote);
+        let tally = self.get_proto::<u64>(&key).await?.unwrap_or_default();
+        self.put_proto(
+            key,
+            tally
+                .checked_add(delegation_amount.value())
+                .context("delegator vote tally overflowed")?,
+        );
+
+        Ok(())
+    }
+
     /// Set the proposal voting end block height for a proposal.
     async fn put_proposal_voting_start(&mut self, proposal_id: u64, end_block: u64) {
         self.put_proto(state_key::proposal_voting_start(proposal_id), end_block);
@@ -202,6 +298,55 @@ pub trait StateWriteExt: StateWrite {
     async fn put_proposal_voting_end(&mut self, proposal_id: u64, end_block: u64) {
         self.put_proto(state_key::proposal_voting_end(proposal_id), end_block);
     }
+
+    /// Set the position of the state commitment tree when voting started for a proposal.
+    async fn put_proposal_voting_start_position(
+        &mut self,
+        proposal_id: u64,
+        start_position: tct::Position,
+    ) {
+        self.put_proto(
+            state_key::proposal_voting_start_position(proposal_id),
+            u64::from(start_position),
+        );
+    }
+
+    /// Schedule a DAO transaction for execution at a given height, after any other transactions
+    /// already scheduled for that height.
+    async fn schedule_dao_transaction(&mut self, height: u64, plan: TransactionPlan) -> Result<()> {
+        let index = self
+            .scheduled_dao_transactions(height)
+            .await?
+            .last()
+            .map(|(index, _)| index + 1)
+            .unwrap_or(0);
+        self.put(state_key::scheduled_dao_transaction(height, index), plan);
+        Ok(())
+    }
+
+    /// Cancel the first DAO transaction scheduled at a given height with a matching effect hash,
+    /// returning whether such a transaction was found.
+    async fn cancel_dao_transaction(
+        &mut self,
+        height: u64,
+        effect_hash: EffectHash,
+    ) -> Result<bool> {
+        for (index, plan) in self.scheduled_dao_transactions(height).await? {
+            if plan.dao_effect_hash() == effect_hash {
+                self.delete(state_key::scheduled_dao_transaction(height, index));
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Remove all the DAO transactions scheduled at a given height.
+    async fn clear_scheduled_dao_transactions(&mut self, height: u64) -> Result<()> {
+        for (index, _) in self.scheduled_dao_transactions(height).await? {
+            self.delete(state_key::scheduled_dao_transaction(height, index));
+        }
+        Ok(())
+    }
 }
 
-impl<T: StateWrite + StateReadExt> StateWriteExt for T {}
+impl<T: StateWrite + StateReadExt + ?Sized> StateWriteExt for T {}
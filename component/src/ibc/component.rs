@@ -6,7 +6,7 @@
 pub(crate) mod channel;
 pub(crate) mod client;
 pub(crate) mod connection;
-pub(crate) mod state_key;
+pub mod state_key;
 
 use crate::ibc::transfer::Ics20Transfer;
 use crate::Component;
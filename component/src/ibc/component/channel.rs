@@ -32,7 +32,7 @@ pub(crate) mod execution;
 pub(crate) mod stateful;
 pub(crate) mod stateless;
 
-use stateful::proof_verification::commit_packet;
+use stateful::proof_verification::{commit_acknowledgement, commit_packet};
 
 pub struct Ics4Channel {}
 
@@ -88,6 +88,19 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         self.put_proto::<Vec<u8>>(commitment_key, packet_hash);
     }
 
+    /// Record the commitment to the acknowledgement written for a received
+    /// packet, so that it can be proven to the packet's sender.
+    fn put_packet_acknowledgement(&mut self, packet: &Packet, ack: &[u8]) {
+        self.put_proto::<Vec<u8>>(
+            state_key::packet_acknowledgement(
+                &packet.port_on_b,
+                &packet.chan_on_b,
+                packet.sequence.into(),
+            ),
+            commit_acknowledgement(ack),
+        );
+    }
+
     fn delete_packet_commitment(
         &mut self,
         channel_id: &ChannelId,
@@ -51,6 +51,10 @@ pub fn commit_packet(packet: &Packet) -> Vec<u8> {
     Sha256::digest(&commit).to_vec()
 }
 
+pub fn commit_acknowledgement(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
 fn verify_merkle_absence_proof(
     proof_specs: &ProofSpecs,
     prefix: &CommitmentPrefix,
@@ -90,6 +90,13 @@ pub fn packet_receipt(packet: &Packet) -> String {
     )
 }
 
+pub fn packet_acknowledgement(port_id: &PortId, channel_id: &ChannelId, sequence: u64) -> String {
+    format!(
+        "acks/ports/{}/channels/{}/sequences/{}",
+        port_id, channel_id, sequence
+    )
+}
+
 pub fn packet_commitment(packet: &Packet) -> String {
     format!(
         "commitments/ports/{}/channels/{}/packets/{}",
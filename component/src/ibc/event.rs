@@ -328,6 +328,31 @@ pub fn receive_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
     )
 }
 
+pub fn write_acknowledgement(packet: &Packet, ack: &[u8]) -> Event {
+    Event::new(
+        "write_acknowledgement",
+        vec![
+            ("packet_data_hex", hex::encode(packet.data.clone())).index(),
+            (
+                "packet_timeout_height",
+                packet.timeout_height_on_b.to_string(),
+            )
+                .index(),
+            (
+                "packet_timeout_timestamp",
+                packet.timeout_timestamp_on_b.to_string(),
+            )
+                .index(),
+            ("packet_sequence", packet.sequence.to_string()).index(),
+            ("packet_src_port", packet.port_on_a.to_string()).index(),
+            ("packet_src_channel", packet.chan_on_a.to_string()).index(),
+            ("packet_dst_port", packet.port_on_b.to_string()).index(),
+            ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
+            ("packet_ack_hex", hex::encode(ack)).index(),
+        ],
+    )
+}
+
 pub fn acknowledge_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
     Event::new(
         "acknowledge_packet",
@@ -8,6 +8,8 @@ use ibc::core::ics02_client::client_state::ClientState;
 use ibc::core::ics02_client::height::Height;
 use ibc::core::ics04_channel::channel::State as ChannelState;
 use ibc::core::ics04_channel::packet::Packet;
+use ibc::core::ics04_channel::timeout::TimeoutHeight;
+use ibc::core::ics24_host::identifier::ChainId;
 use ibc::core::ics24_host::identifier::ChannelId;
 use ibc::core::ics24_host::identifier::PortId;
 use penumbra_storage::{StateRead, StateWrite};
@@ -34,7 +36,7 @@ mod private {
 pub struct IBCPacket<S: CheckStatus> {
     pub(crate) source_port: PortId,
     pub(crate) source_channel: ChannelId,
-    pub(crate) timeout_height: Height,
+    pub(crate) timeout_height: TimeoutHeight,
     pub(crate) timeout_timestamp: u64,
     pub(crate) data: Vec<u8>,
 
@@ -56,10 +58,23 @@ impl IBCPacket<Unchecked> {
 
 impl From<Ics20Withdrawal> for IBCPacket<Unchecked> {
     fn from(withdrawal: Ics20Withdrawal) -> Self {
+        // The timeout height is in the counterparty's current revision, which
+        // is encoded in its chain id; a zero height means there's no height timeout.
+        let timeout_height = match withdrawal.timeout_height {
+            0 => TimeoutHeight::Never,
+            height => TimeoutHeight::At(
+                Height::new(
+                    ChainId::chain_version(&withdrawal.destination_chain_id),
+                    height,
+                )
+                .expect("timeout height is non-zero"),
+            ),
+        };
+
         Self {
             source_port: withdrawal.source_port.clone(),
             source_channel: withdrawal.source_channel.clone(),
-            timeout_height: ibc::Height::new(0, withdrawal.timeout_height).unwrap(),
+            timeout_height,
             timeout_timestamp: withdrawal.timeout_time,
             data: withdrawal.packet_data(),
 
@@ -112,11 +127,11 @@ pub trait SendPacketRead: StateRead {
 
         // check that time timeout height hasn't already pased in the local client tracking the
         // receiving chain
-        if packet.timeout_height <= latest_height {
+        if packet.timeout_height.has_expired(latest_height) {
             return Err(anyhow::anyhow!(
                 "timeout height {} is less than the latest height {}",
                 packet.timeout_height,
-                latest_height.revision_height()
+                latest_height
             ));
         }
 
@@ -159,7 +174,7 @@ pub trait SendPacketWrite: StateWrite {
             chan_on_b: ChannelId::default(),
             port_on_b: PortId::default(),
 
-            timeout_height_on_b: packet.timeout_height.into(),
+            timeout_height_on_b: packet.timeout_height,
             timeout_timestamp_on_b: ibc::timestamp::Timestamp::from_nanoseconds(
                 packet.timeout_timestamp,
             )
@@ -1,6 +1,9 @@
+use crate::ibc::component::channel::StateWriteExt as _;
 use crate::ibc::component::state_key;
+use crate::ibc::event;
 use crate::ibc::ibc_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute};
 use crate::ibc::packet::{IBCPacket, Unchecked};
+use crate::shielded_pool::{NoteManager, SupplyWrite};
 use crate::Component;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -15,14 +18,15 @@ use ibc::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
 use ibc::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use ibc::core::ics04_channel::msgs::timeout::MsgTimeout;
+use ibc::core::ics04_channel::packet::Packet;
 use ibc::core::ics04_channel::Version;
 use ibc::core::ics24_host::identifier::{ChannelId, PortId};
-use penumbra_chain::genesis;
+use penumbra_chain::{genesis, NoteSource};
 use penumbra_crypto::asset::Denom;
-use penumbra_crypto::{asset, Amount};
+use penumbra_crypto::{asset, Address, Amount, Value};
 use penumbra_proto::core::ibc::v1alpha1::FungibleTokenPacketData;
 use penumbra_proto::{StateReadProto, StateWriteProto};
-use penumbra_storage::{StateRead, StateWrite};
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
 use penumbra_transaction::action::Ics20Withdrawal;
 use prost::Message;
 
@@ -44,6 +48,184 @@ fn is_source(source_port: &PortId, source_channel: &ChannelId, denom: &Denom) ->
     !denom.starts_with(&prefix)
 }
 
+/// The acknowledgement written for a successfully received ICS-20 packet.
+const SUCCESS_ACKNOWLEDGEMENT: &[u8] = br#"{"result":"AQ=="}"#;
+
+/// The acknowledgement written for an ICS-20 packet that could not be received,
+/// which instructs the sending chain to refund the sender.
+fn error_acknowledgement(error: &anyhow::Error) -> Vec<u8> {
+    serde_json::json!({ "error": format!("{:#}", error) })
+        .to_string()
+        .into_bytes()
+}
+
+/// The token supply change for minting `amount` of value that is already
+/// counted in the supply, e.g. because it was held in escrow.
+fn supply_decrease(amount: Amount) -> Result<i64> {
+    i64::try_from(amount.value())
+        .map(|amount| -amount)
+        .map_err(|_| anyhow::anyhow!("amount {} overflows the token supply change", amount))
+}
+
+/// The source to record for notes created by an ICS-20 packet.
+///
+/// Packets are delivered by relayer transactions, so notes are attributed to
+/// the enclosing transaction where there is one, which lets the view service
+/// surface them as inbound transactions.
+fn packet_note_source<S: StateRead>(state: &S, packet: &Packet) -> NoteSource {
+    state
+        .object_get("source")
+        .unwrap_or(NoteSource::Ics20Transfer {
+            sequence: packet.sequence.into(),
+        })
+}
+
+/// Check that the sender of an ICS-20 packet sent by this chain can be
+/// refunded, because the packet timed out or was rejected by the receiver.
+async fn refund_tokens_check<S: StateRead>(state: S, packet: &Packet) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let denom: asset::Denom = packet_data.denom.as_str().try_into()?;
+    let _sender: Address = packet_data.sender.parse()?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        // check if we have enough balance to refund tokens to sender
+        let value_balance: Amount = state
+            .get(&state_key::ics20_value_balance(
+                &packet.chan_on_a,
+                &denom.id(),
+            ))
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        let amount_penumbra: Amount = packet_data.amount.try_into()?;
+        if value_balance < amount_penumbra {
+            return Err(anyhow::anyhow!(
+                "insufficient balance to refund tokens to sender"
+            ));
+        }
+    }
+
+    let amount: Amount = packet_data.amount.try_into()?;
+    supply_decrease(amount)?;
+
+    Ok(())
+}
+
+/// Refund the sender of an ICS-20 packet sent by this chain, by minting a new
+/// note to the withdrawal's return address.
+///
+/// Native tokens are released from escrow; vouchers, which were burned by the
+/// withdrawal, are minted again.
+async fn refund_tokens<S: StateWrite>(state: &mut S, packet: &Packet) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let denom: asset::Denom = packet_data.denom.as_str().try_into()?;
+    let amount: Amount = packet_data.amount.try_into()?;
+    let sender: Address = packet_data.sender.parse()?;
+    let source = packet_note_source(&*state, packet);
+    let value = Value {
+        amount,
+        asset_id: denom.id(),
+    };
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        let escrow_key = state_key::ics20_value_balance(&packet.chan_on_a, &value.asset_id);
+        let value_balance: Amount = state.get(&escrow_key).await?.unwrap_or_else(Amount::zero);
+        if value_balance < amount {
+            return Err(anyhow::anyhow!(
+                "insufficient balance to refund tokens to sender"
+            ));
+        }
+        state.put(escrow_key, value_balance - amount);
+    }
+
+    // Withdrawn value is removed from the shielded pool without being removed
+    // from the token supply, so don't count it twice when minting the refund.
+    state
+        .update_token_supply(&value.asset_id, supply_decrease(amount)?)
+        .await?;
+    state.mint_note(value, &sender, source).await?;
+
+    tracing::debug!(
+        ?value,
+        sequence = u64::from(packet.sequence),
+        "refunded ics20 withdrawal"
+    );
+
+    Ok(())
+}
+
+/// Whether an ICS-20 acknowledgement reports that the packet was rejected.
+fn is_error_acknowledgement(ack: &[u8]) -> bool {
+    // Anything other than a well-formed success acknowledgement is treated as
+    // an error, so that a malformed acknowledgement can't strand funds.
+    !matches!(
+        serde_json::from_slice::<serde_json::Value>(ack),
+        Ok(serde_json::Value::Object(fields)) if fields.contains_key("result")
+    )
+}
+
+/// Credit the receiver of an ICS-20 packet, either by releasing escrowed
+/// native tokens or by minting vouchers for bridged tokens.
+///
+/// This can fail after writing some state, so callers must discard the state
+/// on error.
+async fn receive_tokens<S: StateWrite>(state: &mut S, msg: &MsgRecvPacket) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
+    let denom: asset::Denom = packet_data.denom.as_str().try_into()?;
+    let amount: Amount = packet_data.amount.try_into()?;
+    let receiver: Address = packet_data.receiver.parse()?;
+    let source = packet_note_source(&*state, &msg.packet);
+
+    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
+        // the sender is the source, so mint vouchers for the bridged tokens,
+        // prefixed with the port and channel they arrived on.
+        let voucher_denom: asset::Denom = format!(
+            "{}/{}/{}",
+            msg.packet.port_on_b, msg.packet.chan_on_b, denom
+        )
+        .as_str()
+        .try_into()?;
+        let value = Value {
+            amount,
+            asset_id: voucher_denom.id(),
+        };
+
+        state.register_denom(&voucher_denom).await?;
+        state.mint_note(value, &receiver, source).await?;
+    } else {
+        // we are the source, so release the escrowed tokens, which were sent
+        // with our port and channel prepended to their denom.
+        let prefix = format!("{}/{}/", msg.packet.port_on_a, msg.packet.chan_on_a);
+        let unprefixed_denom: asset::Denom = denom
+            .to_string()
+            .strip_prefix(&prefix)
+            .expect("denom starts with the sender's prefix")
+            .try_into()?;
+        let value = Value {
+            amount,
+            asset_id: unprefixed_denom.id(),
+        };
+
+        let escrow_key = state_key::ics20_value_balance(&msg.packet.chan_on_b, &value.asset_id);
+        let value_balance: Amount = state.get(&escrow_key).await?.unwrap_or_else(Amount::zero);
+        if value_balance < amount {
+            return Err(anyhow::anyhow!(
+                "insufficient balance to unescrow tokens to receiver"
+            ));
+        }
+
+        state.put(escrow_key, value_balance - amount);
+        // The escrowed tokens were never removed from the token supply, so
+        // don't count them twice when minting them back into the shielded pool.
+        state
+            .update_token_supply(&value.asset_id, supply_decrease(amount)?)
+            .await?;
+        state.mint_note(value, &receiver, source).await?;
+    }
+
+    Ok(())
+}
+
 #[derive(Clone)]
 pub struct Ics20Transfer {}
 
@@ -181,13 +363,20 @@ impl AppHandlerCheck for Ics20Transfer {
         let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
         let denom: asset::Denom = packet_data.denom.as_str().try_into()?;
 
-        // 2. check if we are the source chain for the denom.
-        if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
+        // 2. check if we are the source chain for the denom, i.e., the sender
+        // is not.
+        if !is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
             // check if we have enough balance to unescrow tokens to receiver
+            let prefix = format!("{}/{}/", msg.packet.port_on_a, msg.packet.chan_on_a);
+            let unprefixed_denom: asset::Denom = denom
+                .to_string()
+                .strip_prefix(&prefix)
+                .expect("denom starts with the sender's prefix")
+                .try_into()?;
             let value_balance: Amount = state
                 .get(&state_key::ics20_value_balance(
-                    &msg.packet.chan_on_a,
-                    &denom.id(),
+                    &msg.packet.chan_on_b,
+                    &unprefixed_denom.id(),
                 ))
                 .await?
                 .unwrap_or_else(Amount::zero);
@@ -204,34 +393,18 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 
     async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
-        let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-        let denom: asset::Denom = packet_data.denom.as_str().try_into()?;
-
-        if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
-            // check if we have enough balance to refund tokens to sender
-            let value_balance: Amount = state
-                .get(&state_key::ics20_value_balance(
-                    &msg.packet.chan_on_a,
-                    &denom.id(),
-                ))
-                .await?
-                .unwrap_or_else(Amount::zero);
-
-            let amount_penumbra: Amount = packet_data.amount.try_into()?;
-            if value_balance < amount_penumbra {
-                return Err(anyhow::anyhow!(
-                    "insufficient balance to refund tokens to sender"
-                ));
-            }
-        }
-
-        Ok(())
+        refund_tokens_check(state, &msg.packet).await
     }
 
     async fn acknowledge_packet_check<S: StateRead>(
-        _state: S,
-        _msg: &MsgAcknowledgement,
+        state: S,
+        msg: &MsgAcknowledgement,
     ) -> Result<()> {
+        let ack: Vec<u8> = msg.acknowledgement.clone().into();
+        if is_error_acknowledgement(&ack) {
+            refund_tokens_check(state, &msg.packet).await?;
+        }
+
         Ok(())
     }
 }
@@ -244,11 +417,45 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
     async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
-    async fn recv_packet_execute<S: StateWrite>(_state: S, _msg: &MsgRecvPacket) {
-        // parse if we are source or dest, and mint or burn accordingly
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
+        // Application-level failures are reported to the sender with an error
+        // acknowledgement, rather than by failing the transaction, so that the
+        // sending chain can refund the tokens. The tokens are received in a
+        // fork of the state, which is discarded on failure.
+        let mut delta = StateDelta::new(&mut state);
+        let ack = match receive_tokens(&mut delta, msg).await {
+            Ok(()) => {
+                let (_, events) = delta.apply();
+                for event in events {
+                    state.record(event);
+                }
+                SUCCESS_ACKNOWLEDGEMENT.to_vec()
+            }
+            Err(e) => {
+                tracing::debug!(error = ?e, "failed to receive ics20 packet");
+                drop(delta);
+                error_acknowledgement(&e)
+            }
+        };
+
+        state.put_packet_acknowledgement(&msg.packet, &ack);
+        state.record(event::write_acknowledgement(&msg.packet, &ack));
+    }
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
+        // the refund was checked in timeout_packet_check.
+        refund_tokens(&mut state, &msg.packet)
+            .await
+            .expect("able to refund timed out ics20 packet");
+    }
+    async fn acknowledge_packet_execute<S: StateWrite>(mut state: S, msg: &MsgAcknowledgement) {
+        let ack: Vec<u8> = msg.acknowledgement.clone().into();
+        if is_error_acknowledgement(&ack) {
+            // the refund was checked in acknowledge_packet_check.
+            refund_tokens(&mut state, &msg.packet)
+                .await
+                .expect("able to refund rejected ics20 packet");
+        }
     }
-    async fn timeout_packet_execute<S: StateWrite>(_state: S, _msg: &MsgTimeout) {}
-    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
 }
 
 impl AppHandler for Ics20Transfer {}
@@ -264,3 +471,24 @@ impl Component for Ics20Transfer {
     #[instrument(name = "ics20_channel", skip(_state, _end_block))]
     async fn end_block<S: StateWrite>(_state: S, _end_block: &abci::request::EndBlock) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acknowledgement_errors() {
+        assert!(!is_error_acknowledgement(SUCCESS_ACKNOWLEDGEMENT));
+        assert!(is_error_acknowledgement(&error_acknowledgement(
+            &anyhow::anyhow!("bad packet")
+        )));
+        assert!(is_error_acknowledgement(b"not json"));
+        assert!(is_error_acknowledgement(br#"["result"]"#));
+    }
+
+    #[test]
+    fn supply_decrease_is_checked() {
+        assert_eq!(supply_decrease(Amount::from(5u64)).unwrap(), -5);
+        assert!(supply_decrease(Amount::from(u64::MAX)).is_err());
+    }
+}
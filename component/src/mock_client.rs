@@ -103,7 +103,7 @@ impl MockClient {
                         }
                     }
                 }
-                StatePayload::RolledUp(commitment) => {
+                StatePayload::RolledUp(commitment) | StatePayload::Position { commitment, .. } => {
                     if self.notes.contains_key(&commitment) {
                         // This is a note we anticipated, so retain its auth path.
                         self.sct.insert(Keep, commitment)?;
@@ -112,7 +112,6 @@ impl MockClient {
                         self.sct.insert(Forget, commitment)?;
                     }
                 }
-                StatePayload::Position { .. } => todo!(),
             }
         }
         self.sct.end_block()?;
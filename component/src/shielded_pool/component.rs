@@ -11,7 +11,7 @@ use tendermint::abci;
 
 use crate::shielded_pool::state_key;
 
-use super::{NoteManager, SupplyWrite};
+use super::{DaoWrite, NoteManager, SupplyWrite};
 
 pub struct ShieldedPool {}
 
@@ -43,6 +43,32 @@ impl Component for ShieldedPool {
                 .unwrap();
         }
 
+        for allocation in &app_state.dao_allocations {
+            tracing::info!(?allocation, "processing DAO allocation");
+
+            assert_ne!(
+                allocation.amount, 0u64,
+                "Genesis DAO allocations contain empty value",
+            );
+
+            let unit = asset::REGISTRY.parse_unit(&allocation.denom);
+            let amount = allocation.amount * 10u64.pow(unit.exponent().into());
+
+            state.register_denom(&unit.base()).await.unwrap();
+            // The DAO's balance counts towards the token supply, just like a note.
+            state
+                .update_token_supply(&unit.id(), amount.try_into().unwrap())
+                .await
+                .unwrap();
+            state
+                .dao_deposit(Value {
+                    amount: amount.into(),
+                    asset_id: unit.id(),
+                })
+                .await
+                .unwrap();
+        }
+
         let mut compact_block = state.stub_compact_block();
         let mut state_commitment_tree = state.stub_state_commitment_tree().await;
 
@@ -77,7 +103,14 @@ impl Component for ShieldedPool {
         compact_block.height = height;
         state.stub_put_compact_block(compact_block);
 
-        // TODO: execute any scheduled DAO spend transactions for this block
+        // Execute any DAO transactions scheduled for this block
+        if let Err(error) = state.execute_scheduled_dao_transactions(height).await {
+            tracing::error!(
+                %height,
+                ?error,
+                "unable to execute scheduled DAO transactions"
+            );
+        }
 
         // We need to reload the compact block here, in case it was
         // edited during the preceding method calls.
@@ -137,6 +170,12 @@ pub trait StateReadExt: StateRead {
         Ok(())
     }
 
+    /// Returns the height at which the given nullifier was spent, if it has been spent.
+    async fn nullifier_spend_height(&self, nullifier: Nullifier) -> Result<Option<u64>> {
+        self.get_proto(&state_key::spent_nullifier_height(&nullifier))
+            .await
+    }
+
     /// Returns the SCT anchor for the given height.
     async fn anchor_by_height(&self, height: u64) -> Result<Option<tct::Root>> {
         self.get(&state_key::anchor_by_height(height)).await
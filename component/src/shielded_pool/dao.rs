@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_chain::NoteSource;
+use penumbra_crypto::{asset, Amount, Value};
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
+use penumbra_transaction::plan::TransactionPlan;
+use tracing::instrument;
+
+use super::{state_key, NoteManager, SupplyWrite};
+use crate::governance::{StateReadExt as _, StateWriteExt as _};
+
+#[async_trait]
+pub trait DaoRead: StateRead {
+    /// Get the amount of the given asset held by the DAO.
+    async fn dao_balance(&self, asset_id: &asset::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::dao_balance(asset_id))
+            .await?
+            .unwrap_or_else(Amount::zero))
+    }
+
+    /// Check whether the DAO holds enough value to pay all the outputs of a transaction plan.
+    async fn dao_can_afford(&self, plan: &TransactionPlan) -> Result<bool> {
+        let mut required = BTreeMap::<asset::Id, u64>::new();
+        for output in plan.output_plans() {
+            let total = required.entry(output.value.asset_id).or_default();
+            *total = match total.checked_add(output.value.amount.value()) {
+                Some(total) => total,
+                None => return Ok(false),
+            };
+        }
+
+        for (asset_id, amount) in required {
+            if self.dao_balance(&asset_id).await?.value() < amount {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<T: StateRead + ?Sized> DaoRead for T {}
+
+#[async_trait]
+pub trait DaoWrite: StateWrite {
+    /// Add value to the DAO's balance.
+    ///
+    /// The deposited value must already have been removed from circulation,
+    /// but it remains part of the token supply while it is held by the DAO.
+    #[instrument(skip(self))]
+    async fn dao_deposit(&mut self, value: Value) -> Result<()> {
+        let balance = self.dao_balance(&value.asset_id).await?;
+        let new_balance = balance
+            .value()
+            .checked_add(value.amount.value())
+            .ok_or_else(|| anyhow::anyhow!("overflow depositing {:?} to the DAO", value))?;
+        self.put(
+            state_key::dao_balance(&value.asset_id),
+            Amount::from(new_balance),
+        );
+        Ok(())
+    }
+
+    /// Remove value from the DAO's balance, failing if the DAO does not hold enough of it.
+    #[instrument(skip(self))]
+    async fn dao_withdraw(&mut self, value: Value) -> Result<()> {
+        let balance = self.dao_balance(&value.asset_id).await?;
+        let new_balance = balance
+            .value()
+            .checked_sub(value.amount.value())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "DAO balance of {} is insufficient to withdraw {:?}",
+                    balance,
+                    value
+                )
+            })?;
+        self.put(
+            state_key::dao_balance(&value.asset_id),
+            Amount::from(new_balance),
+        );
+        Ok(())
+    }
+
+    /// Execute the DAO transactions scheduled for the given height, in the
+    /// order they were scheduled, and remove them from the schedule.
+    ///
+    /// Each transaction is executed in its entirety or not at all: one that
+    /// can't be executed, e.g. because its outputs exceed the DAO's balance,
+    /// is logged and dropped from the schedule, so that it can't halt the chain.
+    #[instrument(skip(self))]
+    async fn execute_scheduled_dao_transactions(&mut self, height: u64) -> Result<()>
+    where
+        Self: Sized,
+    {
+        for (index, plan) in self.scheduled_dao_transactions(height).await? {
+            if !self.dao_can_afford(&plan).await? {
+                tracing::warn!(
+                    %height,
+                    %index,
+                    "DAO balance is insufficient for scheduled transaction, skipping it"
+                );
+                continue;
+            }
+
+            // Execute the transaction in a fork of the state, so that its
+            // writes are discarded if any of its outputs fails.
+            let mut delta = StateDelta::new(&mut *self);
+            match execute_dao_transaction(&mut delta, height, &plan).await {
+                Ok(()) => {
+                    let (state, events) = delta.apply();
+                    for event in events {
+                        state.record(event);
+                    }
+                    tracing::debug!(%height, %index, "executed scheduled DAO transaction");
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        %height,
+                        %index,
+                        ?error,
+                        "failed to execute scheduled DAO transaction, dropping it"
+                    );
+                }
+            }
+        }
+
+        self.clear_scheduled_dao_transactions(height).await
+    }
+}
+
+impl<T: StateWrite + ?Sized> DaoWrite for T {}
+
+/// Pay out the outputs of a DAO transaction from the DAO's balance.
+async fn execute_dao_transaction<S: StateWrite>(
+    mut state: S,
+    height: u64,
+    plan: &TransactionPlan,
+) -> Result<()> {
+    for output in plan.output_plans() {
+        state.dao_withdraw(output.value).await?;
+        // The value was already counted in the token supply while it was
+        // held by the DAO, so don't count it twice when minting it.
+        let amount = i64::try_from(output.value.amount.value()).map_err(|_| {
+            anyhow::anyhow!(
+                "DAO output of {:?} overflows the supply change",
+                output.value
+            )
+        })?;
+        state
+            .update_token_supply(&output.value.asset_id, -amount)
+            .await?;
+        state
+            .mint_note(
+                output.value,
+                &output.dest_address,
+                NoteSource::DaoOutput { height },
+            )
+            .await?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 mod component;
 pub(crate) mod consensus_rules;
+mod dao;
 pub(crate) mod event;
 mod metrics;
 mod note_manager;
@@ -10,5 +11,9 @@ pub mod state_key;
 pub use self::metrics::register_metrics;
 pub(crate) use component::StateWriteExt;
 pub use component::{ShieldedPool, StateReadExt};
+pub use dao::{DaoRead, DaoWrite};
 pub use note_manager::NoteManager;
 pub use supply::{SupplyRead, SupplyWrite};
+
+#[cfg(test)]
+mod tests;
@@ -4,7 +4,7 @@ use super::{
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use penumbra_chain::{sync::StatePayload, NoteSource};
+use penumbra_chain::{sync::StatePayload, NoteSource, StateReadExt as _};
 use penumbra_crypto::{Address, Note, Nullifier, Rseed, Value};
 use penumbra_proto::StateWriteProto;
 use penumbra_storage::StateWrite;
@@ -91,7 +91,7 @@ pub trait NoteManager: StateWrite {
     }
 
     #[instrument(skip(self, source))]
-    async fn spend_nullifier(&mut self, nullifier: Nullifier, source: NoteSource) {
+    async fn spend_nullifier(&mut self, nullifier: Nullifier, source: NoteSource) -> Result<()> {
         tracing::debug!("marking as spent");
 
         // We need to record the nullifier as spent in the JMT (to prevent
@@ -103,12 +103,18 @@ pub trait NoteManager: StateWrite {
             // here lets us find out what transaction spent the nullifier.
             source,
         );
+        // Record the height of the spend, so that governance can tell whether
+        // a note was spent before voting on a proposal started.
+        let height = self.get_block_height().await?;
+        self.put_proto(state_key::spent_nullifier_height(&nullifier), height);
         // Also record an ABCI event for transaction indexing.
         self.record(event::spend(&nullifier));
 
         let mut compact_block = self.stub_compact_block();
         compact_block.nullifiers.push(nullifier);
         self.stub_put_compact_block(compact_block);
+
+        Ok(())
     }
 }
 
@@ -9,6 +9,10 @@ pub fn token_supply(asset_id: &asset::Id) -> String {
     format!("shielded_pool/assets/{}/token_supply", asset_id)
 }
 
+pub fn dao_balance(asset_id: &asset::Id) -> String {
+    format!("shielded_pool/dao/balance/{}", asset_id)
+}
+
 pub fn known_assets() -> &'static str {
     "shielded_pool/known_assets"
 }
@@ -53,6 +57,10 @@ pub fn spent_nullifier_lookup(nullifier: &Nullifier) -> String {
     format!("shielded_pool/spent_nullifiers/{}", nullifier)
 }
 
+pub fn spent_nullifier_height(nullifier: &Nullifier) -> String {
+    format!("shielded_pool/spent_nullifier_height/{}", nullifier)
+}
+
 // TODO: refactor this out (shielded pool should use an extension trait, not re-export other components' internals)
 // pub use crate::stake::state_key::slashed_validators;
 
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use penumbra_chain::{test_keys, StateReadExt as _};
+use penumbra_crypto::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+use penumbra_transaction::plan::{OutputPlan, TransactionPlan};
+use rand_core::SeedableRng;
+
+use crate::{
+    governance::{StateReadExt as _, StateWriteExt as _},
+    TempStorageExt,
+};
+
+use super::{DaoRead, DaoWrite, SupplyRead};
+
+#[tokio::test]
+async fn scheduled_dao_transactions() -> anyhow::Result<()> {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let chain_id = state.get_chain_id().await?;
+
+    let dao_spend = |amount: u64, rng: &mut rand_chacha::ChaChaRng| TransactionPlan {
+        actions: vec![OutputPlan::new(
+            rng,
+            Value {
+                amount: amount.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            *test_keys::ADDRESS_0,
+        )
+        .into()],
+        chain_id: chain_id.clone(),
+        ..Default::default()
+    };
+    let first = dao_spend(600, &mut rng);
+    let second = dao_spend(600, &mut rng);
+    let third = dao_spend(300, &mut rng);
+
+    let height = 10;
+    let supply_before = state
+        .token_supply(&STAKING_TOKEN_ASSET_ID)
+        .await?
+        .unwrap_or(0);
+
+    // Fund the DAO, and schedule the first transaction twice, followed by the
+    // other two.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx
+        .dao_deposit(Value {
+            amount: 1000u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        })
+        .await?;
+    for plan in [&first, &first, &second, &third] {
+        state_tx
+            .schedule_dao_transaction(height, plan.clone())
+            .await?;
+    }
+    state_tx.apply();
+
+    // Cancelling removes only the first matching scheduled transaction.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert!(
+        state_tx
+            .cancel_dao_transaction(height, first.dao_effect_hash())
+            .await?
+    );
+    assert!(
+        !state_tx
+            .cancel_dao_transaction(height + 1, first.dao_effect_hash())
+            .await?
+    );
+    state_tx.apply();
+
+    let scheduled = state.scheduled_dao_transactions(height).await?;
+    assert_eq!(
+        scheduled
+            .iter()
+            .map(|(_, plan)| plan.dao_effect_hash())
+            .collect::<Vec<_>>(),
+        vec![
+            first.dao_effect_hash(),
+            second.dao_effect_hash(),
+            third.dao_effect_hash()
+        ]
+    );
+
+    // Executing the schedule pays out the first transaction, skips the second
+    // because the DAO can no longer afford it, and pays out the third.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.execute_scheduled_dao_transactions(height).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state.dao_balance(&STAKING_TOKEN_ASSET_ID).await?,
+        100u64.into()
+    );
+    assert!(state.scheduled_dao_transactions(height).await?.is_empty());
+    // Spending from the DAO doesn't change the total supply.
+    assert_eq!(
+        state
+            .token_supply(&STAKING_TOKEN_ASSET_ID)
+            .await?
+            .unwrap_or(0),
+        supply_before
+    );
+
+    Ok(())
+}
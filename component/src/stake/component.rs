@@ -18,7 +18,7 @@ use penumbra_crypto::{
 };
 use penumbra_proto::{
     state::future::{DomainFuture, ProtoFuture},
-    StateReadProto, StateWriteProto,
+    DomainType, StateReadProto, StateWriteProto,
 };
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_transaction::action::{Delegate, Undelegate};
@@ -39,7 +39,7 @@ use crate::stake::{
     rate::{BaseRateData, RateData},
     state_key,
     validator::{self, Validator},
-    DelegationChanges, Uptime,
+    DelegationChanges, FundingStreamPayout, Uptime,
 };
 
 use crate::shielded_pool::{NoteManager, SupplyRead, SupplyWrite};
@@ -424,7 +424,7 @@ pub(crate) trait StakingImpl: StateWriteExt {
             // should still be rewarded.
             if validator_state == validator::State::Active {
                 // distribute validator commission
-                for stream in funding_streams {
+                for (stream_index, stream) in funding_streams.into_iter().enumerate() {
                     let commission_reward_amount = stream.reward_amount(
                         delegation_token_supply,
                         &next_base_rate,
@@ -442,6 +442,17 @@ pub(crate) trait StakingImpl: StateWriteExt {
                         },
                     )
                     .await?;
+
+                    // Record what was actually paid, for operators' accounting.
+                    self.record_funding_stream_payout(
+                        stream_index,
+                        FundingStreamPayout {
+                            identity_key: validator.identity_key,
+                            epoch_index: epoch_to_end.index,
+                            address: stream.address,
+                            amount: commission_reward_amount.into(),
+                        },
+                    );
                 }
             }
 
@@ -990,6 +1001,16 @@ pub trait StateReadExt: StateRead {
         Ok(compounded)
     }
 
+    /// Returns the payouts of the given validator's commission to its funding
+    /// streams, ordered by epoch.
+    async fn funding_stream_payouts(&self, id: &IdentityKey) -> Result<Vec<FundingStreamPayout>> {
+        let prefix = state_key::internal::funding_stream_payouts::prefix(id);
+        self.nonconsensus_prefix_raw(prefix.as_bytes())
+            .map(|entry| FundingStreamPayout::decode(entry?.1.as_slice()))
+            .try_collect()
+            .await
+    }
+
     async fn current_base_rate(&self) -> Result<BaseRateData> {
         self.get(state_key::current_base_rate())
             .await
@@ -1209,6 +1230,20 @@ pub trait StateWriteExt: StateWrite {
         self.put_stub_delegation_changes(changes);
     }
 
+    /// Indexes a payout of a validator's commission to the funding stream at
+    /// `stream_index` in its definition.
+    fn record_funding_stream_payout(&mut self, stream_index: usize, payout: FundingStreamPayout) {
+        self.nonconsensus_put_raw(
+            state_key::internal::funding_stream_payouts::key(
+                &payout.identity_key,
+                payout.epoch_index,
+                stream_index,
+            )
+            .into_bytes(),
+            payout.encode_to_vec(),
+        );
+    }
+
     #[instrument(skip(self))]
     async fn set_base_rates(&mut self, current: BaseRateData, next: BaseRateData) {
         tracing::debug!("setting base rates");
@@ -1,4 +1,4 @@
-use penumbra_crypto::Address;
+use penumbra_crypto::{stake::IdentityKey, Address, Amount};
 use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -70,6 +70,55 @@ impl TryFrom<pb::FundingStream> for FundingStream {
     }
 }
 
+/// A payout of a validator's commission to one of its funding streams, made at
+/// the end of an epoch.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[serde(try_from = "pb::FundingStreamPayout", into = "pb::FundingStreamPayout")]
+pub struct FundingStreamPayout {
+    /// The identity key of the validator whose commission was paid out.
+    pub identity_key: IdentityKey,
+    /// The index of the epoch the commission was earned in.
+    pub epoch_index: u64,
+    /// The destination address of the funding stream.
+    pub address: Address,
+    /// The amount of the staking token paid out.
+    pub amount: Amount,
+}
+
+impl DomainType for FundingStreamPayout {
+    type Proto = pb::FundingStreamPayout;
+}
+
+impl From<FundingStreamPayout> for pb::FundingStreamPayout {
+    fn from(payout: FundingStreamPayout) -> Self {
+        pb::FundingStreamPayout {
+            identity_key: Some(payout.identity_key.into()),
+            epoch_index: payout.epoch_index,
+            address: payout.address.to_string(),
+            amount: Some(payout.amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FundingStreamPayout> for FundingStreamPayout {
+    type Error = anyhow::Error;
+
+    fn try_from(payout: pb::FundingStreamPayout) -> Result<Self, Self::Error> {
+        Ok(FundingStreamPayout {
+            identity_key: payout
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                .try_into()?,
+            epoch_index: payout.epoch_index,
+            address: payout.address.parse()?,
+            amount: payout
+                .amount
+                .ok_or_else(|| anyhow::anyhow!("missing amount"))?
+                .try_into()?,
+        })
+    }
+}
+
 /// A list of funding streams whose total commission is less than 100%.
 ///
 /// The total commission of a validator is the sum of the individual reward rate of the
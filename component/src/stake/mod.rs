@@ -18,5 +18,5 @@ pub use self::metrics::register_metrics;
 pub use changes::DelegationChanges;
 pub use component::StateReadExt;
 pub use current_consensus_keys::CurrentConsensusKeys;
-pub use funding_stream::{FundingStream, FundingStreams};
+pub use funding_stream::{FundingStream, FundingStreamPayout, FundingStreams};
 pub use uptime::Uptime;
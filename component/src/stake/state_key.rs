@@ -81,6 +81,8 @@ pub fn current_consensus_keys() -> &'static str {
 }
 
 pub(super) mod internal {
+    use super::*;
+
     pub fn stub_delegation_changes() -> &'static str {
         "staking/delegation_changes"
     }
@@ -88,6 +90,25 @@ pub(super) mod internal {
     pub fn stub_tendermint_validator_updates() -> &'static str {
         "staking/tendermint_validator_updates"
     }
+
+    /// Funding stream payouts are only indexed for queries, so they're kept
+    /// in the nonconsensus store.
+    pub mod funding_stream_payouts {
+        use super::*;
+
+        pub fn prefix(id: &IdentityKey) -> String {
+            format!("staking/funding_stream_payouts/{}/", id)
+        }
+
+        pub fn key(id: &IdentityKey, epoch_index: u64, stream_index: usize) -> String {
+            // Load-bearing format string: we need to pad with 0s to ensure that
+            // the lex order agrees with the numeric order on epochs.
+            format!(
+                "staking/funding_stream_payouts/{}/{:010}/{:05}",
+                id, epoch_index, stream_index
+            )
+        }
+    }
 }
 
 #[cfg(test)]
@@ -140,4 +161,22 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(range, vec![k791.clone(), k792.clone(), k793.clone(),]);
     }
+
+    #[test]
+    fn funding_stream_payouts_sorting() {
+        use internal::funding_stream_payouts::{key, prefix};
+
+        let sk = penumbra_crypto::rdsa::SigningKey::new(OsRng);
+        let ik = IdentityKey((&sk).into());
+
+        let keys = vec![key(&ik, 10, 0), key(&ik, 9, 1), key(&ik, 9, 0)]
+            .into_iter()
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Payouts are ordered by epoch, then by funding stream.
+        assert_eq!(keys, vec![key(&ik, 9, 0), key(&ik, 9, 1), key(&ik, 10, 0)]);
+        assert!(keys.iter().all(|k| k.starts_with(&prefix(&ik))));
+    }
 }
@@ -3,7 +3,7 @@ use std::ops::Deref;
 use ark_ff::PrimeField;
 use ark_r1cs_std::prelude::*;
 use ark_r1cs_std::uint8::UInt8;
-use ark_relations::r1cs::SynthesisError;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
 use decaf377::r1cs::ElementVar;
 use decaf377::r1cs::FqVar;
 use decaf377::Fq;
@@ -34,21 +34,35 @@ impl ValueVar {
         &self,
         value_blinding: Vec<UInt8<Fq>>,
     ) -> Result<BalanceCommitmentVar, SynthesisError> {
+        let commitment =
+            self.unblinded_commitment()? + blinding_commitment(self.amount().cs(), value_blinding)?;
+
+        Ok(BalanceCommitmentVar { inner: commitment })
+    }
+
+    /// The commitment to this value with a zero blinding factor, i.e. the
+    /// value generator for its asset ID multiplied by its amount.
+    pub fn unblinded_commitment(&self) -> Result<ElementVar, SynthesisError> {
         let cs = self.amount().cs();
         let value_generator = FqVar::new_constant(cs.clone(), *VALUE_GENERATOR_DOMAIN_SEP)?;
-        let value_blinding_generator =
-            ElementVar::new_constant(cs.clone(), *VALUE_BLINDING_GENERATOR)?;
 
         let hashed_asset_id = poseidon377::r1cs::hash_1(cs, &value_generator, self.asset_id())?;
         let asset_generator = ElementVar::encode_to_curve(&hashed_asset_id)?;
         let value_amount = self.amount();
-        let commitment = asset_generator.scalar_mul_le(value_amount.to_bits_le()?.iter())?
-            + value_blinding_generator.scalar_mul_le(value_blinding.to_bits_le()?.iter())?;
-
-        Ok(BalanceCommitmentVar { inner: commitment })
+        asset_generator.scalar_mul_le(value_amount.to_bits_le()?.iter())
     }
 }
 
+/// The blinding term of a balance commitment, i.e. the value blinding
+/// generator multiplied by the blinding factor.
+pub fn blinding_commitment(
+    cs: ConstraintSystemRef<Fq>,
+    value_blinding: Vec<UInt8<Fq>>,
+) -> Result<ElementVar, SynthesisError> {
+    let value_blinding_generator = ElementVar::new_constant(cs, *VALUE_BLINDING_GENERATOR)?;
+    value_blinding_generator.scalar_mul_le(value_blinding.to_bits_le()?.iter())
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub struct Commitment(pub decaf377::Element);
 
@@ -1,8 +1,11 @@
 use crate::asset;
 use crate::dex::lp::{BareTradingFunction, TradingFunction};
 use crate::dex::trading_pair::DirectedTradingPair;
+use crate::Amount;
 use anyhow::Result;
-use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
+use penumbra_proto::{
+    client::v1alpha1::SwapExecutionResponse, core::dex::v1alpha1 as pb, DomainType,
+};
 use serde::{Deserialize, Serialize};
 
 /// Contains a path for a trade, including the trading pair (with direction), the trading
@@ -46,6 +49,21 @@ impl Path {
     pub fn end(&self) -> asset::Id {
         self.pair.end
     }
+
+    /// The assets visited by the path, from start to end.
+    pub fn route(&self) -> &[asset::Id] {
+        &self.route
+    }
+
+    /// The number of hops taken by the path.
+    pub fn hops(&self) -> usize {
+        self.route.len() - 1
+    }
+
+    /// The composition of the trading functions along the path.
+    pub fn phi(&self) -> &BareTradingFunction {
+        &self.phi
+    }
 }
 
 impl DomainType for Path {
@@ -82,3 +100,97 @@ impl From<Path> for pb::Path {
         }
     }
 }
+
+/// A path and the amount of the assets on either side that were traded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Trade", into = "pb::Trade")]
+pub struct Trade {
+    pub path: Path,
+    pub start_amount: Amount,
+    pub end_amount: Amount,
+}
+
+impl DomainType for Trade {
+    type Proto = pb::Trade;
+}
+
+impl TryFrom<pb::Trade> for Trade {
+    type Error = anyhow::Error;
+    fn try_from(trade: pb::Trade) -> Result<Self> {
+        Ok(Self {
+            path: trade
+                .path
+                .ok_or_else(|| anyhow::anyhow!("missing trade path"))?
+                .try_into()?,
+            start_amount: trade
+                .start_amount
+                .ok_or_else(|| anyhow::anyhow!("missing trade start amount"))?
+                .try_into()?,
+            end_amount: trade
+                .end_amount
+                .ok_or_else(|| anyhow::anyhow!("missing trade end amount"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Trade> for pb::Trade {
+    fn from(trade: Trade) -> Self {
+        pb::Trade {
+            path: Some(trade.path.into()),
+            start_amount: Some(trade.start_amount.into()),
+            end_amount: Some(trade.end_amount.into()),
+        }
+    }
+}
+
+/// Contains the entire execution of a particular swap, as the [`Trade`] made
+/// along each hop of its route, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::SwapExecution", into = "pb::SwapExecution")]
+pub struct SwapExecution {
+    pub trades: Vec<Trade>,
+}
+
+impl DomainType for SwapExecution {
+    type Proto = pb::SwapExecution;
+}
+
+impl TryFrom<pb::SwapExecution> for SwapExecution {
+    type Error = anyhow::Error;
+    fn try_from(execution: pb::SwapExecution) -> Result<Self> {
+        Ok(Self {
+            trades: execution
+                .trades
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+impl From<SwapExecution> for pb::SwapExecution {
+    fn from(execution: SwapExecution) -> Self {
+        pb::SwapExecution {
+            trades: execution.trades.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SwapExecution> for SwapExecutionResponse {
+    fn from(execution: SwapExecution) -> Self {
+        SwapExecutionResponse {
+            swap_execution: Some(execution.into()),
+        }
+    }
+}
+
+impl TryFrom<SwapExecutionResponse> for SwapExecution {
+    type Error = anyhow::Error;
+    fn try_from(value: SwapExecutionResponse) -> Result<Self> {
+        value
+            .swap_execution
+            .ok_or_else(|| anyhow::anyhow!("empty SwapExecutionResponse message"))?
+            .try_into()
+    }
+}
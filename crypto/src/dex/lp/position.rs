@@ -222,7 +222,7 @@ impl TryFrom<pb::PositionState> for State {
     fn try_from(v: pb::PositionState) -> Result<Self, Self::Error> {
         let Some(position_state) = pb::position_state::PositionStateEnum::from_i32(v.state) else {
             // maps to an invalid position state
-            return Err(anyhow!("invalid position state!"))
+            return Err(anyhow!("invalid position state!"));
         };
 
         match position_state {
@@ -239,14 +239,31 @@ impl TryFrom<pb::PositionState> for State {
 }
 
 impl From<Metadata> for pb::PositionMetadata {
-    fn from(_: Metadata) -> Self {
-        todo!()
+    fn from(value: Metadata) -> Self {
+        Self {
+            position: Some(value.position.into()),
+            state: Some(value.state.into()),
+            reserves: Some(value.reserves.into()),
+        }
     }
 }
 
 impl TryFrom<pb::PositionMetadata> for Metadata {
     type Error = anyhow::Error;
-    fn try_from(_value: pb::PositionMetadata) -> Result<Self, Self::Error> {
-        todo!()
+    fn try_from(value: pb::PositionMetadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position: value
+                .position
+                .ok_or_else(|| anyhow::anyhow!("missing position"))?
+                .try_into()?,
+            state: value
+                .state
+                .ok_or_else(|| anyhow::anyhow!("missing position state"))?
+                .try_into()?,
+            reserves: value
+                .reserves
+                .ok_or_else(|| anyhow::anyhow!("missing reserves"))?
+                .try_into()?,
+        })
     }
 }
@@ -1,4 +1,4 @@
-use crate::asset::Amount;
+use crate::{asset::Amount, dex::TradingPair, Balance, Value};
 use penumbra_proto::{
     client::v1alpha1::StubCpmmReservesResponse, core::dex::v1alpha1 as pb, DomainType,
 };
@@ -11,13 +11,36 @@ use super::position::MAX_RESERVE_AMOUNT;
 /// between assets 1 and 2, without specifying what those assets are, to avoid
 /// duplicating data (each asset ID alone is four times the size of the
 /// reserves).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Reserves {
     pub r1: Amount,
     pub r2: Amount,
 }
 
 impl Reserves {
+    /// Empty reserves, as left in a position once its funds have been withdrawn.
+    pub fn zero() -> Self {
+        Self {
+            r1: 0u64.into(),
+            r2: 0u64.into(),
+        }
+    }
+
+    /// The value of these reserves, given the trading pair of their position.
+    pub fn balance(&self, pair: &TradingPair) -> Balance {
+        let r1 = Value {
+            amount: self.r1,
+            asset_id: pair.asset_1(),
+        };
+
+        let r2 = Value {
+            amount: self.r2,
+            asset_id: pair.asset_2(),
+        };
+
+        Balance::from(r1) + r2
+    }
+
     pub fn check_bounds(&self) -> anyhow::Result<()> {
         if self.r1.value() as u128 > MAX_RESERVE_AMOUNT
             || self.r2.value() as u128 > MAX_RESERVE_AMOUNT
@@ -1,9 +1,14 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigUint;
 use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
 use crate::dex::{fixed_encoding::FixedEncoding, TradingPair};
 use crate::Amount;
 
+use super::position::MAX_RESERVE_AMOUNT;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::TradingFunction", into = "pb::TradingFunction")]
 pub struct TradingFunction {
@@ -120,11 +125,74 @@ impl BareTradingFunction {
     }
 
     /// Returns the composition of two trading functions.
+    ///
+    /// The fees compose multiplicatively, as `gamma = gamma_1 * gamma_2`. If
+    /// the products of the coefficients don't fit in [`MAX_RESERVE_AMOUNT`],
+    /// both are scaled down together, which preserves their ratio up to
+    /// rounding.
     pub fn compose(&self, phi: BareTradingFunction) -> BareTradingFunction {
-        let fee = self.fee * phi.fee;
-        let r1 = self.p * phi.p;
-        let r2 = self.q * phi.q;
-        BareTradingFunction::new(fee, r1, r2)
+        let gamma = (10_000 - self.fee.min(10_000)) * (10_000 - phi.fee.min(10_000)) / 10_000;
+        let fee = 10_000 - gamma;
+
+        let r1 = BigUint::from(u128::from(self.p)) * BigUint::from(u128::from(phi.p));
+        let r2 = BigUint::from(u128::from(self.q)) * BigUint::from(u128::from(phi.q));
+        let max_bits = u128::BITS as u64 - MAX_RESERVE_AMOUNT.leading_zeros() as u64;
+        let shift = r1.bits().max(r2.bits()).saturating_sub(max_bits);
+        let r1 = u128::try_from(r1 >> shift).expect("scaled coefficient fits in 112 bits");
+        let r2 = u128::try_from(r2 >> shift).expect("scaled coefficient fits in 112 bits");
+        BareTradingFunction::new(fee, r1.into(), r2.into())
+    }
+
+    /// Fills as much of `delta_2` of asset 2 as possible against `reserves_1`
+    /// of asset 1, returning the amount of input consumed and the output
+    /// `lambda_1` of asset 1.
+    ///
+    /// Keeping `phi(R)` constant after adding `gamma * delta_2` to `R_2` gives
+    /// `lambda_1 = gamma * q * delta_2 / p`, rounded down. If this exceeds the
+    /// available reserves, the output is capped at `reserves_1` and only the
+    /// input needed to buy it, rounded up, is consumed.
+    ///
+    /// To fill in the other direction, call this on [`Self::flip`].
+    pub fn fill(&self, delta_2: Amount, reserves_1: Amount) -> (Amount, Amount) {
+        if self.p == 0u64.into() || self.q == 0u64.into() || self.fee >= 10_000 {
+            return (0u64.into(), 0u64.into());
+        }
+
+        let gamma_num = BigUint::from(10_000 - self.fee);
+        let gamma_den = BigUint::from(10_000u32);
+        let p = BigUint::from(u128::from(self.p));
+        let q = BigUint::from(u128::from(self.q));
+        let delta_2_big = BigUint::from(u128::from(delta_2));
+        let reserves_1_big = BigUint::from(u128::from(reserves_1));
+
+        let lambda_1 = (&gamma_num * &q * delta_2_big) / (&gamma_den * &p);
+        if lambda_1 <= reserves_1_big {
+            let lambda_1 = u128::try_from(&lambda_1).expect("output is bounded by reserves");
+            return (delta_2, lambda_1.into());
+        }
+
+        // ceil(a / b) = (a + b - 1) / b
+        let num = gamma_den * p * reserves_1_big;
+        let den = gamma_num * q;
+        let consumed = (num + &den - 1u32) / den;
+        let consumed = u128::try_from(&consumed).expect("consumed input is bounded by delta_2");
+        (consumed.min(u128::from(delta_2)).into(), reserves_1)
+    }
+
+    /// Compares the rates at which two trading functions give out asset 1 in
+    /// exchange for asset 2, exactly rather than with the float placeholder
+    /// used by [`Self::effective_price`].
+    ///
+    /// A function that gives out more of asset 1 per unit of asset 2 compares
+    /// as greater.
+    pub fn cmp_fill_rate(&self, other: &Self) -> Ordering {
+        // Compare gamma_a * q_a / p_a with gamma_b * q_b / p_b by cross-multiplying.
+        let rate = |phi: &Self, other: &Self| {
+            BigUint::from(10_000u32.saturating_sub(phi.fee))
+                * BigUint::from(u128::from(phi.q))
+                * BigUint::from(u128::from(other.p))
+        };
+        rate(self, other).cmp(&rate(other, self))
     }
 }
 
@@ -196,4 +264,47 @@ mod tests {
         assert_eq!(integer, btf.effective_price().trunc() as u128);
         assert_eq!(fractional, btf.effective_price().fract() as u128);
     }
+
+    #[test]
+    fn test_fill() {
+        // 1:1 with a 1% fee.
+        let btf = BareTradingFunction::new(100, 1u64.into(), 1u64.into());
+
+        // Enough reserves: all of the input is consumed, less the fee.
+        let (consumed, output) = btf.fill(1_000u64.into(), 10_000u64.into());
+        assert_eq!(consumed, 1_000u64.into());
+        assert_eq!(output, 990u64.into());
+
+        // Not enough reserves: the output is capped, and only the input needed
+        // to buy it is consumed, rounding up.
+        let (consumed, output) = btf.fill(1_000u64.into(), 99u64.into());
+        assert_eq!(consumed, 100u64.into());
+        assert_eq!(output, 99u64.into());
+
+        // A function giving out more asset 1 per unit of asset 2 is preferred.
+        let cheaper = BareTradingFunction::new(0, 1u64.into(), 2u64.into());
+        assert_eq!(cheaper.cmp_fill_rate(&btf), Ordering::Greater);
+        assert_eq!(btf.cmp_fill_rate(&btf), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_compose() {
+        // The fees compose multiplicatively: 0.99 * 0.98 = 0.9702.
+        let a = BareTradingFunction::new(100, 2u64.into(), 3u64.into());
+        let b = BareTradingFunction::new(200, 5u64.into(), 7u64.into());
+        let composed = a.compose(b);
+        assert_eq!(composed.fee, 298);
+        assert_eq!(composed.p, 10u64.into());
+        assert_eq!(composed.q, 21u64.into());
+
+        // Coefficients whose products overflow are scaled down together.
+        let big = BareTradingFunction::new(
+            0,
+            MAX_RESERVE_AMOUNT.into(),
+            (MAX_RESERVE_AMOUNT / 2).into(),
+        );
+        let composed = big.compose(big.clone());
+        assert!(u128::from(composed.p) <= MAX_RESERVE_AMOUNT);
+        assert_eq!(u128::from(composed.p) / u128::from(composed.q), 4);
+    }
 }
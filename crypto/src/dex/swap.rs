@@ -12,10 +12,12 @@ use super::TradingPair;
 mod ciphertext;
 mod payload;
 mod plaintext;
+mod r1cs;
 
 pub use ciphertext::SwapCiphertext;
 pub use payload::SwapPayload;
 pub use plaintext::SwapPlaintext;
+pub use r1cs::SwapPlaintextVar;
 
 // Swap ciphertext byte length.
 pub const SWAP_CIPHERTEXT_BYTES: usize = 272;
@@ -0,0 +1,98 @@
+use ark_ff::PrimeField;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::SynthesisError;
+use decaf377::{r1cs::FqVar, Fq};
+
+use crate::{address::AddressVar, value::ValueVar};
+
+use super::{SwapPlaintext, DOMAIN_SEPARATOR};
+
+/// A [`SwapPlaintext`] witnessed in an R1CS constraint system.
+pub struct SwapPlaintextVar {
+    pub claim_fee: ValueVar,
+    pub delta_1_i: FqVar,
+    pub delta_2_i: FqVar,
+    pub asset_1: FqVar,
+    pub asset_2: FqVar,
+    pub claim_address: AddressVar,
+    pub rseed: FqVar,
+}
+
+impl SwapPlaintextVar {
+    /// Compute the swap commitment for this plaintext.
+    pub fn commit(&self) -> Result<FqVar, SynthesisError> {
+        let cs = self.delta_1_i.cs();
+        let domain_sep = FqVar::new_constant(cs.clone(), *DOMAIN_SEPARATOR)?;
+        let inner_hash = poseidon377::r1cs::hash_4(
+            cs.clone(),
+            &domain_sep,
+            (
+                self.asset_1.clone(),
+                self.asset_2.clone(),
+                self.delta_1_i.clone(),
+                self.delta_2_i.clone(),
+            ),
+        )?;
+        poseidon377::r1cs::hash_7(
+            cs,
+            &domain_sep,
+            (
+                self.rseed.clone(),
+                self.claim_fee.amount(),
+                self.claim_fee.asset_id(),
+                self.claim_address
+                    .diversified_generator()
+                    .compress_to_field()?,
+                self.claim_address.transmission_key().compress_to_field()?,
+                self.claim_address.clue_key(),
+                inner_hash,
+            ),
+        )
+    }
+}
+
+impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
+    fn new_variable<T: std::borrow::Borrow<SwapPlaintext>>(
+        cs: impl Into<ark_relations::r1cs::Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: ark_r1cs_std::prelude::AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let swap_plaintext: SwapPlaintext = f()?.borrow().clone();
+        match mode {
+            // The swap plaintext is only ever a witness.
+            AllocationMode::Constant | AllocationMode::Input => {
+                Err(SynthesisError::AssignmentMissing)
+            }
+            AllocationMode::Witness => {
+                let claim_fee =
+                    ValueVar::new_witness(cs.clone(), || Ok(swap_plaintext.claim_fee.0))?;
+                let delta_1_i =
+                    FqVar::new_witness(cs.clone(), || Ok(Fq::from(swap_plaintext.delta_1_i)))?;
+                let delta_2_i =
+                    FqVar::new_witness(cs.clone(), || Ok(Fq::from(swap_plaintext.delta_2_i)))?;
+                let asset_1 =
+                    FqVar::new_witness(cs.clone(), || Ok(swap_plaintext.trading_pair.asset_1().0))?;
+                let asset_2 =
+                    FqVar::new_witness(cs.clone(), || Ok(swap_plaintext.trading_pair.asset_2().0))?;
+                let claim_address =
+                    AddressVar::new_witness(cs.clone(), || Ok(swap_plaintext.claim_address))?;
+                let rseed = FqVar::new_witness(cs, || {
+                    Ok(Fq::from_le_bytes_mod_order(
+                        &swap_plaintext.rseed.to_bytes()[..],
+                    ))
+                })?;
+                Ok(Self {
+                    claim_fee,
+                    delta_1_i,
+                    delta_2_i,
+                    asset_1,
+                    asset_2,
+                    claim_address,
+                    rseed,
+                })
+            }
+        }
+    }
+}
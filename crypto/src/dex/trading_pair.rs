@@ -58,6 +58,24 @@ impl From<DirectedTradingPair> for TradingPair {
     }
 }
 
+impl FromStr for DirectedTradingPair {
+    type Err = anyhow::Error;
+
+    /// Takes an input of the form START_DENOM:END_DENOM, parsing each half
+    /// using `asset::REGISTRY.parse_unit`, and keeps the order of the assets.
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let parts: Vec<&str> = s.split(':').collect();
+
+        if parts.len() != 2 {
+            Err(anyhow!("invalid directed trading pair string"))
+        } else {
+            let start = REGISTRY.parse_unit(parts[0]);
+            let end = REGISTRY.parse_unit(parts[1]);
+            Ok(Self::new(start.id(), end.id()))
+        }
+    }
+}
+
 /// The canonical representation of a tuple of asset [`Id`]s.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize)]
 #[serde(try_from = "pb::TradingPair", into = "pb::TradingPair")]
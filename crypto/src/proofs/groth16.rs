@@ -1,29 +1,40 @@
+mod delegator_vote;
 mod gadgets;
 mod output;
 mod spend;
+mod swap;
+mod swap_claim;
 mod traits;
+mod undelegate_claim;
 
+pub use delegator_vote::{DelegatorVoteCircuit, DelegatorVoteProof};
 pub use output::{OutputCircuit, OutputProof};
 pub use spend::{SpendCircuit, SpendProof};
+pub use swap::{SwapCircuit, SwapProof};
+pub use swap_claim::{SwapClaimCircuit, SwapClaimProof};
 pub use traits::ParameterSetup;
+pub use undelegate_claim::{UndelegateClaimCircuit, UndelegateClaimProof};
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         asset,
+        dex::{swap::SwapPlaintext, BatchSwapOutputData, TradingPair},
         keys::{SeedPhrase, SpendKey},
+        stake::{IdentityKey, Penalty, UnbondingToken},
+        transaction::Fee,
     };
     use decaf377::{Fq, Fr};
     use proptest::prelude::*;
 
-    use decaf377_rdsa::{SpendAuth, VerificationKey};
+    use decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey};
     use penumbra_tct as tct;
     use rand_core::OsRng;
 
-    use crate::{note, Note, Value};
+    use crate::{note, Balance, Note, Value};
 
-    use ark_ff::PrimeField;
+    use ark_ff::{PrimeField, Zero};
 
     fn fq_strategy() -> BoxedStrategy<Fq> {
         any::<[u8; 32]>()
@@ -554,4 +565,293 @@ mod tests {
             assert!(proof_result.is_ok());
         }
     }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `SwapProof` verification succeeds.
+    fn swap_proof_verification_success(seed_phrase_randomness in any::<[u8; 32]>(), fee_blinding in fr_strategy(), delta_1_i in 2..200u64, delta_2_i in 2..200u64, fee_amount in 0..20u64) {
+        let (pk, vk) = SwapCircuit::generate_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_recipient = sk_recipient.full_viewing_key();
+        let ivk_recipient = fvk_recipient.incoming();
+        let (claim_address, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+
+        let trading_pair = TradingPair::new(
+            asset::REGISTRY.parse_denom("gm").unwrap().id(),
+            asset::REGISTRY.parse_denom("gn").unwrap().id(),
+        );
+        let fee = Fee(Value {
+            amount: fee_amount.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        });
+        let swap_plaintext = SwapPlaintext::new(
+            &mut rng,
+            trading_pair,
+            delta_1_i.into(),
+            delta_2_i.into(),
+            fee,
+            claim_address,
+        );
+        let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
+        let swap_commitment = swap_plaintext.swap_commitment();
+        let balance_commitment = (Balance::default()
+            - Value {
+                amount: delta_1_i.into(),
+                asset_id: trading_pair.asset_1(),
+            }
+            - Value {
+                amount: delta_2_i.into(),
+                asset_id: trading_pair.asset_2(),
+            })
+        .commit(Fr::zero())
+            + fee_commitment;
+
+        let proof = SwapProof::prove(
+            &mut rng,
+            &pk,
+            swap_plaintext,
+            fee_blinding,
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, balance_commitment, swap_commitment, fee_commitment);
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `SwapClaimProof` verification succeeds.
+    fn swap_claim_proof_verification_success(seed_phrase_randomness in any::<[u8; 32]>(), delta_1_i in 2..200u64, delta_2_i in 2..200u64, lambda_1 in 2..200u64, lambda_2 in 2..200u64, success in any::<bool>()) {
+        let (pk, vk) = SwapClaimCircuit::generate_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_recipient = sk_recipient.full_viewing_key();
+        let ivk_recipient = fvk_recipient.incoming();
+        let (claim_address, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+        let nk = *sk_recipient.nullifier_key();
+
+        let trading_pair = TradingPair::new(
+            asset::REGISTRY.parse_denom("gm").unwrap().id(),
+            asset::REGISTRY.parse_denom("gn").unwrap().id(),
+        );
+        let fee = Fee(Value {
+            amount: 1u64.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        });
+        let swap_plaintext = SwapPlaintext::new(
+            &mut rng,
+            trading_pair,
+            delta_1_i.into(),
+            delta_2_i.into(),
+            fee.clone(),
+            claim_address,
+        );
+        let swap_commitment = swap_plaintext.swap_commitment();
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+        let position = state_commitment_proof.position();
+        let nullifier = nk.derive_nullifier(position, &swap_commitment);
+
+        let epoch_duration = 20;
+        let output_data = BatchSwapOutputData {
+            // Include other swaps in the batch so that the pro rata shares are rounded.
+            delta_1: delta_1_i + 1000,
+            delta_2: delta_2_i + 1000,
+            lambda_1,
+            lambda_2,
+            height: epoch_duration * u64::from(position.epoch()) + u64::from(position.block()),
+            trading_pair,
+            success,
+        };
+        let (output_1_note, output_2_note) = swap_plaintext.output_notes(&output_data);
+        let note_commitment_1 = output_1_note.commit();
+        let note_commitment_2 = output_2_note.commit();
+
+        let proof = SwapClaimProof::prove(
+            &mut rng,
+            &pk,
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(
+            &vk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+            fee,
+        );
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `UndelegateClaimProof` verification succeeds.
+    fn undelegate_claim_proof_verification_success(balance_blinding in fr_strategy(), unbonding_amount in 2..2000000u64, penalty in 0..1_0000_0000u64) {
+        let (pk, vk) = UndelegateClaimCircuit::generate_test_parameters();
+        let mut rng = OsRng;
+
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(rng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+        let unbonding_amount = unbonding_amount.into();
+        let penalty = Penalty(penalty);
+        let balance_commitment = penalty
+            .balance_for_claim(unbonding_id, unbonding_amount)
+            .commit(balance_blinding);
+
+        let proof = UndelegateClaimProof::prove(
+            &mut rng,
+            &pk,
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, balance_commitment, unbonding_id, penalty);
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `DelegatorVoteProof` verification succeeds.
+    fn delegator_vote_proof_verification_success(seed_phrase_randomness in any::<[u8; 32]>(), spend_auth_randomizer in fr_strategy(), value_amount in 2..200u64) {
+        let (pk, vk) = DelegatorVoteCircuit::generate_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (sender, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let value_to_send = Value {
+            amount: value_amount.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+
+        let note = Note::generate(&mut rng, &sender, value_to_send);
+        let note_commitment = note.commit();
+        let rsk = sk_sender.spend_auth_key().randomize(&spend_auth_randomizer);
+        let nk = *sk_sender.nullifier_key();
+        let ak: VerificationKey<SpendAuth> = sk_sender.spend_auth_key().into();
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, note_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(note_commitment).unwrap();
+        let start_position = sct.position().unwrap();
+        // The value voted with is public, so the value commitment is unblinded.
+        let balance_commitment = value_to_send.commit(Fr::zero());
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        let nf = nk.derive_nullifier(0.into(), &note_commitment);
+
+        let proof = DelegatorVoteProof::prove(
+            &mut rng,
+            &pk,
+            state_commitment_proof,
+            note,
+            Fr::zero(),
+            spend_auth_randomizer,
+            ak,
+            nk,
+            anchor,
+            balance_commitment,
+            nf,
+            rk,
+            start_position,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, anchor, balance_commitment, nf, rk, start_position);
+        assert!(proof_result.is_ok());
+    }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2))]
+    #[test]
+    /// Check that the `DelegatorVoteProof` verification fails when using a start position
+    /// at or before the position of the note voted with.
+    fn delegator_vote_proof_verification_start_position_integrity_failure(seed_phrase_randomness in any::<[u8; 32]>(), spend_auth_randomizer in fr_strategy(), value_amount in 2..200u64) {
+        let (pk, vk) = DelegatorVoteCircuit::generate_test_parameters();
+        let mut rng = OsRng;
+
+        let seed_phrase = SeedPhrase::from_randomness(seed_phrase_randomness);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (sender, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let value_to_send = Value {
+            amount: value_amount.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+
+        let note = Note::generate(&mut rng, &sender, value_to_send);
+        let note_commitment = note.commit();
+        let rsk = sk_sender.spend_auth_key().randomize(&spend_auth_randomizer);
+        let nk = *sk_sender.nullifier_key();
+        let ak: VerificationKey<SpendAuth> = sk_sender.spend_auth_key().into();
+        let mut sct = tct::Tree::new();
+        // Voting started before the note was inserted.
+        let incorrect_start_position = sct.position().unwrap();
+        sct.insert(tct::Witness::Keep, note_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(note_commitment).unwrap();
+        let start_position = sct.position().unwrap();
+        let balance_commitment = value_to_send.commit(Fr::zero());
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        let nf = nk.derive_nullifier(0.into(), &note_commitment);
+
+        let proof = DelegatorVoteProof::prove(
+            &mut rng,
+            &pk,
+            state_commitment_proof,
+            note,
+            Fr::zero(),
+            spend_auth_randomizer,
+            ak,
+            nk,
+            anchor,
+            balance_commitment,
+            nf,
+            rk,
+            start_position,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(&vk, anchor, balance_commitment, nf, rk, incorrect_start_position);
+        assert!(proof_result.is_err());
+    }
+    }
 }
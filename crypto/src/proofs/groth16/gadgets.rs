@@ -1,6 +1,10 @@
+use ark_ff::PrimeField;
 use ark_r1cs_std::prelude::*;
 use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
-use decaf377::{r1cs::ElementVar, Fq};
+use decaf377::{
+    r1cs::{ElementVar, FqVar},
+    Fq,
+};
 
 /// Check the element is not identity.
 pub(crate) fn element_not_identity(
@@ -13,3 +17,74 @@ pub(crate) fn element_not_identity(
     identity.conditional_enforce_not_equal(&element, enforce)?;
     Ok(())
 }
+
+/// Witness a value that is constrained to fit in 128 bits.
+pub(crate) fn witness_u128(
+    cs: ConstraintSystemRef<Fq>,
+    // Witness
+    value: Result<u128, SynthesisError>,
+) -> Result<FqVar, SynthesisError> {
+    let bits = (0..128)
+        .map(|i| Boolean::new_witness(cs.clone(), || value.clone().map(|v| (v >> i) & 1 == 1)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Boolean::le_bits_to_fp_var(&bits)
+}
+
+/// Check that the value fits in the given number of bits.
+pub(crate) fn enforce_bit_length(value: &FqVar, bits: usize) -> Result<(), SynthesisError> {
+    for bit in &value.to_bits_le()?[bits..] {
+        bit.enforce_equal(&Boolean::FALSE)?;
+    }
+    Ok(())
+}
+
+/// Compute `floor(numerator / divisor)`, or zero if the divisor is zero.
+///
+/// The numerator and quotient must fit in 128 bits, and the divisor in 64
+/// bits, so that the division can be checked without wrapping around the
+/// field modulus.
+pub(crate) fn divide_floor(
+    cs: ConstraintSystemRef<Fq>,
+    numerator: &FqVar,
+    divisor: &FqVar,
+) -> Result<FqVar, SynthesisError> {
+    // The quotient and remainder are computed out of circuit and witnessed,
+    // then checked against the numerator and divisor.
+    let division = numerator.value().and_then(|numerator| {
+        let numerator = fq_to_u128(numerator)?;
+        let divisor = fq_to_u128(divisor.value()?)?;
+        Ok(match divisor {
+            0 => (0, 0, 0),
+            _ => (
+                numerator / divisor,
+                numerator % divisor,
+                divisor - numerator % divisor - 1,
+            ),
+        })
+    });
+
+    let quotient = witness_u128(cs.clone(), division.clone().map(|(q, _, _)| q))?;
+    let remainder = witness_u128(cs.clone(), division.clone().map(|(_, r, _)| r))?;
+    // The gap between the remainder and the divisor, which fits in 128 bits
+    // only if the remainder is less than the divisor.
+    let gap = witness_u128(cs, division.map(|(_, _, gap)| gap))?;
+
+    let divisor_is_zero = divisor.is_eq(&FqVar::zero())?;
+    let divisor_is_nonzero = divisor_is_zero.not();
+
+    (quotient.clone() * divisor + &remainder)
+        .conditional_enforce_equal(numerator, &divisor_is_nonzero)?;
+    (divisor - &remainder - FqVar::one()).conditional_enforce_equal(&gap, &divisor_is_nonzero)?;
+    quotient.conditional_enforce_equal(&FqVar::zero(), &divisor_is_zero)?;
+
+    Ok(quotient)
+}
+
+fn fq_to_u128(value: Fq) -> Result<u128, SynthesisError> {
+    let repr = value.into_repr();
+    let limbs = repr.as_ref();
+    if limbs[2..].iter().any(|limb| *limb != 0) {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    Ok(limbs[0] as u128 | (limbs[1] as u128) << 64)
+}
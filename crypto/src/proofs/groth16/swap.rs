@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use ark_r1cs_std::prelude::*;
+use ark_r1cs_std::uint8::UInt8;
+use decaf377::FieldExt;
+use decaf377::{r1cs::FqVar, Bls12_377, Fq, Fr};
+
+use ark_ff::ToConstraintField;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use penumbra_tct as tct;
+use rand::{CryptoRng, Rng};
+use rand_core::OsRng;
+
+use crate::proofs::groth16::ParameterSetup;
+use crate::{
+    asset,
+    balance::{
+        self,
+        commitment::{blinding_commitment, BalanceCommitmentVar},
+    },
+    dex::{
+        swap::{SwapPlaintext, SwapPlaintextVar},
+        TradingPair,
+    },
+    keys::{SeedPhrase, SpendKey},
+    transaction::Fee,
+    value::ValueVar,
+    Value,
+};
+
+/// Groth16 proof for creating a swap.
+///
+/// Swaps create an output NFT encoding the swap data, so they are most
+/// similar to outputs.
+#[derive(Clone, Debug)]
+pub struct SwapCircuit {
+    // Witnesses
+    /// The swap being created.
+    swap_plaintext: SwapPlaintext,
+    /// The blinding factor used for the fee commitment.
+    fee_blinding: Fr,
+
+    // Public inputs
+    /// The balance commitment of the swap, including the fee.
+    pub balance_commitment: balance::Commitment,
+    /// The swap commitment.
+    pub swap_commitment: tct::Commitment,
+    /// The commitment to the prepaid claim fee.
+    pub fee_commitment: balance::Commitment,
+}
+
+impl ConstraintSynthesizer<Fq> for SwapCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> ark_relations::r1cs::Result<()> {
+        // Witnesses
+        let swap_plaintext_var =
+            SwapPlaintextVar::new_witness(cs.clone(), || Ok(self.swap_plaintext.clone()))?;
+        let fee_blinding_arr: [u8; 32] = self.fee_blinding.to_bytes();
+        let fee_blinding_vars = UInt8::new_witness_vec(cs.clone(), &fee_blinding_arr)?;
+
+        // Public inputs
+        let claimed_balance_commitment =
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.balance_commitment))?;
+        let claimed_swap_commitment = FqVar::new_input(cs.clone(), || Ok(self.swap_commitment.0))?;
+        let claimed_fee_commitment =
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.fee_commitment))?;
+
+        // Swap commitment integrity.
+        let swap_commitment = swap_plaintext_var.commit()?;
+        swap_commitment.enforce_equal(&claimed_swap_commitment)?;
+
+        // Fee commitment integrity. The fee is required by the swap, so it's
+        // committed to with a negative sign.
+        let fee_commitment = blinding_commitment(cs.clone(), fee_blinding_vars)?
+            - swap_plaintext_var.claim_fee.unblinded_commitment()?;
+        fee_commitment.enforce_equal(&claimed_fee_commitment.inner)?;
+
+        // Balance commitment integrity. The input amounts are transparent, so
+        // they're committed to with a zero blinding factor.
+        let input_1 = ValueVar {
+            amount: asset::AmountVar {
+                amount: swap_plaintext_var.delta_1_i.clone(),
+            },
+            asset_id: asset::AssetIdVar {
+                asset_id: swap_plaintext_var.asset_1.clone(),
+            },
+        };
+        let input_2 = ValueVar {
+            amount: asset::AmountVar {
+                amount: swap_plaintext_var.delta_2_i.clone(),
+            },
+            asset_id: asset::AssetIdVar {
+                asset_id: swap_plaintext_var.asset_2.clone(),
+            },
+        };
+        let balance_commitment = claimed_fee_commitment.inner
+            - input_1.unblinded_commitment()?
+            - input_2.unblinded_commitment()?;
+        balance_commitment.enforce_equal(&claimed_balance_commitment.inner)?;
+
+        Ok(())
+    }
+}
+
+impl ParameterSetup for SwapCircuit {
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let seed_phrase = SeedPhrase::from_randomness([b'f'; 32]);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (claim_address, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let trading_pair = TradingPair::new(
+            asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            asset::REGISTRY.parse_denom("nala").unwrap().id(),
+        );
+        let swap_plaintext = SwapPlaintext::new(
+            &mut OsRng,
+            trading_pair,
+            100000u64.into(),
+            1u64.into(),
+            Fee(Value::from_str("1upenumbra").expect("valid value")),
+            claim_address,
+        );
+        let fee_blinding = Fr::from(1);
+        let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
+
+        let circuit = SwapCircuit {
+            swap_commitment: swap_plaintext.swap_commitment(),
+            swap_plaintext,
+            fee_blinding,
+            balance_commitment: balance::Commitment(decaf377::basepoint()),
+            fee_commitment,
+        };
+        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
+            .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SwapProof(Proof<Bls12_377>);
+
+impl SwapProof {
+    pub fn prove<R: CryptoRng + Rng>(
+        rng: &mut R,
+        pk: &ProvingKey<Bls12_377>,
+        swap_plaintext: SwapPlaintext,
+        fee_blinding: Fr,
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::Commitment,
+        fee_commitment: balance::Commitment,
+    ) -> anyhow::Result<Self> {
+        let circuit = SwapCircuit {
+            swap_plaintext,
+            fee_blinding,
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+        };
+        let proof = Groth16::prove(pk, circuit, rng).map_err(|err| anyhow::anyhow!(err))?;
+        Ok(Self(proof))
+    }
+
+    /// Called to verify the proof using the provided public inputs.
+    ///
+    /// The public inputs are:
+    /// * balance commitment of the swap,
+    /// * swap commitment,
+    /// * commitment to the prepaid claim fee.
+    pub fn verify(
+        &self,
+        vk: &VerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::Commitment,
+        fee_commitment: balance::Commitment,
+    ) -> anyhow::Result<()> {
+        let processed_pvk = Groth16::process_vk(vk).map_err(|err| anyhow::anyhow!(err))?;
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(swap_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(fee_commitment.0.to_field_elements().unwrap());
+
+        let proof_result =
+            Groth16::verify_with_processed_vk(&processed_pvk, public_inputs.as_slice(), &self.0)
+                .map_err(|err| anyhow::anyhow!(err))?;
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("proof did not verify"))
+    }
+}
+
+impl From<SwapProof> for Vec<u8> {
+    fn from(proof: SwapProof) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        proof
+            .0
+            .serialize(&mut bytes)
+            .expect("can serialize Groth16 proof");
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for SwapProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<SwapProof, Self::Error> {
+        Ok(SwapProof(
+            Proof::deserialize(bytes).map_err(|_| anyhow::anyhow!("proof malformed"))?,
+        ))
+    }
+}
@@ -0,0 +1,400 @@
+use std::str::FromStr;
+
+use ark_r1cs_std::prelude::*;
+use decaf377::{r1cs::FqVar, Bls12_377, Fq};
+
+use ark_ff::ToConstraintField;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use penumbra_tct as tct;
+use rand::{CryptoRng, Rng};
+use rand_core::OsRng;
+
+use crate::proofs::groth16::{gadgets, ParameterSetup};
+use crate::{
+    asset,
+    dex::{
+        swap::{SwapPlaintext, SwapPlaintextVar},
+        BatchSwapOutputData, TradingPair,
+    },
+    keys::{NullifierKey, NullifierKeyVar, SeedPhrase, SpendKey},
+    note::{self, NoteCommitmentVar, NoteVar},
+    nullifier::NullifierVar,
+    transaction::Fee,
+    value::ValueVar,
+    Nullifier, Value,
+};
+
+/// Groth16 proof for claiming the outputs of a swap.
+///
+/// Swap claims consume an existing swap NFT, so they are most similar to
+/// spends, however the inclusion proof must be for a specific block, since
+/// clearing prices are only valid for a particular block.
+#[derive(Clone, Debug)]
+pub struct SwapClaimCircuit {
+    // Witnesses
+    /// The swap being claimed.
+    swap_plaintext: SwapPlaintext,
+    /// Inclusion proof for the swap commitment.
+    state_commitment_proof: tct::Proof,
+    /// The nullifier deriving key.
+    nk: NullifierKey,
+    /// The blinding factors of the two output notes.
+    ///
+    /// These are derived from the swap's rseed out of circuit, since the
+    /// derivation uses BLAKE2b, which is too expensive to do in-circuit.
+    note_blinding_1: Fq,
+    note_blinding_2: Fq,
+
+    // Public inputs
+    /// The merkle root of the state commitment tree.
+    pub anchor: tct::Root,
+    /// The nullifier of the swap being claimed.
+    pub nullifier: Nullifier,
+    /// The prepaid fee for the claim.
+    pub fee: Fee,
+    /// The clearing prices and trading pair of the batch the swap was in.
+    pub output_data: BatchSwapOutputData,
+    /// The number of blocks in an epoch.
+    pub epoch_duration: u64,
+    /// The note commitments of the two output notes.
+    pub note_commitment_1: note::Commitment,
+    pub note_commitment_2: note::Commitment,
+}
+
+impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> ark_relations::r1cs::Result<()> {
+        // Witnesses
+        let swap_plaintext_var =
+            SwapPlaintextVar::new_witness(cs.clone(), || Ok(self.swap_plaintext.clone()))?;
+        let claimed_swap_commitment = NoteCommitmentVar::new_witness(cs.clone(), || {
+            Ok(self.state_commitment_proof.commitment())
+        })?;
+        let position_var = tct::r1cs::PositionVar::new_witness(cs.clone(), || {
+            Ok(self.state_commitment_proof.position())
+        })?;
+        let merkle_path_var =
+            tct::r1cs::MerkleAuthPathVar::new(cs.clone(), self.state_commitment_proof)?;
+        let nk_var = NullifierKeyVar::new_witness(cs.clone(), || Ok(self.nk))?;
+        let note_blinding_1_var = FqVar::new_witness(cs.clone(), || Ok(self.note_blinding_1))?;
+        let note_blinding_2_var = FqVar::new_witness(cs.clone(), || Ok(self.note_blinding_2))?;
+
+        // Public inputs
+        let anchor_var = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.anchor)))?;
+        let claimed_nullifier_var = NullifierVar::new_input(cs.clone(), || Ok(self.nullifier))?;
+        let claimed_fee_amount = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.fee.0.amount)))?;
+        let claimed_fee_asset_id = FqVar::new_input(cs.clone(), || Ok(self.fee.0.asset_id.0))?;
+        let delta_1 = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.output_data.delta_1)))?;
+        let delta_2 = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.output_data.delta_2)))?;
+        let lambda_1 = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.output_data.lambda_1)))?;
+        let lambda_2 = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.output_data.lambda_2)))?;
+        let success = Boolean::new_input(cs.clone(), || Ok(self.output_data.success))?;
+        let trading_pair_asset_1 =
+            FqVar::new_input(cs.clone(), || Ok(self.output_data.trading_pair.asset_1().0))?;
+        let trading_pair_asset_2 =
+            FqVar::new_input(cs.clone(), || Ok(self.output_data.trading_pair.asset_2().0))?;
+        let output_height = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.output_data.height)))?;
+        let epoch_duration_var =
+            FqVar::new_input(cs.clone(), || Ok(Fq::from(self.epoch_duration)))?;
+        let claimed_note_commitment_1 =
+            NoteCommitmentVar::new_input(cs.clone(), || Ok(self.note_commitment_1))?;
+        let claimed_note_commitment_2 =
+            NoteCommitmentVar::new_input(cs.clone(), || Ok(self.note_commitment_2))?;
+
+        // Swap commitment integrity.
+        let swap_commitment = swap_plaintext_var.commit()?;
+        swap_commitment.enforce_equal(&claimed_swap_commitment.inner)?;
+
+        // Merkle auth path verification against the provided anchor.
+        merkle_path_var.verify(
+            cs.clone(),
+            &Boolean::TRUE,
+            position_var.inner.clone(),
+            anchor_var,
+            claimed_swap_commitment.inner(),
+        )?;
+
+        // Nullifier integrity.
+        let nullifier_var = nk_var.derive_nullifier(&position_var, &claimed_swap_commitment)?;
+        nullifier_var.enforce_equal(&claimed_nullifier_var)?;
+
+        // The swap commitment must have been created at the height of the batch.
+        let position_bits = position_var.inner.to_bits_le()?;
+        let block = Boolean::le_bits_to_fp_var(&position_bits[16..32])?;
+        let epoch = Boolean::le_bits_to_fp_var(&position_bits[32..48])?;
+        (epoch_duration_var * epoch + block).enforce_equal(&output_height)?;
+
+        // The batch must be for the swap's trading pair.
+        swap_plaintext_var
+            .asset_1
+            .enforce_equal(&trading_pair_asset_1)?;
+        swap_plaintext_var
+            .asset_2
+            .enforce_equal(&trading_pair_asset_2)?;
+
+        // Fee consistency.
+        swap_plaintext_var
+            .claim_fee
+            .amount()
+            .enforce_equal(&claimed_fee_amount)?;
+        swap_plaintext_var
+            .claim_fee
+            .asset_id()
+            .enforce_equal(&claimed_fee_asset_id)?;
+
+        // Output amounts integrity. The input amounts are bounded so that the
+        // pro rata products can't wrap around the field modulus.
+        gadgets::enforce_bit_length(&swap_plaintext_var.delta_1_i, 64)?;
+        gadgets::enforce_bit_length(&swap_plaintext_var.delta_2_i, 64)?;
+        let swapped_lambda_1_i = gadgets::divide_floor(
+            cs.clone(),
+            &(swap_plaintext_var.delta_2_i.clone() * lambda_1),
+            &delta_2,
+        )?;
+        let swapped_lambda_2_i = gadgets::divide_floor(
+            cs,
+            &(swap_plaintext_var.delta_1_i.clone() * lambda_2),
+            &delta_1,
+        )?;
+        let lambda_1_i = FqVar::conditionally_select(
+            &success,
+            &swapped_lambda_1_i,
+            &swap_plaintext_var.delta_1_i,
+        )?;
+        let lambda_2_i = FqVar::conditionally_select(
+            &success,
+            &swapped_lambda_2_i,
+            &swap_plaintext_var.delta_2_i,
+        )?;
+
+        // Output note integrity.
+        let output_1 = NoteVar {
+            value: ValueVar {
+                amount: asset::AmountVar { amount: lambda_1_i },
+                asset_id: asset::AssetIdVar {
+                    asset_id: swap_plaintext_var.asset_1.clone(),
+                },
+            },
+            note_blinding: note_blinding_1_var,
+            address: swap_plaintext_var.claim_address.clone(),
+        };
+        let output_2 = NoteVar {
+            value: ValueVar {
+                amount: asset::AmountVar { amount: lambda_2_i },
+                asset_id: asset::AssetIdVar {
+                    asset_id: swap_plaintext_var.asset_2.clone(),
+                },
+            },
+            note_blinding: note_blinding_2_var,
+            address: swap_plaintext_var.claim_address.clone(),
+        };
+        output_1
+            .commit()?
+            .enforce_equal(&claimed_note_commitment_1)?;
+        output_2
+            .commit()?
+            .enforce_equal(&claimed_note_commitment_2)?;
+
+        Ok(())
+    }
+}
+
+impl ParameterSetup for SwapClaimCircuit {
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let seed_phrase = SeedPhrase::from_randomness([b'f'; 32]);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (claim_address, _dtk_d) = ivk_sender.payment_address(0u32.into());
+        let nk = *sk_sender.nullifier_key();
+
+        let trading_pair = TradingPair::new(
+            asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            asset::REGISTRY.parse_denom("nala").unwrap().id(),
+        );
+        let swap_plaintext = SwapPlaintext::new(
+            &mut OsRng,
+            trading_pair,
+            100000u64.into(),
+            1u64.into(),
+            Fee(Value::from_str("1upenumbra").expect("valid value")),
+            claim_address,
+        );
+        let mut sct = tct::Tree::new();
+        let swap_commitment = swap_plaintext.swap_commitment();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+        let output_data = BatchSwapOutputData {
+            delta_1: 100000,
+            delta_2: 1,
+            lambda_1: 1,
+            lambda_2: 100000,
+            height: 0,
+            trading_pair,
+            success: true,
+        };
+
+        let circuit = SwapClaimCircuit::new(
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            Nullifier(Fq::from(1)),
+            output_data,
+            1,
+            note::Commitment(Fq::from(1)),
+            note::Commitment(Fq::from(2)),
+        );
+        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
+            .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+impl SwapClaimCircuit {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        swap_plaintext: SwapPlaintext,
+        state_commitment_proof: tct::Proof,
+        nk: NullifierKey,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) -> Self {
+        let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
+        Self {
+            fee: swap_plaintext.claim_fee.clone(),
+            note_blinding_1: output_rseed_1.derive_note_blinding(),
+            note_blinding_2: output_rseed_2.derive_note_blinding(),
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SwapClaimProof(Proof<Bls12_377>);
+
+impl SwapClaimProof {
+    #![allow(clippy::too_many_arguments)]
+    pub fn prove<R: CryptoRng + Rng>(
+        rng: &mut R,
+        pk: &ProvingKey<Bls12_377>,
+        swap_plaintext: SwapPlaintext,
+        state_commitment_proof: tct::Proof,
+        nk: NullifierKey,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+    ) -> anyhow::Result<Self> {
+        let circuit = SwapClaimCircuit::new(
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        );
+        let proof = Groth16::prove(pk, circuit, rng).map_err(|err| anyhow::anyhow!(err))?;
+        Ok(Self(proof))
+    }
+
+    /// Called to verify the proof using the provided public inputs.
+    ///
+    /// The claim fee is bound by the swap commitment, so the verifier must
+    /// supply the fee claimed in the action.
+    pub fn verify(
+        &self,
+        vk: &VerifyingKey<Bls12_377>,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        output_data: BatchSwapOutputData,
+        epoch_duration: u64,
+        note_commitment_1: note::Commitment,
+        note_commitment_2: note::Commitment,
+        fee: Fee,
+    ) -> anyhow::Result<()> {
+        let processed_pvk = Groth16::process_vk(vk).map_err(|err| anyhow::anyhow!(err))?;
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(Fq::from(anchor.0).to_field_elements().unwrap());
+        public_inputs.extend(nullifier.0.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(fee.0.amount).to_field_elements().unwrap());
+        public_inputs.extend(fee.0.asset_id.0.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(output_data.delta_1).to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(output_data.delta_2).to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(output_data.lambda_1).to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(output_data.lambda_2).to_field_elements().unwrap());
+        public_inputs.extend(
+            Fq::from(output_data.success as u64)
+                .to_field_elements()
+                .unwrap(),
+        );
+        public_inputs.extend(
+            output_data
+                .trading_pair
+                .asset_1()
+                .0
+                .to_field_elements()
+                .unwrap(),
+        );
+        public_inputs.extend(
+            output_data
+                .trading_pair
+                .asset_2()
+                .0
+                .to_field_elements()
+                .unwrap(),
+        );
+        public_inputs.extend(Fq::from(output_data.height).to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(epoch_duration).to_field_elements().unwrap());
+        public_inputs.extend(note_commitment_1.0.to_field_elements().unwrap());
+        public_inputs.extend(note_commitment_2.0.to_field_elements().unwrap());
+
+        let proof_result =
+            Groth16::verify_with_processed_vk(&processed_pvk, public_inputs.as_slice(), &self.0)
+                .map_err(|err| anyhow::anyhow!(err))?;
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("proof did not verify"))
+    }
+}
+
+impl From<SwapClaimProof> for Vec<u8> {
+    fn from(proof: SwapClaimProof) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        proof
+            .0
+            .serialize(&mut bytes)
+            .expect("can serialize Groth16 proof");
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for SwapClaimProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<SwapClaimProof, Self::Error> {
+        Ok(SwapClaimProof(
+            Proof::deserialize(bytes).map_err(|_| anyhow::anyhow!("proof malformed"))?,
+        ))
+    }
+}
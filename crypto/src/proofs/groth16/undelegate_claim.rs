@@ -0,0 +1,188 @@
+use ark_r1cs_std::prelude::*;
+use ark_r1cs_std::uint8::UInt8;
+use decaf377::FieldExt;
+use decaf377::{r1cs::FqVar, Bls12_377, Fq, Fr};
+
+use ark_ff::ToConstraintField;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use decaf377_rdsa::{SigningKey, SpendAuth};
+use rand::{CryptoRng, Rng};
+use rand_core::OsRng;
+
+use crate::proofs::groth16::{gadgets, ParameterSetup};
+use crate::{
+    asset,
+    balance::{
+        self,
+        commitment::{blinding_commitment, BalanceCommitmentVar},
+    },
+    stake::{IdentityKey, Penalty, UnbondingToken},
+    value::ValueVar,
+    Amount, STAKING_TOKEN_ASSET_ID,
+};
+
+/// The denominator of a [`Penalty`], which is expressed in bps^2.
+const PENALTY_DENOMINATOR: u64 = 1_0000_0000;
+
+/// Groth16 proof for claiming undelegated stake once it has finished unbonding.
+#[derive(Clone, Debug)]
+pub struct UndelegateClaimCircuit {
+    // Witnesses
+    /// The amount of unbonding tokens being claimed.
+    unbonding_amount: Amount,
+    /// The blinding factor used for the balance commitment.
+    balance_blinding: Fr,
+
+    // Public inputs
+    /// The balance commitment of the claim.
+    pub balance_commitment: balance::Commitment,
+    /// The asset ID of the unbonding token being claimed.
+    pub unbonding_id: asset::Id,
+    /// The penalty applied to the unbonded stake.
+    pub penalty: Penalty,
+}
+
+impl ConstraintSynthesizer<Fq> for UndelegateClaimCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> ark_relations::r1cs::Result<()> {
+        // Witnesses
+        let unbonding_amount_var =
+            gadgets::witness_u128(cs.clone(), Ok(self.unbonding_amount.into()))?;
+        let balance_blinding_arr: [u8; 32] = self.balance_blinding.to_bytes();
+        let balance_blinding_vars = UInt8::new_witness_vec(cs.clone(), &balance_blinding_arr)?;
+
+        // Public inputs
+        let claimed_balance_commitment =
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.balance_commitment))?;
+        let unbonding_id_var = FqVar::new_input(cs.clone(), || Ok(self.unbonding_id.0))?;
+        let penalty_var = FqVar::new_input(cs.clone(), || Ok(Fq::from(self.penalty.0)))?;
+
+        // Apply the penalty to the unbonding amount, rounding down. The amount
+        // is bounded so that the product can't wrap around the field modulus.
+        gadgets::enforce_bit_length(&unbonding_amount_var, 64)?;
+        let penalty_denominator = FqVar::constant(Fq::from(PENALTY_DENOMINATOR));
+        let unbonded_amount_var = gadgets::divide_floor(
+            cs.clone(),
+            &(unbonding_amount_var.clone() * (penalty_denominator.clone() - penalty_var)),
+            &penalty_denominator,
+        )?;
+
+        // Balance commitment integrity. The claim consumes the unbonding tokens
+        // and produces the unbonded stake.
+        let unbonding = ValueVar {
+            amount: asset::AmountVar {
+                amount: unbonding_amount_var,
+            },
+            asset_id: asset::AssetIdVar {
+                asset_id: unbonding_id_var,
+            },
+        };
+        let unbonded = ValueVar {
+            amount: asset::AmountVar {
+                amount: unbonded_amount_var,
+            },
+            asset_id: asset::AssetIdVar {
+                asset_id: FqVar::constant(STAKING_TOKEN_ASSET_ID.0),
+            },
+        };
+        let balance_commitment = unbonded.unblinded_commitment()?
+            - unbonding.unblinded_commitment()?
+            + blinding_commitment(cs, balance_blinding_vars)?;
+        balance_commitment.enforce_equal(&claimed_balance_commitment.inner)?;
+
+        Ok(())
+    }
+}
+
+impl ParameterSetup for UndelegateClaimCircuit {
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+
+        let circuit = UndelegateClaimCircuit {
+            unbonding_amount: 1u64.into(),
+            balance_blinding: Fr::from(1),
+            balance_commitment: balance::Commitment(decaf377::basepoint()),
+            unbonding_id,
+            penalty: Penalty(1),
+        };
+        let (pk, vk) = Groth16::circuit_specific_setup(circuit, &mut OsRng)
+            .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UndelegateClaimProof(Proof<Bls12_377>);
+
+impl UndelegateClaimProof {
+    pub fn prove<R: CryptoRng + Rng>(
+        rng: &mut R,
+        pk: &ProvingKey<Bls12_377>,
+        unbonding_amount: Amount,
+        balance_blinding: Fr,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<Self> {
+        let circuit = UndelegateClaimCircuit {
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        };
+        let proof = Groth16::prove(pk, circuit, rng).map_err(|err| anyhow::anyhow!(err))?;
+        Ok(Self(proof))
+    }
+
+    /// Called to verify the proof using the provided public inputs.
+    ///
+    /// The public inputs are:
+    /// * balance commitment of the claim,
+    /// * asset ID of the unbonding token,
+    /// * penalty applied to the unbonded stake.
+    pub fn verify(
+        &self,
+        vk: &VerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<()> {
+        let processed_pvk = Groth16::process_vk(vk).map_err(|err| anyhow::anyhow!(err))?;
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(balance_commitment.0.to_field_elements().unwrap());
+        public_inputs.extend(unbonding_id.0.to_field_elements().unwrap());
+        public_inputs.extend(Fq::from(penalty.0).to_field_elements().unwrap());
+
+        let proof_result =
+            Groth16::verify_with_processed_vk(&processed_pvk, public_inputs.as_slice(), &self.0)
+                .map_err(|err| anyhow::anyhow!(err))?;
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("proof did not verify"))
+    }
+}
+
+impl From<UndelegateClaimProof> for Vec<u8> {
+    fn from(proof: UndelegateClaimProof) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        proof
+            .0
+            .serialize(&mut bytes)
+            .expect("can serialize Groth16 proof");
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for UndelegateClaimProof {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<UndelegateClaimProof, Self::Error> {
+        Ok(UndelegateClaimProof(
+            Proof::deserialize(bytes).map_err(|_| anyhow::anyhow!("proof malformed"))?,
+        ))
+    }
+}
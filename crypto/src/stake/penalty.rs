@@ -42,11 +42,10 @@ impl Penalty {
     }
 
     /// Helper method to compute the effect of an UndelegateClaim on the
-    /// transaction's value balance, used in planning and (transparent) proof
-    /// verification.
+    /// transaction's value balance, used in planning.
     ///
     /// This method takes the `unbonding_id` rather than the `UnbondingToken` so
-    /// that it can be used in mock proof verification, where computation of the
+    /// that it mirrors the undelegate claim circuit, where computation of the
     /// unbonding token's asset ID happens outside of the circuit.
     pub fn balance_for_claim(&self, unbonding_id: asset::Id, unbonding_amount: Amount) -> Balance {
         // The undelegate claim action subtracts the unbonding amount and adds
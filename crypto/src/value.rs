@@ -71,8 +71,8 @@ impl Value {
 
 #[derive(Clone)]
 pub struct ValueVar {
-    amount: asset::AmountVar,
-    asset_id: asset::AssetIdVar,
+    pub amount: asset::AmountVar,
+    pub asset_id: asset::AssetIdVar,
 }
 
 impl AllocVar<Value, Fq> for ValueVar {
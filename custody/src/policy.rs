@@ -1,17 +1,45 @@
 //! A set of basic spend authorization policies.
 
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
 
-use penumbra_crypto::Address;
+use penumbra_crypto::{asset, Address, STAKING_TOKEN_ASSET_ID};
 use penumbra_transaction::plan::ActionPlan;
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
 
 use crate::{AuthorizeRequest, PreAuthorization};
 
+mod history;
+
+pub use history::{Destination, Outflow, SpendHistory};
+
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
     fn check(&self, request: &AuthorizeRequest) -> Result<(), anyhow::Error>;
+
+    /// Checks whether the proposed transaction's [`Outflow`]s are allowed by
+    /// this policy, given the outflows of previously authorized transactions.
+    ///
+    /// Policies that don't depend on previously authorized transactions don't
+    /// need to implement this.
+    fn check_history(
+        &self,
+        _outflows: &[Outflow],
+        _history: &SpendHistory,
+        _now: SystemTime,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    /// How long this policy needs previously authorized transactions to be
+    /// remembered for, if at all.
+    fn retention(&self) -> Option<Duration> {
+        None
+    }
 }
 
 /// A set of basic spend authorization policies.
@@ -19,6 +47,7 @@ pub trait Policy {
 /// These policies are intended to be simple enough that they can be written by
 /// hand in a config file.  More complex policy logic than than should be
 /// implemented by a custom implementation of the [`Policy`] trait.
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -35,6 +64,39 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Limit the total amount of an asset sent outside of the account within
+    /// a rolling time window.
+    ///
+    /// Every [`Outflow`] counts towards the limit, including IBC withdrawals,
+    /// swaps, liquidity positions, delegations and proposal deposits.
+    SpendLimit {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        /// The maximum amount that can be sent within the window, in base units.
+        limit: u64,
+        /// The length of the rolling window, in seconds.
+        #[serde(default = "one_day")]
+        window_seconds: u64,
+    },
+    /// Limit the total amount of an asset sent to any single [`Destination`]
+    /// within a rolling time window (by default, one day).
+    DestinationSpendLimit {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        /// The maximum amount that can be sent to each destination within the
+        /// window, in base units.
+        limit: u64,
+        /// The length of the rolling window, in seconds.
+        #[serde(default = "one_day")]
+        window_seconds: u64,
+    },
+    /// Only allow transactions paying at most the given fee, in base units of
+    /// the staking token.
+    MaxFee { max_fee: u64 },
+}
+
+fn one_day() -> u64 {
+    24 * 60 * 60
 }
 
 /// A set of pre-authorization policies.
@@ -55,6 +117,45 @@ pub enum PreAuthorizationPolicy {
         #[serde(with = "ed25519_vec_base64")]
         allowed_signers: Vec<ed25519_consensus::VerificationKey>,
     },
+    /// Require pre-authorizations from a threshold of signers, which may use
+    /// any supported signature scheme.
+    // In toml, each signer will turn into
+    // [[auth_policy.allowed_signers]]
+    // method = 'P256'
+    // key = '<base64-encoded SEC1 key>'
+    Threshold {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of pre-authorization keys that can be used to authorize a transaction plan.
+        allowed_signers: Vec<PreAuthorizationKey>,
+    },
+}
+
+/// A pre-authorization key for any supported signature scheme.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "method", content = "key")]
+pub enum PreAuthorizationKey {
+    Ed25519(#[serde(with = "key_base64")] ed25519_consensus::VerificationKey),
+    Secp256k1(#[serde(with = "key_base64")] k256::ecdsa::VerifyingKey),
+    P256(#[serde(with = "key_base64")] p256::ecdsa::VerifyingKey),
+}
+
+impl PreAuthorizationKey {
+    /// Whether the given pre-authorization was made with this key.
+    fn signed(&self, pre_auth: &PreAuthorization) -> bool {
+        match (self, pre_auth) {
+            (PreAuthorizationKey::Ed25519(vk), PreAuthorization::Ed25519(pre_auth)) => {
+                *vk == pre_auth.vk
+            }
+            (PreAuthorizationKey::Secp256k1(vk), PreAuthorization::Secp256k1(pre_auth)) => {
+                *vk == pre_auth.vk
+            }
+            (PreAuthorizationKey::P256(vk), PreAuthorization::P256(pre_auth)) => *vk == pre_auth.vk,
+            _ => false,
+        }
+    }
 }
 
 /// A serde helper to serialize pre-authorization keys as base64-encoded data.
@@ -93,6 +194,61 @@ mod ed25519_vec_base64 {
     }
 }
 
+/// A serde helper to serialize a single pre-authorization key of any supported
+/// signature scheme as base64-encoded data, using the SEC1 encoding for ECDSA
+/// keys.
+mod key_base64 {
+    pub trait KeyBytes: Sized {
+        fn to_key_bytes(&self) -> Vec<u8>;
+        fn from_key_bytes(bytes: &[u8]) -> anyhow::Result<Self>;
+    }
+
+    impl KeyBytes for ed25519_consensus::VerificationKey {
+        fn to_key_bytes(&self) -> Vec<u8> {
+            self.as_bytes().to_vec()
+        }
+        fn from_key_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+            Self::try_from(bytes).map_err(Into::into)
+        }
+    }
+
+    impl KeyBytes for k256::ecdsa::VerifyingKey {
+        fn to_key_bytes(&self) -> Vec<u8> {
+            self.to_encoded_point(true).as_bytes().to_vec()
+        }
+        fn from_key_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+            Self::from_sec1_bytes(bytes).map_err(Into::into)
+        }
+    }
+
+    impl KeyBytes for p256::ecdsa::VerifyingKey {
+        fn to_key_bytes(&self) -> Vec<u8> {
+            self.to_encoded_point(true).as_bytes().to_vec()
+        }
+        fn from_key_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+            Self::from_sec1_bytes(bytes).map_err(Into::into)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer, K: KeyBytes>(
+        key: &K,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode(key.to_key_bytes()))
+    }
+
+    pub fn deserialize<'de, D, K>(deserializer: D) -> Result<K, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        K: KeyBytes,
+    {
+        use serde::Deserialize;
+        let base64_key = String::deserialize(deserializer)?;
+        let bytes = base64::decode(base64_key).map_err(serde::de::Error::custom)?;
+        K::from_key_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Policy for AuthPolicy {
     fn check(&self, request: &AuthorizeRequest) -> Result<(), anyhow::Error> {
         let plan = &request.plan;
@@ -116,6 +272,14 @@ impl Policy for AuthPolicy {
                         ));
                     }
                 }
+                // Withdrawals send funds to an address on another chain, which
+                // can never be in the allow list.
+                if let Some(withdrawal) = plan.ics20_withdrawals().next() {
+                    return Err(anyhow::anyhow!(
+                        "withdrawal {:?} has a destination outside the allow list",
+                        withdrawal
+                    ));
+                }
                 Ok(())
             }
             AuthPolicy::OnlyIbcRelay => {
@@ -135,6 +299,95 @@ impl Policy for AuthPolicy {
                 Ok(())
             }
             AuthPolicy::PreAuthorization(policy) => policy.check(request),
+            AuthPolicy::MaxFee { max_fee } => {
+                let fee = &plan.fee.0;
+                if fee.asset_id != *STAKING_TOKEN_ASSET_ID {
+                    return Err(anyhow::anyhow!(
+                        "fee {:?} is not paid in the staking token",
+                        fee
+                    ));
+                }
+                if fee.amount.value() > *max_fee {
+                    return Err(anyhow::anyhow!(
+                        "fee of {} exceeds the maximum fee of {}",
+                        fee.amount,
+                        max_fee
+                    ));
+                }
+                Ok(())
+            }
+            // Rate limits are enforced by `check_history`.
+            AuthPolicy::SpendLimit { .. } | AuthPolicy::DestinationSpendLimit { .. } => Ok(()),
+        }
+    }
+
+    fn check_history(
+        &self,
+        outflows: &[Outflow],
+        history: &SpendHistory,
+        now: SystemTime,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            AuthPolicy::SpendLimit {
+                asset_id,
+                limit,
+                window_seconds,
+            } => {
+                let window = Duration::from_secs(*window_seconds);
+                let spent = history.total_spent(asset_id, None, window, now);
+                let spending = outflows
+                    .iter()
+                    .filter(|outflow| outflow.asset_id == *asset_id)
+                    .fold(0u64, |total, outflow| total.saturating_add(outflow.amount));
+                if spent.saturating_add(spending) > *limit {
+                    return Err(anyhow::anyhow!(
+                        "sending {} of {} would exceed the limit of {} per {}s ({} already sent)",
+                        spending,
+                        asset_id,
+                        limit,
+                        window_seconds,
+                        spent
+                    ));
+                }
+                Ok(())
+            }
+            AuthPolicy::DestinationSpendLimit {
+                asset_id,
+                limit,
+                window_seconds,
+            } => {
+                let window = Duration::from_secs(*window_seconds);
+                for outflow in outflows
+                    .iter()
+                    .filter(|outflow| outflow.asset_id == *asset_id)
+                {
+                    let spent =
+                        history.total_spent(asset_id, Some(&outflow.destination), window, now);
+                    if spent.saturating_add(outflow.amount) > *limit {
+                        return Err(anyhow::anyhow!(
+                            "sending {} of {} to {} would exceed the limit of {} per {}s ({} already sent)",
+                            outflow.amount,
+                            asset_id,
+                            outflow.destination,
+                            limit,
+                            window_seconds,
+                            spent
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn retention(&self) -> Option<Duration> {
+        match self {
+            AuthPolicy::SpendLimit { window_seconds, .. }
+            | AuthPolicy::DestinationSpendLimit { window_seconds, .. } => {
+                Some(Duration::from_secs(*window_seconds))
+            }
+            _ => None,
         }
     }
 }
@@ -152,7 +405,7 @@ impl Policy for PreAuthorizationPolicy {
                         .iter()
                         .filter_map(|pre_auth| match pre_auth {
                             PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
-                            // _ => None,
+                            _ => None,
                         });
 
                 let mut allowed_signers = allowed_signers.iter().cloned().collect::<HashSet<_>>();
@@ -176,6 +429,129 @@ impl Policy for PreAuthorizationPolicy {
                 }
                 Ok(())
             }
+            PreAuthorizationPolicy::Threshold {
+                required_signatures,
+                allowed_signers,
+            } => {
+                let mut remaining_signers = allowed_signers.iter().collect::<Vec<_>>();
+                let mut seen_signers = 0;
+
+                for pre_auth in &request.pre_authorizations {
+                    if remaining_signers
+                        .iter()
+                        .any(|signer| signer.signed(pre_auth))
+                    {
+                        // Remove the signer from the remaining signers, so that
+                        // each signer can only submit one pre-authorization.
+                        remaining_signers.retain(|signer| !signer.signed(pre_auth));
+                        pre_auth.verify_plan(&request.plan)?;
+                        seen_signers += 1;
+                    }
+                }
+
+                if seen_signers < *required_signatures as usize {
+                    return Err(anyhow::anyhow!(
+                        "required {} pre-authorization signatures but only saw {}",
+                        required_signatures,
+                        seen_signers,
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::keys::AccountID;
+    use penumbra_proto::{custody::v1alpha1 as pb, DomainType};
+    use penumbra_transaction::plan::TransactionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn threshold_pre_authorization_mixes_signer_types() {
+        let plan = TransactionPlan::default();
+        let plan_bytes = plan.encode_to_vec();
+
+        let ed25519_key = ed25519_consensus::SigningKey::new(OsRng);
+        let secp256k1_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+        let p256_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+
+        let ed25519_auth: PreAuthorization = pb::PreAuthorization {
+            pre_authorization: Some(pb::pre_authorization::PreAuthorization::Ed25519(
+                pb::pre_authorization::Ed25519 {
+                    vk: ed25519_key.verification_key().to_bytes().to_vec(),
+                    sig: ed25519_key.sign(&plan_bytes).to_bytes().to_vec(),
+                },
+            )),
         }
+        .try_into()
+        .unwrap();
+        let secp256k1_auth: PreAuthorization = {
+            use k256::ecdsa::{signature::Signer, Signature};
+            let sig: Signature = secp256k1_key.sign(&plan_bytes);
+            pb::PreAuthorization {
+                pre_authorization: Some(pb::pre_authorization::PreAuthorization::Secp256k1(
+                    pb::pre_authorization::Secp256k1 {
+                        vk: secp256k1_key
+                            .verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes()
+                            .to_vec(),
+                        sig: sig.as_ref().to_vec(),
+                    },
+                )),
+            }
+            .try_into()
+            .unwrap()
+        };
+        let p256_auth: PreAuthorization = {
+            use p256::ecdsa::{signature::Signer, Signature};
+            // Hardware-backed keys typically produce DER-encoded signatures.
+            let sig: Signature = p256_key.sign(&plan_bytes);
+            pb::PreAuthorization {
+                pre_authorization: Some(pb::pre_authorization::PreAuthorization::P256(
+                    pb::pre_authorization::P256 {
+                        vk: p256_key
+                            .verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes()
+                            .to_vec(),
+                        sig: sig.to_der().as_bytes().to_vec(),
+                    },
+                )),
+            }
+            .try_into()
+            .unwrap()
+        };
+
+        let policy = PreAuthorizationPolicy::Threshold {
+            required_signatures: 2,
+            allowed_signers: vec![
+                PreAuthorizationKey::Ed25519(ed25519_key.verification_key()),
+                PreAuthorizationKey::Secp256k1(secp256k1_key.verifying_key()),
+                PreAuthorizationKey::P256(p256_key.verifying_key()),
+            ],
+        };
+        let request = |pre_authorizations| AuthorizeRequest {
+            plan: plan.clone(),
+            account_id: AccountID([0; 32]),
+            pre_authorizations,
+        };
+
+        assert!(policy
+            .check(&request(vec![secp256k1_auth.clone(), p256_auth.clone()]))
+            .is_ok());
+        assert!(policy
+            .check(&request(vec![ed25519_auth.clone(), p256_auth]))
+            .is_ok());
+        // A single signer can't meet the threshold by signing twice.
+        assert!(policy
+            .check(&request(vec![secp256k1_auth.clone(), secp256k1_auth]))
+            .is_err());
+        assert!(policy.check(&request(vec![ed25519_auth])).is_err());
     }
 }
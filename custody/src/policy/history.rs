@@ -0,0 +1,342 @@
+use std::{
+    io::Write,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use penumbra_crypto::{
+    asset, keys::FullViewingKey, stake::IdentityKey, Address, Value, STAKING_TOKEN_ASSET_ID,
+};
+use penumbra_transaction::plan::{ActionPlan, TransactionPlan};
+use serde::{Deserialize, Serialize};
+
+/// Where the value of an [`Outflow`] is sent.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum Destination {
+    /// An address outside of the signing account.
+    Address(Address),
+    /// An address on a counterparty chain, reached over an IBC channel.
+    Ics20 { channel: String, address: String },
+    /// The DEX, as the input of a swap or the reserves of a liquidity position.
+    Dex,
+    /// A validator's delegation pool.
+    Delegation(IdentityKey),
+    /// The deposit for a governance proposal.
+    ProposalDeposit,
+}
+
+impl std::fmt::Display for Destination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Destination::Address(address) => write!(f, "{}", address),
+            Destination::Ics20 { channel, address } => write!(f, "{} via {}", address, channel),
+            Destination::Dex => write!(f, "the DEX"),
+            Destination::Delegation(identity_key) => write!(f, "validator {}", identity_key),
+            Destination::ProposalDeposit => write!(f, "a proposal deposit"),
+        }
+    }
+}
+
+/// Value sent by a transaction outside of the signing account.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Outflow {
+    /// Where the value is sent.
+    pub destination: Destination,
+    /// The asset being sent.
+    pub asset_id: asset::Id,
+    /// The amount of the asset being sent.
+    pub amount: u64,
+}
+
+impl Outflow {
+    /// Compute the outflows of a transaction plan: the value it moves out of
+    /// the account controlled by the given full viewing key, whether by
+    /// outputs to other addresses, IBC withdrawals, swaps, liquidity
+    /// positions, delegations, or proposal deposits.
+    ///
+    /// Outflows to the same destination and asset are combined.
+    pub fn from_plan(plan: &TransactionPlan, fvk: &FullViewingKey) -> Vec<Outflow> {
+        let mut outflows: Vec<Outflow> = Vec::new();
+        let mut add = |destination: Destination, value: Value| {
+            let amount = value.amount.value();
+            if amount == 0 {
+                return;
+            }
+            match outflows.iter_mut().find(|outflow| {
+                outflow.destination == destination && outflow.asset_id == value.asset_id
+            }) {
+                Some(outflow) => outflow.amount = outflow.amount.saturating_add(amount),
+                None => outflows.push(Outflow {
+                    destination,
+                    asset_id: value.asset_id,
+                    amount,
+                }),
+            }
+        };
+
+        // Every action is listed explicitly, so that new actions that move
+        // value must be accounted for here.
+        for action in &plan.actions {
+            match action {
+                ActionPlan::Output(output) => {
+                    if fvk.address_index(&output.dest_address).is_none() {
+                        add(Destination::Address(output.dest_address), output.value);
+                    }
+                }
+                ActionPlan::Ics20Withdrawal(withdrawal) => add(
+                    Destination::Ics20 {
+                        channel: withdrawal.source_channel.to_string(),
+                        address: withdrawal.destination_chain_address.clone(),
+                    },
+                    withdrawal.value(),
+                ),
+                ActionPlan::Swap(swap) => {
+                    let swap = &swap.swap_plaintext;
+                    add(
+                        Destination::Dex,
+                        Value {
+                            amount: swap.delta_1_i,
+                            asset_id: swap.trading_pair.asset_1(),
+                        },
+                    );
+                    add(
+                        Destination::Dex,
+                        Value {
+                            amount: swap.delta_2_i,
+                            asset_id: swap.trading_pair.asset_2(),
+                        },
+                    );
+                }
+                ActionPlan::PositionOpen(position_open) => {
+                    let pair = &position_open.position.phi.pair;
+                    add(
+                        Destination::Dex,
+                        Value {
+                            amount: position_open.initial_reserves.r1,
+                            asset_id: pair.asset_1(),
+                        },
+                    );
+                    add(
+                        Destination::Dex,
+                        Value {
+                            amount: position_open.initial_reserves.r2,
+                            asset_id: pair.asset_2(),
+                        },
+                    );
+                }
+                ActionPlan::Delegate(delegate) => add(
+                    Destination::Delegation(delegate.validator_identity),
+                    Value {
+                        amount: delegate.unbonded_amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    },
+                ),
+                ActionPlan::ProposalSubmit(proposal_submit) => add(
+                    Destination::ProposalDeposit,
+                    Value {
+                        amount: proposal_submit.deposit_amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    },
+                ),
+                // These actions only move value within the account.
+                ActionPlan::Spend(_)
+                | ActionPlan::Undelegate(_)
+                | ActionPlan::UndelegateClaim(_)
+                | ActionPlan::SwapClaim(_)
+                | ActionPlan::PositionClose(_)
+                | ActionPlan::PositionWithdraw(_)
+                | ActionPlan::PositionRewardClaim(_)
+                | ActionPlan::ProposalWithdraw(_)
+                | ActionPlan::ProposalDepositClaim(_)
+                | ActionPlan::DelegatorVote(_)
+                | ActionPlan::ValidatorVote(_)
+                | ActionPlan::ValidatorDefinition(_)
+                | ActionPlan::IBCAction(_) => {}
+            }
+        }
+        outflows
+    }
+}
+
+/// An [`Outflow`] that was authorized at a particular time.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct Record {
+    /// The authorization time, in seconds since the Unix epoch.
+    timestamp: u64,
+    #[serde(flatten)]
+    outflow: Outflow,
+}
+
+/// A record of previously authorized outflows, used to enforce rate limits.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SpendHistory {
+    records: Vec<Record>,
+}
+
+impl SpendHistory {
+    /// Read the spend history from the provided path, or start an empty
+    /// history if the file does not exist yet.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        serde_json::from_slice(std::fs::read(path)?.as_slice()).map_err(Into::into)
+    }
+
+    /// Write the spend history to the provided path.
+    ///
+    /// The history is written to a temporary file and then moved into place,
+    /// so that a crash while saving can't lose previously recorded spends.
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(&serde_json::to_vec(self)?)?;
+        file.sync_all()?;
+        std::fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Record the outflows of a transaction authorized at time `now`.
+    pub fn record(&mut self, outflows: &[Outflow], now: SystemTime) {
+        let timestamp = unix_seconds(now);
+        self.records.extend(
+            outflows
+                .iter()
+                .cloned()
+                .map(|outflow| Record { timestamp, outflow }),
+        );
+    }
+
+    /// Forget all outflows authorized more than `retention` before `now`.
+    pub fn prune(&mut self, retention: Duration, now: SystemTime) {
+        let cutoff = unix_seconds(now).saturating_sub(retention.as_secs());
+        self.records.retain(|record| record.timestamp > cutoff);
+    }
+
+    /// The total amount of the given asset sent within the `window` before
+    /// `now`, optionally only counting outflows to the given destination.
+    pub fn total_spent(
+        &self,
+        asset_id: &asset::Id,
+        destination: Option<&Destination>,
+        window: Duration,
+        now: SystemTime,
+    ) -> u64 {
+        let cutoff = unix_seconds(now).saturating_sub(window.as_secs());
+        self.records
+            .iter()
+            .filter(|record| record.timestamp > cutoff)
+            .filter(|record| &record.outflow.asset_id == asset_id)
+            .filter(|record| destination.map_or(true, |d| &record.outflow.destination == d))
+            .fold(0u64, |total, record| {
+                total.saturating_add(record.outflow.amount)
+            })
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::{keys::SeedPhrase, keys::SpendKey, Amount};
+    use penumbra_proto::core::ibc::v1alpha1 as pb_ibc;
+
+    use super::*;
+    use crate::policy::{AuthPolicy, Policy};
+
+    #[test]
+    fn withdrawals_are_outflows() {
+        let seed_phrase = SeedPhrase::generate(rand_core::OsRng);
+        let spend_key = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk = spend_key.full_viewing_key();
+        let (return_address, _) = fvk.payment_address(0u32.into());
+
+        let withdrawal = pb_ibc::Ics20Withdrawal {
+            destination_chain_id: "counterparty".to_string(),
+            denom: Some(asset::REGISTRY.parse_denom("upenumbra").unwrap().into()),
+            amount: Some(Amount::from(600u64).into()),
+            destination_chain_address: "cosmos1recipient".to_string(),
+            return_address: Some(return_address.into()),
+            timeout_height: 0,
+            timeout_time: 1,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+        }
+        .try_into()
+        .unwrap();
+        let plan = TransactionPlan {
+            actions: vec![ActionPlan::Ics20Withdrawal(withdrawal)],
+            ..Default::default()
+        };
+
+        let outflows = Outflow::from_plan(&plan, fvk);
+        assert_eq!(
+            outflows,
+            vec![Outflow {
+                destination: Destination::Ics20 {
+                    channel: "channel-0".to_string(),
+                    address: "cosmos1recipient".to_string(),
+                },
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                amount: 600,
+            }]
+        );
+
+        // The withdrawal counts towards the spend limit.
+        let policy = AuthPolicy::SpendLimit {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            limit: 1000,
+            window_seconds: 3600,
+        };
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let mut history = SpendHistory::default();
+        assert!(policy.check_history(&outflows, &history, now).is_ok());
+        history.record(&outflows, now);
+        assert!(policy.check_history(&outflows, &history, now).is_err());
+    }
+
+    #[test]
+    fn rolling_window_totals() {
+        let seed_phrase = SeedPhrase::generate(rand_core::OsRng);
+        let spend_key = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let ivk = spend_key.incoming_viewing_key();
+        let (alice, _) = ivk.payment_address(0u32.into());
+        let (bob, _) = ivk.payment_address(1u32.into());
+
+        let start = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let hour = Duration::from_secs(3600);
+        let outflow = |destination, amount| Outflow {
+            destination: Destination::Address(destination),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            amount,
+        };
+
+        let mut history = SpendHistory::default();
+        history.record(&[outflow(alice, 100), outflow(bob, 10)], start);
+        history.record(&[outflow(alice, 50)], start + hour);
+
+        let now = start + 2 * hour;
+        let day = 24 * hour;
+        let total = |destination, window| {
+            history.total_spent(&STAKING_TOKEN_ASSET_ID, destination, window, now)
+        };
+        assert_eq!(total(None, day), 160);
+        assert_eq!(total(Some(&Destination::Address(alice)), day), 150);
+        assert_eq!(total(Some(&Destination::Address(bob)), day), 10);
+        // Only the second spend is within the last 90 minutes.
+        assert_eq!(total(None, hour + hour / 2), 50);
+
+        // Pruning forgets spends that fall outside of the retention period.
+        history.prune(hour + hour / 2, now);
+        assert_eq!(
+            history.total_spent(&STAKING_TOKEN_ASSET_ID, None, day, now),
+            50
+        );
+    }
+}
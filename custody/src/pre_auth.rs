@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 #[serde(try_from = "pb::PreAuthorization", into = "pb::PreAuthorization")]
 pub enum PreAuthorization {
     Ed25519(Ed25519),
+    Secp256k1(Secp256k1),
+    P256(P256),
 }
 
 /// An Ed25519-based preauthorization, containing an Ed25519 signature over the
@@ -34,6 +36,66 @@ impl Ed25519 {
     }
 }
 
+/// An ECDSA preauthorization over the secp256k1 curve, containing a signature
+/// over the SHA-256 hash of the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::Secp256k1",
+    into = "pb::pre_authorization::Secp256k1"
+)]
+pub struct Secp256k1 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: k256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: k256::ecdsa::Signature,
+}
+
+impl Secp256k1 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify_plan(&self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        use k256::ecdsa::signature::Verifier;
+        let plan_bytes = plan.encode_to_vec();
+        // Many secp256k1 signers don't normalize signatures to low-S form,
+        // which the verifier requires.
+        let sig = self.sig.normalize_s().unwrap_or(self.sig);
+        self.vk.verify(&plan_bytes, &sig).map_err(Into::into)
+    }
+}
+
+/// An ECDSA preauthorization over the NIST P-256 curve, containing a signature
+/// over the SHA-256 hash of the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::P256",
+    into = "pb::pre_authorization::P256"
+)]
+pub struct P256 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: p256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: p256::ecdsa::Signature,
+}
+
+impl P256 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify_plan(&self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        use p256::ecdsa::signature::Verifier;
+        let plan_bytes = plan.encode_to_vec();
+        self.vk.verify(&plan_bytes, &self.sig).map_err(Into::into)
+    }
+}
+
+impl PreAuthorization {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify_plan(&self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        match self {
+            PreAuthorization::Ed25519(pre_auth) => pre_auth.verify_plan(plan),
+            PreAuthorization::Secp256k1(pre_auth) => pre_auth.verify_plan(plan),
+            PreAuthorization::P256(pre_auth) => pre_auth.verify_plan(plan),
+        }
+    }
+}
+
 impl DomainType for PreAuthorization {
     type Proto = pb::PreAuthorization;
 }
@@ -45,6 +107,10 @@ impl TryFrom<pb::PreAuthorization> for PreAuthorization {
             Some(pb::pre_authorization::PreAuthorization::Ed25519(ed)) => {
                 Self::Ed25519(ed.try_into()?)
             }
+            Some(pb::pre_authorization::PreAuthorization::Secp256k1(k)) => {
+                Self::Secp256k1(k.try_into()?)
+            }
+            Some(pb::pre_authorization::PreAuthorization::P256(p)) => Self::P256(p.try_into()?),
             None => {
                 return Err(anyhow::anyhow!("missing pre-authorization"));
             }
@@ -59,6 +125,12 @@ impl From<PreAuthorization> for pb::PreAuthorization {
                 PreAuthorization::Ed25519(ed) => {
                     pb::pre_authorization::PreAuthorization::Ed25519(ed.into())
                 }
+                PreAuthorization::Secp256k1(k) => {
+                    pb::pre_authorization::PreAuthorization::Secp256k1(k.into())
+                }
+                PreAuthorization::P256(p) => {
+                    pb::pre_authorization::PreAuthorization::P256(p.into())
+                }
             }),
         }
     }
@@ -86,3 +158,53 @@ impl From<Ed25519> for pb::pre_authorization::Ed25519 {
         }
     }
 }
+
+impl DomainType for Secp256k1 {
+    type Proto = pb::pre_authorization::Secp256k1;
+}
+
+impl TryFrom<pb::pre_authorization::Secp256k1> for Secp256k1 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::Secp256k1) -> Result<Self, Self::Error> {
+        use k256::ecdsa::Signature;
+        Ok(Self {
+            vk: k256::ecdsa::VerifyingKey::from_sec1_bytes(&value.vk)?,
+            sig: Signature::try_from(value.sig.as_slice())
+                .or_else(|_| Signature::from_der(&value.sig))?,
+        })
+    }
+}
+
+impl From<Secp256k1> for pb::pre_authorization::Secp256k1 {
+    fn from(value: Secp256k1) -> pb::pre_authorization::Secp256k1 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.as_ref().to_vec(),
+        }
+    }
+}
+
+impl DomainType for P256 {
+    type Proto = pb::pre_authorization::P256;
+}
+
+impl TryFrom<pb::pre_authorization::P256> for P256 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::P256) -> Result<Self, Self::Error> {
+        use p256::ecdsa::Signature;
+        Ok(Self {
+            vk: p256::ecdsa::VerifyingKey::from_sec1_bytes(&value.vk)?,
+            sig: Signature::try_from(value.sig.as_slice())
+                .or_else(|_| Signature::from_der(&value.sig))?,
+        })
+    }
+}
+
+impl From<P256> for pb::pre_authorization::P256 {
+    fn from(value: P256) -> pb::pre_authorization::P256 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.as_ref().to_vec(),
+        }
+    }
+}
@@ -1,12 +1,17 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use std::{sync::Mutex, time::SystemTime};
+
 use penumbra_proto::custody::v1alpha1::{self as pb, AuthorizeResponse};
 use penumbra_transaction::AuthorizationData;
 use rand_core::OsRng;
 use tonic::{async_trait, Request, Response, Status};
 
-use crate::{policy::Policy, AuthorizeRequest};
+use crate::{
+    policy::{Outflow, Policy, SpendHistory},
+    AuthorizeRequest,
+};
 
 mod config;
 
@@ -16,12 +21,21 @@ pub use config::Config;
 /// presents as an asynchronous signer.
 pub struct SoftKms {
     config: Config,
+    history: Mutex<SpendHistory>,
 }
 
 impl SoftKms {
-    /// Initialize with the given [`Config`].
-    pub fn new(config: Config) -> Self {
-        Self { config }
+    /// Initialize with the given [`Config`], loading the spend history from
+    /// the configured path, if any.
+    pub fn new(config: Config) -> anyhow::Result<Self> {
+        let history = match &config.spend_history_path {
+            Some(path) => SpendHistory::load(path)?,
+            None => SpendHistory::default(),
+        };
+        Ok(Self {
+            config,
+            history: Mutex::new(history),
+        })
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan).
@@ -33,8 +47,50 @@ impl SoftKms {
             policy.check(request)?;
         }
 
+        self.check_and_record_outflows(request, SystemTime::now())?;
+
         Ok(request.plan.authorize(OsRng, &self.config.spend_key))
     }
+
+    /// Check the request against the rate-limiting policies, and if it's
+    /// allowed, record its outflows in the spend history.
+    ///
+    /// The updated history is persisted before the request is signed, so that
+    /// a restart can't be used to reset the rate limits.
+    fn check_and_record_outflows(
+        &self,
+        request: &AuthorizeRequest,
+        now: SystemTime,
+    ) -> anyhow::Result<()> {
+        let retention = match self
+            .config
+            .auth_policy
+            .iter()
+            .filter_map(Policy::retention)
+            .max()
+        {
+            Some(retention) => retention,
+            // No policy depends on the spend history, so don't record it.
+            None => return Ok(()),
+        };
+
+        let outflows = Outflow::from_plan(&request.plan, self.config.spend_key.full_viewing_key());
+
+        let mut history = self.history.lock().expect("history lock is not poisoned");
+        for policy in &self.config.auth_policy {
+            policy.check_history(&outflows, &history, now)?;
+        }
+
+        let mut new_history = history.clone();
+        new_history.record(&outflows, now);
+        new_history.prune(retention, now);
+        if let Some(path) = &self.config.spend_history_path {
+            new_history.save(path)?;
+        }
+        *history = new_history;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
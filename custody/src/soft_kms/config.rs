@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::policy::AuthPolicy;
 use penumbra_crypto::keys::SpendKey;
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,13 @@ pub struct Config {
     pub spend_key: SpendKey,
     #[serde(default, skip_serializing_if = "is_default")]
     pub auth_policy: Vec<AuthPolicy>,
+    /// Where to persist the history of authorized spends used to enforce
+    /// rate-limiting policies.
+    ///
+    /// If unset, the history is only kept in memory, and rate limits reset
+    /// whenever the [`SoftKms`](super::SoftKms) restarts.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spend_history_path: Option<PathBuf>,
 }
 
 impl From<SpendKey> for Config {
@@ -21,6 +30,7 @@ impl From<SpendKey> for Config {
         Self {
             spend_key,
             auth_policy: Default::default(),
+            spend_history_path: Default::default(),
         }
     }
 }
@@ -38,9 +48,9 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use penumbra_crypto::keys::SeedPhrase;
+    use penumbra_crypto::{keys::SeedPhrase, STAKING_TOKEN_ASSET_ID};
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::policy::{PreAuthorizationKey, PreAuthorizationPolicy};
 
     use super::*;
 
@@ -66,11 +76,35 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::SpendLimit {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                limit: 1_000_000,
+                window_seconds: 7 * 24 * 60 * 60,
+            },
+            AuthPolicy::DestinationSpendLimit {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                limit: 100_000,
+                window_seconds: 24 * 60 * 60,
+            },
+            AuthPolicy::MaxFee { max_fee: 1_000 },
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Threshold {
+                required_signatures: 2,
+                allowed_signers: vec![
+                    PreAuthorizationKey::Ed25519(pvk),
+                    PreAuthorizationKey::Secp256k1(
+                        k256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key(),
+                    ),
+                    PreAuthorizationKey::P256(
+                        p256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key(),
+                    ),
+                ],
+            }),
         ];
 
         let example = Config {
             spend_key: spend_key.clone(),
             auth_policy,
+            spend_history_path: Some("spend_history.json".into()),
         };
 
         let encoded = toml::to_string_pretty(&example).unwrap();
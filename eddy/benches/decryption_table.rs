@@ -0,0 +1,43 @@
+use criterion::{async_executor::FuturesExecutor, criterion_group, criterion_main, Criterion};
+use rand_core::{OsRng, RngCore};
+
+use penumbra_eddy::{DecryptionTable, RocksDbDecryptionTable};
+
+/// The bitsize of the benchmarked table, large enough that it doesn't fit in
+/// the block cache.
+const TABLE_BITS: usize = 22;
+
+fn bench(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let table = RocksDbDecryptionTable::open(dir.path()).unwrap();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(table.initialize(TABLE_BITS)).unwrap();
+    // Lookups read from Tokio's blocking thread pool, so they need to run
+    // inside the runtime's context.
+    let _guard = runtime.enter();
+
+    let keys = (0..1024)
+        .map(|_| {
+            let x = OsRng.next_u32() % (1 << TABLE_BITS);
+            (decaf377::Fr::from(x) * decaf377::basepoint())
+                .vartime_compress()
+                .0
+        })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("decryption-table");
+    group.bench_function("rocksdb_lookup", |b| {
+        let mut i = 0;
+        b.to_async(FuturesExecutor).iter(|| {
+            i = (i + 1) % keys.len();
+            table.lookup(keys[i])
+        })
+    });
+    group.bench_function("rocksdb_reopen", |b| {
+        b.iter(|| RocksDbDecryptionTable::open(dir.path()).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
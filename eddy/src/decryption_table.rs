@@ -1,14 +1,20 @@
 //! Lookup tables for decryption.
 //!
-//! Currently, this module just has a naive, in-memory implementation.  In the
-//! future, it should have a trait that allows users to plug their own storage
-//! system to back the lookup table.
+//! The [`DecryptionTable`] trait allows users to plug their own storage system
+//! to back the lookup table.  This module provides a naive, in-memory
+//! implementation for testing, and (with the `rocksdb` feature) a persistent
+//! implementation backed by RocksDB.
 
 use std::{collections::BTreeMap, future::Future, pin::Pin, sync::Arc};
 
 use futures::FutureExt;
 use parking_lot::Mutex;
 
+#[cfg(feature = "rocksdb")]
+mod persistent;
+#[cfg(feature = "rocksdb")]
+pub use persistent::RocksDbDecryptionTable;
+
 /// An error indicating that the [`DecryptionTable`] did not contain a requested
 /// discrete logarithm.
 ///
@@ -40,11 +46,6 @@ pub trait DecryptionTable: Send + Sync {
         key: [u8; 32],
     ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>>;
     /// Store a 32-bit discrete logarithm, indexed by the byte-encoded group element.
-    // idea: a rocksdb backing store could store the data in the form
-    // (nbits || key, value)
-    // where nbits is the bitsize of value
-    // then do a linear scan through bitsizes to cut the working set size
-    // might not be better than naive caching?
     fn store(
         &self,
         key: [u8; 32],
@@ -0,0 +1,185 @@
+use std::{future::Future, path::Path, pin::Pin, sync::Arc};
+
+use anyhow::Context;
+use futures::FutureExt;
+use rocksdb::{Options, WriteBatch, WriteOptions, DB};
+
+use super::DecryptionTable;
+
+/// The key under which the bitsize of the initialized table is recorded.
+///
+/// Table keys are always 32 bytes long, so this can't collide with an entry.
+const INITIALIZED_BITS_KEY: &[u8] = b"eddy/initialized_bits";
+
+/// The number of entries written per batch during initialization.
+const BATCH_SIZE: u32 = 1 << 16;
+
+/// A persistent decryption table backed by RocksDB.
+///
+/// Entries are stored as a flat mapping from the 32-byte encoding of a group
+/// element to the little-endian encoding of its discrete logarithm.  The table
+/// records how far it has been initialized, so that reopening an
+/// already-initialized table is cheap: [`DecryptionTable::initialize`] only
+/// computes the entries that are missing.
+#[derive(Clone)]
+pub struct RocksDbDecryptionTable {
+    db: Arc<DB>,
+}
+
+impl RocksDbDecryptionTable {
+    /// Open the table stored at `path`, creating an empty one if none exists.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        // Lookups are random point reads of uniformly distributed keys, so
+        // there's no locality for range scans to exploit.
+        opts.optimize_for_point_lookup(64);
+
+        let db = DB::open(&opts, path.as_ref()).with_context(|| {
+            format!(
+                "could not open decryption table at {}",
+                path.as_ref().display()
+            )
+        })?;
+
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    /// The bitsize `k` such that all discrete logarithms up to `2^k` are
+    /// present in the table, if it has been initialized.
+    pub fn initialized_bits(&self) -> anyhow::Result<Option<usize>> {
+        self.db
+            .get(INITIALIZED_BITS_KEY)?
+            .map(|bytes| {
+                let bytes: [u8; 8] = bytes
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("malformed initialized bitsize"))?;
+                Ok(u64::from_le_bytes(bytes) as usize)
+            })
+            .transpose()
+    }
+
+    fn lookup_blocking(&self, key: [u8; 32]) -> anyhow::Result<Option<u32>> {
+        self.db
+            .get_pinned(key)?
+            .map(|bytes| {
+                let bytes: [u8; 4] = bytes
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("malformed decryption table entry"))?;
+                Ok(u32::from_le_bytes(bytes))
+            })
+            .transpose()
+    }
+
+    #[allow(non_snake_case)]
+    fn initialize_blocking(&self, k: usize) -> anyhow::Result<()> {
+        let bound = 1u32
+            .checked_shl(k as u32)
+            .ok_or_else(|| anyhow::anyhow!("table bitsize {} is too large", k))?;
+        let start = match self.initialized_bits()? {
+            Some(bits) if bits >= k => return Ok(()),
+            Some(bits) => 1u32 << bits,
+            None => 0,
+        };
+
+        // The table can always be regenerated, and completeness is only
+        // recorded after a flush, so there's no need for the WAL.
+        let mut write_opts = WriteOptions::default();
+        write_opts.disable_wal(true);
+
+        let B = decaf377::basepoint();
+        let (mut x, mut xB) = (start, decaf377::Fr::from(start) * B);
+        while x < bound {
+            let mut batch = WriteBatch::default();
+            let batch_end = bound.min(x.saturating_add(BATCH_SIZE));
+            while x < batch_end {
+                batch.put(xB.vartime_compress().0, x.to_le_bytes());
+                x += 1;
+                xB += B;
+            }
+            self.db.write_opt(batch, &write_opts)?;
+        }
+        self.db.flush()?;
+
+        self.db
+            .put(INITIALIZED_BITS_KEY, (k as u64).to_le_bytes())?;
+        self.db.flush()?;
+
+        Ok(())
+    }
+}
+
+impl DecryptionTable for RocksDbDecryptionTable {
+    /// Look up an entry, reading it from the blocking thread pool so that a
+    /// slow read doesn't stall the executor.  This must be called from within
+    /// a Tokio runtime.
+    fn lookup(
+        &self,
+        key: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>> {
+        let table = self.clone();
+        async move { tokio::task::spawn_blocking(move || table.lookup_blocking(key)).await? }
+            .boxed()
+    }
+
+    fn store(
+        &self,
+        key: [u8; 32],
+        value: u32,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'static>> {
+        let result = self.db.put(key, value.to_le_bytes()).map_err(Into::into);
+        futures::future::ready(result).boxed()
+    }
+
+    /// Initialize the table, writing entries in large batches.
+    ///
+    /// If the table was already initialized to at least `2^k`, this does
+    /// nothing; if it was initialized to a smaller size, only the missing
+    /// entries are written.  Initialization is CPU-bound, so it runs on
+    /// Tokio's blocking thread pool, and must be called from within a Tokio
+    /// runtime.
+    fn initialize(
+        &self,
+        k: usize,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + '_>> {
+        let table = self.clone();
+        async move { tokio::task::spawn_blocking(move || table.initialize_blocking(k)).await? }
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[allow(non_snake_case)]
+    async fn reopen_initialized_table() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let table = RocksDbDecryptionTable::open(dir.path())?;
+        assert_eq!(table.initialized_bits()?, None);
+        table.initialize(8).await?;
+        drop(table);
+
+        let table = RocksDbDecryptionTable::open(dir.path())?;
+        assert_eq!(table.initialized_bits()?, Some(8));
+
+        // Growing the table only adds the missing entries.
+        table.initialize(10).await?;
+        assert_eq!(table.initialized_bits()?, Some(10));
+        table.initialize(4).await?;
+        assert_eq!(table.initialized_bits()?, Some(10));
+
+        for x in [0u32, 1, 255, 256, 1023] {
+            let xB = decaf377::Fr::from(x) * decaf377::basepoint();
+            assert_eq!(table.lookup(xB.vartime_compress().0).await?, Some(x));
+        }
+        let out_of_range = decaf377::Fr::from(1024u32) * decaf377::basepoint();
+        assert_eq!(table.lookup(out_of_range.vartime_compress().0).await?, None);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,806 @@
-//! Types used to perform distributed key generation (WIP).
+//! Types used to perform distributed key generation.
+//!
+//! This is a Pedersen-style DKG built from `n` parallel instances of Feldman
+//! verifiable secret sharing, one per participant:
+//!
+//! 1. Each participant (acting as a *dealer*) samples a random polynomial of
+//!    degree `threshold - 1`, broadcasts a [`Commitment`] to its coefficients,
+//!    and sends each other participant a private [`Share`] of its secret (the
+//!    evaluation of its polynomial at the recipient's index).  The commitment
+//!    includes a proof of knowledge of the dealer's secret, which prevents a
+//!    dealer from choosing its contribution as a function of the others'.
+//! 2. Each participant checks the shares it received against the dealers'
+//!    commitments, and broadcasts a [`Complaint`] against every dealer whose
+//!    share was missing or invalid.
+//! 3. Each accused dealer broadcasts a [`ComplaintResponse`] revealing the
+//!    disputed share.  Dealers that fail to respond with a valid share are
+//!    disqualified.
+//!
+//! Finally, each participant sums the shares from the qualified dealers to
+//! obtain its [`PrivateKeyShare`], and the commitments from the qualified
+//! dealers determine every participant's [`PublicKeyShare`] as well as the
+//! combined [`EncryptionKey`].
+//!
+//! Shares must be sent over private, authenticated channels; establishing
+//! those channels is out of scope for this module.  All other messages are
+//! assumed to be broadcast, so that every participant sees the same set of
+//! commitments, complaints, and responses.
 
-use crate::PublicKeyShare;
+use std::collections::{BTreeMap, BTreeSet};
 
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
+use ark_std::UniformRand;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{EncryptionKey, PrivateKeyShare, PublicKeyShare, TranscriptProtocol};
+
+/// The public outcome of a DKG: the public key shares of every participant,
+/// and the number of them required to decrypt.
+#[derive(Clone, Debug)]
 pub struct Committee {
     pub shares: Vec<PublicKeyShare>,
     pub threshold: u32,
 }
+
+impl Committee {
+    /// Look up the public key share of the given participant, e.g. to verify
+    /// one of its decryption shares.
+    pub fn public_key_share(&self, participant_index: u32) -> Option<&PublicKeyShare> {
+        self.shares
+            .iter()
+            .find(|share| share.participant_index == participant_index)
+    }
+}
+
+/// The parameters of a DKG, which all participants must agree on in advance.
+///
+/// Participants are numbered `1..=num_participants`.
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The number of key shares needed to decrypt.
+    pub threshold: u32,
+    /// The total number of participants.
+    pub num_participants: u32,
+}
+
+impl Config {
+    fn validate(&self) -> Result<()> {
+        if self.threshold == 0 {
+            return Err(anyhow!("threshold must be nonzero"));
+        }
+        if self.threshold > self.num_participants {
+            return Err(anyhow!(
+                "threshold {} exceeds the number of participants {}",
+                self.threshold,
+                self.num_participants
+            ));
+        }
+        Ok(())
+    }
+
+    fn participant_indices(&self) -> impl Iterator<Item = u32> {
+        1..=self.num_participants
+    }
+
+    fn check_index(&self, participant_index: u32) -> Result<()> {
+        if participant_index == 0 || participant_index > self.num_participants {
+            return Err(anyhow!(
+                "participant index {} is out of range 1..={}",
+                participant_index,
+                self.num_participants
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A dealer's broadcast commitment to the coefficients of its polynomial.
+#[derive(Clone, Debug)]
+pub struct Commitment {
+    pub(crate) dealer_index: u32,
+    /// Commitments `a_k * B` to each coefficient `a_k`, lowest degree first.
+    pub(crate) coefficients: Vec<decaf377::Element>,
+    pub(crate) proof: KnowledgeProof,
+}
+
+/// A Schnorr proof of knowledge of the discrete log of a dealer's commitment
+/// to its constant term.
+#[derive(Clone, Debug)]
+pub(crate) struct KnowledgeProof {
+    /// The challenge scalar
+    c: decaf377::Fr,
+    /// The response to the challenge
+    r: decaf377::Fr,
+}
+
+/// A dealer's share of its secret for a single recipient, which must be sent
+/// privately.
+#[derive(Clone, Debug)]
+pub struct Share {
+    pub(crate) dealer_index: u32,
+    pub(crate) recipient_index: u32,
+    pub(crate) share: decaf377::Fr,
+}
+
+/// A broadcast accusation that a dealer sent an invalid share, or no share at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub complainer_index: u32,
+    pub dealer_index: u32,
+}
+
+/// A dealer's broadcast response to a [`Complaint`], revealing the disputed share.
+#[derive(Clone, Debug)]
+pub struct ComplaintResponse {
+    pub(crate) complainer_index: u32,
+    pub(crate) dealer_index: u32,
+    pub(crate) share: decaf377::Fr,
+}
+
+/// The result of a successful DKG for a single participant.
+pub struct Output {
+    pub private_key_share: PrivateKeyShare,
+    pub committee: Committee,
+    pub encryption_key: EncryptionKey,
+}
+
+/// A single participant's state in a DKG.
+pub struct Participant {
+    config: Config,
+    index: u32,
+    /// Our polynomial's coefficients, lowest degree first.
+    coefficients: Vec<decaf377::Fr>,
+    commitments: BTreeMap<u32, Commitment>,
+    shares: BTreeMap<u32, decaf377::Fr>,
+    disqualified: BTreeSet<u32>,
+}
+
+impl Participant {
+    /// Begin a DKG as the participant with the given (1-based) index.
+    pub fn new<R: RngCore + CryptoRng>(
+        config: Config,
+        participant_index: u32,
+        mut rng: R,
+    ) -> Result<Self> {
+        config.validate()?;
+        config.check_index(participant_index)?;
+
+        let coefficients = (0..config.threshold)
+            .map(|_| decaf377::Fr::rand(&mut rng))
+            .collect();
+
+        Ok(Participant {
+            config,
+            index: participant_index,
+            coefficients,
+            commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            disqualified: BTreeSet::new(),
+        })
+    }
+
+    /// The index of this participant.
+    pub fn participant_index(&self) -> u32 {
+        self.index
+    }
+
+    /// Round 1: produce the commitment to broadcast to all participants.
+    #[allow(non_snake_case)]
+    pub fn commitment<R: RngCore + CryptoRng>(&self, mut rng: R) -> Commitment {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|a| a * decaf377::basepoint())
+            .collect::<Vec<_>>();
+
+        let mut transcript = commitment_transcript(self.index, &coefficients);
+        let k = decaf377::Fr::rand(&mut rng);
+        let kB = k * decaf377::basepoint();
+        transcript.append_blinding_commitment(b"kB", &kB);
+        let c = transcript.challenge_scalar(b"c");
+        let r = k - self.coefficients[0] * c;
+
+        Commitment {
+            dealer_index: self.index,
+            coefficients,
+            proof: KnowledgeProof { c, r },
+        }
+    }
+
+    /// Round 1: produce the shares to send privately to each participant
+    /// (including ourselves).
+    pub fn shares(&self) -> Vec<Share> {
+        self.config
+            .participant_indices()
+            .map(|recipient_index| Share {
+                dealer_index: self.index,
+                recipient_index,
+                share: evaluate(&self.coefficients, recipient_index),
+            })
+            .collect()
+    }
+
+    /// Round 2: record a dealer's broadcast commitment.
+    ///
+    /// A dealer whose commitment is malformed is disqualified, and an error is
+    /// returned describing its misbehavior.
+    pub fn receive_commitment(&mut self, commitment: Commitment) -> Result<()> {
+        let dealer_index = commitment.dealer_index;
+        self.config.check_index(dealer_index)?;
+        if self.commitments.contains_key(&dealer_index) {
+            self.disqualified.insert(dealer_index);
+            return Err(anyhow!(
+                "dealer {} broadcast more than one commitment",
+                dealer_index
+            ));
+        }
+        if let Err(e) = commitment.verify(&self.config) {
+            self.disqualified.insert(dealer_index);
+            return Err(e);
+        }
+
+        self.commitments.insert(dealer_index, commitment);
+        Ok(())
+    }
+
+    /// Round 2: record a share sent to us by a dealer.
+    ///
+    /// Shares that don't match the dealer's commitment are discarded, and
+    /// result in a [`Complaint`] against that dealer, so commitments must be
+    /// received before shares.
+    pub fn receive_share(&mut self, share: Share) -> Result<()> {
+        if share.recipient_index != self.index {
+            return Err(anyhow!(
+                "share from dealer {} is addressed to participant {}, not {}",
+                share.dealer_index,
+                share.recipient_index,
+                self.index
+            ));
+        }
+        let commitment = self
+            .commitments
+            .get(&share.dealer_index)
+            .ok_or_else(|| anyhow!("no commitment from dealer {}", share.dealer_index))?;
+        commitment.verify_share(self.index, &share.share)?;
+
+        self.shares.insert(share.dealer_index, share.share);
+        Ok(())
+    }
+
+    /// Round 2: produce complaints against every dealer that committed but did
+    /// not send us a valid share, to broadcast to all participants.
+    pub fn complaints(&self) -> Vec<Complaint> {
+        self.commitments
+            .keys()
+            .filter(|dealer_index| !self.disqualified.contains(dealer_index))
+            .filter(|dealer_index| !self.shares.contains_key(dealer_index))
+            .map(|&dealer_index| Complaint {
+                complainer_index: self.index,
+                dealer_index,
+            })
+            .collect()
+    }
+
+    /// Round 3: respond to the complaints against us, by revealing the
+    /// disputed shares.
+    ///
+    /// Malformed complaints are ignored, since answering a complaint from an
+    /// out-of-range index such as 0 would reveal our secret.
+    pub fn respond_to_complaints(&self, complaints: &[Complaint]) -> Vec<ComplaintResponse> {
+        complaints
+            .iter()
+            .filter(|complaint| complaint.dealer_index == self.index)
+            .filter(|complaint| complaint.check(&self.config).is_ok())
+            .map(|complaint| ComplaintResponse {
+                complainer_index: complaint.complainer_index,
+                dealer_index: self.index,
+                share: evaluate(&self.coefficients, complaint.complainer_index),
+            })
+            .collect()
+    }
+
+    /// Round 3: resolve all broadcast complaints using the broadcast responses.
+    ///
+    /// Every dealer that didn't commit, or that didn't answer a complaint
+    /// against it with a share matching its commitment, is disqualified.
+    /// Valid revealed shares addressed to us replace the ones we complained
+    /// about. Malformed complaints are ignored, as honest dealers don't
+    /// answer them.
+    pub fn resolve_complaints(
+        &mut self,
+        complaints: &[Complaint],
+        responses: &[ComplaintResponse],
+    ) {
+        for dealer_index in self.config.participant_indices() {
+            if !self.commitments.contains_key(&dealer_index) {
+                self.disqualified.insert(dealer_index);
+            }
+        }
+
+        for complaint in complaints {
+            if complaint.check(&self.config).is_err()
+                || self.disqualified.contains(&complaint.dealer_index)
+            {
+                continue;
+            }
+            let commitment = match self.commitments.get(&complaint.dealer_index) {
+                Some(commitment) => commitment,
+                None => continue,
+            };
+            let revealed = responses
+                .iter()
+                .filter(|response| {
+                    response.dealer_index == complaint.dealer_index
+                        && response.complainer_index == complaint.complainer_index
+                })
+                .find(|response| {
+                    commitment
+                        .verify_share(complaint.complainer_index, &response.share)
+                        .is_ok()
+                });
+
+            match revealed {
+                Some(response) if complaint.complainer_index == self.index => {
+                    self.shares.insert(complaint.dealer_index, response.share);
+                }
+                Some(_) => {}
+                None => {
+                    self.disqualified.insert(complaint.dealer_index);
+                }
+            }
+        }
+    }
+
+    /// The indices of the dealers that have not been disqualified.
+    pub fn qualified_dealers(&self) -> Vec<u32> {
+        self.commitments
+            .keys()
+            .filter(|dealer_index| !self.disqualified.contains(dealer_index))
+            .copied()
+            .collect()
+    }
+
+    /// Complete the DKG, computing our key share, the public key shares of
+    /// all participants, and the encryption key.
+    ///
+    /// This must only be called after complaints have been resolved, so that
+    /// all honest participants agree on the set of qualified dealers.
+    pub fn finalize(self) -> Result<Output> {
+        let qualified = self.qualified_dealers();
+        // With fewer than `threshold` qualified dealers, a coalition of
+        // `threshold` participants could include every contributor, and no
+        // honest participant's randomness is guaranteed to be included.
+        if qualified.len() < self.config.threshold as usize {
+            return Err(anyhow!(
+                "only {} qualified dealers, but the threshold is {}",
+                qualified.len(),
+                self.config.threshold
+            ));
+        }
+
+        let mut key_share = decaf377::Fr::zero();
+        for dealer_index in &qualified {
+            key_share += self.shares.get(dealer_index).ok_or_else(|| {
+                anyhow!(
+                    "missing share from qualified dealer {}; were complaints resolved?",
+                    dealer_index
+                )
+            })?;
+        }
+
+        let shares = self
+            .config
+            .participant_indices()
+            .map(|participant_index| PublicKeyShare {
+                participant_index,
+                pub_key_share: qualified
+                    .iter()
+                    .map(|dealer_index| self.commitments[dealer_index].evaluate(participant_index))
+                    .fold(decaf377::Element::default(), |acc, x| acc + x),
+            })
+            .collect::<Vec<_>>();
+
+        let cached_pub = shares[(self.index - 1) as usize];
+        if cached_pub.pub_key_share != key_share * decaf377::basepoint() {
+            return Err(anyhow!(
+                "private key share does not match the committed public key share"
+            ));
+        }
+
+        let encryption_key = EncryptionKey(
+            qualified
+                .iter()
+                .map(|dealer_index| self.commitments[dealer_index].coefficients[0])
+                .fold(decaf377::Element::default(), |acc, x| acc + x),
+        );
+
+        Ok(Output {
+            private_key_share: PrivateKeyShare {
+                participant_index: self.index,
+                key_share,
+                cached_pub,
+            },
+            committee: Committee {
+                shares,
+                threshold: self.config.threshold,
+            },
+            encryption_key,
+        })
+    }
+}
+
+impl Commitment {
+    /// The index of the dealer that produced this commitment.
+    pub fn dealer_index(&self) -> u32 {
+        self.dealer_index
+    }
+
+    #[allow(non_snake_case)]
+    fn verify(&self, config: &Config) -> Result<()> {
+        if self.coefficients.len() != config.threshold as usize {
+            return Err(anyhow!(
+                "dealer {} committed to {} coefficients, expected {}",
+                self.dealer_index,
+                self.coefficients.len(),
+                config.threshold
+            ));
+        }
+
+        let mut transcript = commitment_transcript(self.dealer_index, &self.coefficients);
+        let kB = self.proof.r * decaf377::basepoint() + self.proof.c * self.coefficients[0];
+        transcript.append_blinding_commitment(b"kB", &kB);
+        if transcript.challenge_scalar(b"c") != self.proof.c {
+            return Err(anyhow!(
+                "dealer {} commitment has an invalid proof of knowledge",
+                self.dealer_index
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Compute the commitment to the dealer's share for the given participant.
+    fn evaluate(&self, participant_index: u32) -> decaf377::Element {
+        let x = decaf377::Fr::from(participant_index);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(decaf377::Element::default(), |acc, a| acc * x + *a)
+    }
+
+    fn verify_share(&self, participant_index: u32, share: &decaf377::Fr) -> Result<()> {
+        if share * decaf377::basepoint() != self.evaluate(participant_index) {
+            return Err(anyhow!(
+                "share from dealer {} for participant {} does not match its commitment",
+                self.dealer_index,
+                participant_index
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Complaint {
+    /// Check that the complaint is between two distinct participants.
+    fn check(&self, config: &Config) -> Result<()> {
+        config.check_index(self.complainer_index)?;
+        config.check_index(self.dealer_index)?;
+        if self.complainer_index == self.dealer_index {
+            return Err(anyhow!(
+                "participant {} complained about itself",
+                self.dealer_index
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Share {
+    /// The index of the participant this share must be sent to.
+    pub fn recipient_index(&self) -> u32 {
+        self.recipient_index
+    }
+}
+
+fn commitment_transcript(
+    dealer_index: u32,
+    coefficients: &[decaf377::Element],
+) -> merlin::Transcript {
+    let mut transcript = merlin::Transcript::new(b"eddy-dkg");
+    transcript.begin_dkg_commitment(dealer_index);
+    for coefficient in coefficients {
+        transcript.append_coefficient_commitment(coefficient);
+    }
+    transcript
+}
+
+/// Evaluate the polynomial with the given coefficients at a participant's index.
+fn evaluate(coefficients: &[decaf377::Fr], participant_index: u32) -> decaf377::Fr {
+    let x = decaf377::Fr::from(participant_index);
+    coefficients
+        .iter()
+        .rev()
+        .fold(decaf377::Fr::zero(), |acc, a| acc * x + a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DecryptionTable, MockDecryptionTable, Value};
+
+    /// Misbehavior for a simulated dealer.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Dealer {
+        Honest,
+        /// Sends a corrupted share to the given participant, then reveals the
+        /// correct share when accused.
+        CorruptShare(u32),
+        /// Sends a corrupted share to the given participant, and ignores the
+        /// resulting complaint.
+        CorruptShareUnresponsive(u32),
+    }
+
+    /// Run a DKG among simulated in-process participants, returning each
+    /// participant's output.
+    fn simulate(config: Config, dealers: &[Dealer]) -> Vec<Output> {
+        let mut rng = rand::thread_rng();
+        let mut participants = (1..=config.num_participants)
+            .map(|i| Participant::new(config, i, &mut rng).unwrap())
+            .collect::<Vec<_>>();
+
+        // Round 1
+        let commitments = participants
+            .iter()
+            .map(|p| p.commitment(&mut rng))
+            .collect::<Vec<_>>();
+        let mut shares = Vec::new();
+        for (p, behavior) in participants.iter().zip(dealers) {
+            for mut share in p.shares() {
+                match behavior {
+                    Dealer::CorruptShare(victim) | Dealer::CorruptShareUnresponsive(victim)
+                        if share.recipient_index == *victim =>
+                    {
+                        share.share += decaf377::Fr::from(1u32);
+                    }
+                    _ => {}
+                }
+                shares.push(share);
+            }
+        }
+
+        // Round 2
+        for p in participants.iter_mut() {
+            for commitment in &commitments {
+                p.receive_commitment(commitment.clone()).unwrap();
+            }
+            for share in shares.iter().filter(|s| s.recipient_index == p.index) {
+                // Invalid shares are rejected, and complained about below.
+                let _ = p.receive_share(share.clone());
+            }
+        }
+        let complaints = participants
+            .iter()
+            .flat_map(|p| p.complaints())
+            .collect::<Vec<_>>();
+
+        // Round 3
+        let responses = participants
+            .iter()
+            .zip(dealers)
+            .filter(|(_, behavior)| !matches!(behavior, Dealer::CorruptShareUnresponsive(_)))
+            .flat_map(|(p, _)| p.respond_to_complaints(&complaints))
+            .collect::<Vec<_>>();
+        for p in participants.iter_mut() {
+            p.resolve_complaints(&complaints, &responses);
+        }
+
+        participants
+            .into_iter()
+            .map(|p| p.finalize().unwrap())
+            .collect()
+    }
+
+    async fn decrypt_with(outputs: &[Output], decryptors: &[usize]) -> Value {
+        let mut rng = rand::thread_rng();
+        let encryption_key = outputs[0].encryption_key;
+        let committee = &outputs[0].committee;
+
+        let value = Value::from(0x1234_5678u64);
+        let (ciphertext, _) = value
+            .transparent_encrypt(&encryption_key, &mut rng)
+            .unwrap();
+
+        let mut shares = Vec::new();
+        for &i in decryptors {
+            let key_share = &outputs[i].private_key_share;
+            let share = key_share.decryption_share(
+                &ciphertext,
+                &mut merlin::Transcript::new(b"test"),
+                &mut rng,
+            );
+            let pub_key_share = committee
+                .public_key_share(key_share.participant_index())
+                .unwrap();
+            shares.push(
+                share
+                    .verify(
+                        &ciphertext,
+                        pub_key_share,
+                        &mut merlin::Transcript::new(b"test"),
+                    )
+                    .unwrap(),
+            );
+        }
+
+        let table = MockDecryptionTable::default();
+        table.initialize(16).await.unwrap();
+        ciphertext.decrypt(shares, &table).await.unwrap()
+    }
+
+    fn assert_consistent(outputs: &[Output]) {
+        for output in outputs {
+            assert_eq!(output.encryption_key, outputs[0].encryption_key);
+            assert_eq!(output.committee.shares, outputs[0].committee.shares);
+            assert_eq!(
+                output.private_key_share.public_key_share(),
+                *outputs[0]
+                    .committee
+                    .public_key_share(output.private_key_share.participant_index())
+                    .unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn honest_committee() {
+        let config = Config {
+            threshold: 3,
+            num_participants: 5,
+        };
+        let outputs = simulate(config, &[Dealer::Honest; 5]);
+        assert_consistent(&outputs);
+
+        assert_eq!(
+            decrypt_with(&outputs, &[0, 2, 4]).await,
+            Value::from(0x1234_5678u64)
+        );
+        assert_eq!(
+            decrypt_with(&outputs, &[1, 2, 3, 4]).await,
+            Value::from(0x1234_5678u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn resolved_complaint() {
+        let config = Config {
+            threshold: 2,
+            num_participants: 4,
+        };
+        let dealers = [
+            Dealer::Honest,
+            Dealer::CorruptShare(3),
+            Dealer::Honest,
+            Dealer::Honest,
+        ];
+        let outputs = simulate(config, &dealers);
+        assert_consistent(&outputs);
+
+        // The accused dealer answered the complaint, so it remains qualified,
+        // and the complainer can still decrypt.
+        assert_eq!(
+            decrypt_with(&outputs, &[1, 2]).await,
+            Value::from(0x1234_5678u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn disqualified_dealer() {
+        let config = Config {
+            threshold: 2,
+            num_participants: 4,
+        };
+        let dealers = [
+            Dealer::Honest,
+            Dealer::Honest,
+            Dealer::Honest,
+            Dealer::CorruptShareUnresponsive(1),
+        ];
+        let outputs = simulate(config, &dealers);
+        assert_consistent(&outputs);
+
+        // The disqualified dealer's contribution is excluded from the key, but
+        // it still holds a usable key share.
+        assert_eq!(
+            decrypt_with(&outputs, &[0, 3]).await,
+            Value::from(0x1234_5678u64)
+        );
+    }
+
+    #[test]
+    fn malformed_complaints_are_ignored() {
+        let mut rng = rand::thread_rng();
+        let config = Config {
+            threshold: 2,
+            num_participants: 3,
+        };
+        let mut participants = (1..=3)
+            .map(|i| Participant::new(config, i, &mut rng).unwrap())
+            .collect::<Vec<_>>();
+        let commitments = participants
+            .iter()
+            .map(|p| p.commitment(&mut rng))
+            .collect::<Vec<_>>();
+        let shares = participants
+            .iter()
+            .flat_map(|p| p.shares())
+            .collect::<Vec<_>>();
+        for p in participants.iter_mut() {
+            for commitment in &commitments {
+                p.receive_commitment(commitment.clone()).unwrap();
+            }
+            for share in shares.iter().filter(|s| s.recipient_index == p.index) {
+                p.receive_share(share.clone()).unwrap();
+            }
+        }
+
+        let complaints = [
+            // Answering this would reveal the dealer's secret.
+            Complaint {
+                complainer_index: 0,
+                dealer_index: 1,
+            },
+            Complaint {
+                complainer_index: 2,
+                dealer_index: 2,
+            },
+            Complaint {
+                complainer_index: 1,
+                dealer_index: 7,
+            },
+        ];
+        for p in &participants {
+            assert!(p.respond_to_complaints(&complaints).is_empty());
+        }
+
+        // The unanswered complaints don't disqualify anyone.
+        for p in participants.iter_mut() {
+            p.resolve_complaints(&complaints, &[]);
+            assert_eq!(p.qualified_dealers(), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn forged_commitment_is_rejected() {
+        let mut rng = rand::thread_rng();
+        let config = Config {
+            threshold: 2,
+            num_participants: 3,
+        };
+        let dealer = Participant::new(config, 1, &mut rng).unwrap();
+        let mut receiver = Participant::new(config, 2, &mut rng).unwrap();
+
+        // Replacing the constant term invalidates the proof of knowledge.
+        let mut commitment = dealer.commitment(&mut rng);
+        commitment.coefficients[0] = decaf377::basepoint();
+        assert!(receiver.receive_commitment(commitment).is_err());
+        assert_eq!(receiver.qualified_dealers(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn invalid_configs_are_rejected() {
+        let rng = rand::thread_rng();
+        let config = Config {
+            threshold: 4,
+            num_participants: 3,
+        };
+        assert!(Participant::new(config, 1, rng).is_err());
+
+        let config = Config {
+            threshold: 2,
+            num_participants: 3,
+        };
+        assert!(Participant::new(config, 0, rand::thread_rng()).is_err());
+        assert!(Participant::new(config, 4, rand::thread_rng()).is_err());
+    }
+}
@@ -1,3 +1,4 @@
 /// The key used to encrypt ciphertexts (the public key of the encryption
 /// scheme).
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncryptionKey(pub(crate) decaf377::Element);
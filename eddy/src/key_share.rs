@@ -1,4 +1,5 @@
 /// A decryptor's private key share.
+#[derive(Clone)]
 pub struct PrivateKeyShare {
     pub(crate) participant_index: u32,
     pub(crate) key_share: decaf377::Fr,
@@ -6,7 +7,27 @@ pub struct PrivateKeyShare {
 }
 
 /// A decryptor's public key share.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PublicKeyShare {
     pub(crate) participant_index: u32,
     pub(crate) pub_key_share: decaf377::Element,
 }
+
+impl PrivateKeyShare {
+    /// The index of the participant holding this key share.
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+
+    /// The public key share corresponding to this private key share.
+    pub fn public_key_share(&self) -> PublicKeyShare {
+        self.cached_pub
+    }
+}
+
+impl PublicKeyShare {
+    /// The index of the participant holding this key share.
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+}
@@ -6,7 +6,7 @@
 //! - [x] Decryption Proofs
 //! - [x] Lookup table interface
 //! - [ ] Error on insufficient shares
-//! - [ ] Distributed key generation
+//! - [x] Distributed key generation
 //! - [ ] Serialization
 //! - [ ] Encryption Proofs
 //!
@@ -28,6 +28,8 @@ pub mod proofs;
 
 pub use ciphertext::{Ciphertext, InsufficientSharesError};
 pub use decryption_share::{DecryptionShare, Unverified, VerificationStatus, Verified};
+#[cfg(feature = "rocksdb")]
+pub use decryption_table::RocksDbDecryptionTable;
 pub use decryption_table::{DecryptionTable, MockDecryptionTable, TableLookupError};
 pub use encryption_key::EncryptionKey;
 pub use key_share::{PrivateKeyShare, PublicKeyShare};
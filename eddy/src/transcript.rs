@@ -3,6 +3,8 @@ use crate::{limb, PublicKeyShare};
 pub trait TranscriptProtocol {
     fn begin_decryption(&mut self);
     fn begin_limb_decryption(&mut self);
+    fn begin_dkg_commitment(&mut self, dealer_index: u32);
+    fn append_coefficient_commitment(&mut self, point: &decaf377::Element);
     fn append_public_key_share(&mut self, share: &PublicKeyShare);
     fn append_limb_ciphertext(&mut self, ciphertext: &limb::Ciphertext);
     fn append_decryption_share_point(&mut self, point: &decaf377::Element);
@@ -18,6 +20,13 @@ impl TranscriptProtocol for merlin::Transcript {
     fn begin_limb_decryption(&mut self) {
         self.append_message(b"dom-sep", b"begin-limb");
     }
+    fn begin_dkg_commitment(&mut self, dealer_index: u32) {
+        self.append_message(b"dom-sep", b"eddy-decaf377-dkg-commitment");
+        self.append_message(b"dealer", &dealer_index.to_le_bytes());
+    }
+    fn append_coefficient_commitment(&mut self, point: &decaf377::Element) {
+        self.append_message(b"coefficient-commitment", &point.vartime_compress().0);
+    }
     fn append_public_key_share(&mut self, share: &PublicKeyShare) {
         self.append_message(b"dom-sep", b"public-key-share");
         self.append_message(b"index", &share.participant_index.to_le_bytes());
@@ -1,6 +1,7 @@
 mod debug;
 mod keys;
 mod query;
+mod sign;
 mod tx;
 mod validator;
 mod view;
@@ -8,7 +9,8 @@ mod view;
 pub use debug::DebugCmd;
 pub use keys::KeysCmd;
 pub use query::QueryCmd;
-pub use tx::TxCmd;
+pub use sign::SignCmd;
+pub use tx::{TxArgs, TxCmd};
 pub use validator::ValidatorCmd;
 pub use view::transaction_hashes::TransactionHashesCmd;
 pub use view::ViewCmd;
@@ -39,8 +41,11 @@ pub enum Command {
     #[clap(subcommand, display_order = 300, visible_alias = "v")]
     View(ViewCmd),
     /// Create and broadcast a transaction.
-    #[clap(subcommand, display_order = 400, visible_alias = "tx")]
-    Transaction(TxCmd),
+    #[clap(display_order = 400, visible_alias = "tx")]
+    Transaction(TxArgs),
+    /// Authorize a transaction plan, for signing on an offline machine.
+    #[clap(display_order = 450)]
+    Sign(SignCmd),
     /// Manage your wallet's keys.
     #[clap(subcommand, display_order = 500)]
     Keys(KeysCmd),
@@ -56,7 +61,8 @@ impl Command {
     /// Determine if this command requires a network sync before it executes.
     pub fn offline(&self) -> bool {
         match self {
-            Command::Transaction(cmd) => cmd.offline(),
+            Command::Transaction(args) => args.cmd.offline(),
+            Command::Sign(cmd) => cmd.offline(),
             Command::View(cmd) => cmd.offline(),
             Command::Keys(cmd) => cmd.offline(),
             Command::Validator(cmd) => cmd.offline(),
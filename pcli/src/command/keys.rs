@@ -6,7 +6,11 @@ use penumbra_crypto::keys::SeedPhrase;
 use rand_core::OsRng;
 use sha2::{Digest, Sha256};
 
-use crate::KeyStore;
+use crate::{
+    passphrase::{prompt_new_passphrase, read_new_passphrase, WalletFile},
+    watch_only::{WatchOnlyWallet, WATCH_ONLY_FILE_NAME},
+    KeyStore,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum KeysCmd {
@@ -17,9 +21,35 @@ pub enum KeysCmd {
     #[clap(subcommand)]
     Export(ExportCmd),
     /// Generate a new seed phrase and import its corresponding key.
-    Generate,
+    Generate {
+        /// Store the wallet unencrypted, instead of encrypting it under a passphrase.
+        #[clap(long)]
+        unencrypted: bool,
+    },
+    /// Change the passphrase the wallet is encrypted under, or encrypt an
+    /// unencrypted wallet.
+    ChangePassphrase,
     /// Delete the entire wallet permanently.
     Delete,
+    /// Manage the accounts in the wallet.
+    #[clap(subcommand)]
+    Account(AccountCmd),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum AccountCmd {
+    /// Derive a new account from the wallet's seed phrase.
+    Add {
+        /// The name of the new account.
+        name: String,
+    },
+    /// List the accounts in the wallet.
+    List,
+    /// Select the account used by other commands.
+    Select {
+        /// The name of the account to select.
+        name: String,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -28,12 +58,23 @@ pub enum ImportCmd {
     Phrase {
         /// A 24 word phrase in quotes.
         seed_phrase: String,
+        /// Store the wallet unencrypted, instead of encrypting it under a passphrase.
+        #[clap(long)]
+        unencrypted: bool,
+    },
+    /// Import a full viewing key, creating a watch-only wallet.
+    ///
+    /// A watch-only wallet can view its balances and plan transactions, but
+    /// needs a remote custody service (see --custody-address) to authorize them.
+    FullViewingKey {
+        /// The full viewing key, as exported by `pcli keys export full-viewing-key`.
+        full_viewing_key: String,
     },
 }
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ExportCmd {
-    /// Export the full viewing key for the wallet.
+    /// Export the full viewing key for the active account.
     FullViewingKey,
 }
 
@@ -43,30 +84,86 @@ impl KeysCmd {
         true
     }
 
-    fn archive_wallet(&self, wallet: &KeyStore) -> Result<()> {
+    /// Save a newly created wallet, encrypting it unless `unencrypted` is set,
+    /// and archive a backup copy of it.
+    fn save_new_wallet(
+        &self,
+        wallet: &KeyStore,
+        data_dir: &camino::Utf8Path,
+        unencrypted: bool,
+    ) -> Result<()> {
+        let passphrase = if unencrypted {
+            None
+        } else {
+            Some(read_new_passphrase()?)
+        };
+        if data_dir.join(WATCH_ONLY_FILE_NAME).exists() {
+            return Err(anyhow!(
+                "A watch-only wallet already exists in {}, refusing to create another wallet",
+                data_dir
+            ));
+        }
+        let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+        match &passphrase {
+            Some(passphrase) => wallet.save_encrypted(wallet_path, passphrase)?,
+            None => {
+                eprintln!("Warning: the wallet is being stored unencrypted.");
+                wallet.save(wallet_path)?
+            }
+        }
+        self.archive_wallet(wallet, passphrase.as_deref())
+    }
+
+    fn archive_wallet(&self, wallet: &KeyStore, passphrase: Option<&str>) -> Result<()> {
         // Archive the newly generated state
+        let archive_path = self.archive_path(wallet);
+        println!("Saving backup wallet to {}", archive_path.display());
+        match passphrase {
+            Some(passphrase) => wallet.save_encrypted(archive_path, passphrase)?,
+            None => wallet.save(archive_path)?,
+        }
+        Ok(())
+    }
+
+    /// Rewrite the archived backup of a wallet after it changed, so that the
+    /// backup has the same accounts and passphrase as the wallet itself.
+    fn update_archived_wallet(&self, wallet_file: &WalletFile) -> Result<()> {
+        let archive_path = self.archive_path(&wallet_file.wallet);
+        if !archive_path.exists() {
+            return self.archive_wallet(&wallet_file.wallet, wallet_file.passphrase());
+        }
+        println!("Updating backup wallet at {}", archive_path.display());
+        match wallet_file.passphrase() {
+            Some(passphrase) => wallet_file
+                .wallet
+                .update_encrypted(archive_path, passphrase)?,
+            None => wallet_file.wallet.update(archive_path)?,
+        }
+        Ok(())
+    }
+
+    fn archive_path(&self, wallet: &KeyStore) -> std::path::PathBuf {
         let archive_dir = ProjectDirs::from("zone", "penumbra", "penumbra-testnet-archive")
             .expect("can access penumbra-testnet-archive dir");
 
-        // Create the directory <data dir>/penumbra-testnet-archive/<chain id>/<spend key hash prefix>/
-        let spend_key_hash = Sha256::digest(&wallet.spend_key.to_bytes().0);
+        // Create the directory <data dir>/penumbra-testnet-archive/<spend key hash prefix>/,
+        // using the first account's spend key, so that the directory doesn't
+        // change when another account is added or selected.
+        let spend_key = &wallet.accounts()[0].spend_key;
+        let spend_key_hash = Sha256::digest(&spend_key.to_bytes().0);
         let wallet_archive_dir = archive_dir
             .data_dir()
             .join(hex::encode(&spend_key_hash[0..8]));
         std::fs::create_dir_all(&wallet_archive_dir)
             .expect("can create penumbra wallet archive directory");
 
-        // Save the wallet file in the archive directory
-        let archive_path = wallet_archive_dir.join(crate::CUSTODY_FILE_NAME);
-        println!("Saving backup wallet to {}", archive_path.display());
-        wallet.save(archive_path)?;
-        Ok(())
+        wallet_archive_dir.join(crate::CUSTODY_FILE_NAME)
     }
 
     pub fn exec(&self, data_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
         let data_dir = data_dir.as_ref();
         match self {
-            KeysCmd::Generate => {
+            KeysCmd::Generate { unencrypted } => {
                 let seed_phrase = SeedPhrase::generate(OsRng);
 
                 // xxx: Something better should be done here, this is in danger of being
@@ -77,20 +174,90 @@ impl KeysCmd {
                 );
 
                 let wallet = KeyStore::from_seed_phrase(seed_phrase);
-                wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                self.archive_wallet(&wallet)?;
+                self.save_new_wallet(&wallet, data_dir, *unencrypted)?;
             }
-            KeysCmd::Import(ImportCmd::Phrase { seed_phrase }) => {
+            KeysCmd::Import(ImportCmd::Phrase {
+                seed_phrase,
+                unencrypted,
+            }) => {
                 let wallet = KeyStore::from_seed_phrase(SeedPhrase::from_str(seed_phrase)?);
-                wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                self.archive_wallet(&wallet)?;
+                self.save_new_wallet(&wallet, data_dir, *unencrypted)?;
+            }
+            KeysCmd::Import(ImportCmd::FullViewingKey { full_viewing_key }) => {
+                if data_dir.join(crate::CUSTODY_FILE_NAME).exists() {
+                    return Err(anyhow!(
+                        "A wallet already exists in {}, refusing to create a watch-only wallet",
+                        data_dir
+                    ));
+                }
+                let watch_only = WatchOnlyWallet::from_fvk_str(full_viewing_key)?;
+                watch_only.save(data_dir.join(WATCH_ONLY_FILE_NAME))?;
+                println!(
+                    "Created watch-only wallet for account {}",
+                    watch_only.full_viewing_key.hash()
+                );
+            }
+            KeysCmd::ChangePassphrase => {
+                let mut wallet_file = WalletFile::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
+                // Always prompt for the new passphrase, since the environment
+                // variable (if any) holds the current one.
+                wallet_file.set_passphrase(prompt_new_passphrase()?);
+                wallet_file.save()?;
+                self.update_archived_wallet(&wallet_file)?;
+                println!("Wallet passphrase changed");
             }
             KeysCmd::Export(ExportCmd::FullViewingKey) => {
-                let wallet = KeyStore::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
-                println!("{}", wallet.spend_key.full_viewing_key());
+                let watch_only_path = data_dir.join(WATCH_ONLY_FILE_NAME);
+                if watch_only_path.exists() {
+                    let watch_only = WatchOnlyWallet::load(watch_only_path)?;
+                    println!("{}", watch_only.full_viewing_key);
+                } else {
+                    let wallet = WalletFile::load(data_dir.join(crate::CUSTODY_FILE_NAME))?.wallet;
+                    println!("{}", wallet.spend_key().full_viewing_key());
+                }
+            }
+            KeysCmd::Account(AccountCmd::Add { name }) => {
+                let mut wallet_file = WalletFile::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
+                let fvk = wallet_file
+                    .wallet
+                    .add_account(name.as_str())?
+                    .spend_key
+                    .full_viewing_key()
+                    .clone();
+                wallet_file.save()?;
+                self.update_archived_wallet(&wallet_file)?;
+                println!("Added account {} with full viewing key:\n{}", name, fvk);
+                println!("The view service will rescan the chain to find its notes.");
+            }
+            KeysCmd::Account(AccountCmd::List) => {
+                let wallet = WalletFile::load(data_dir.join(crate::CUSTODY_FILE_NAME))?.wallet;
+                let active = wallet.active_account().name.clone();
+                for account in wallet.accounts() {
+                    let marker = if account.name == active { "*" } else { " " };
+                    let index = account
+                        .index
+                        .map_or_else(|| "-".to_string(), |index| index.to_string());
+                    println!(
+                        "{} {} (index {}): {}",
+                        marker,
+                        account.name,
+                        index,
+                        account.spend_key.full_viewing_key().hash()
+                    );
+                }
+            }
+            KeysCmd::Account(AccountCmd::Select { name }) => {
+                let mut wallet_file = WalletFile::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
+                wallet_file.wallet.select_account(name)?;
+                wallet_file.save()?;
+                self.update_archived_wallet(&wallet_file)?;
+                println!("Selected account {}", name);
             }
             KeysCmd::Delete => {
-                let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+                let mut wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
+                if !wallet_path.exists() {
+                    wallet_path = data_dir.join(WATCH_ONLY_FILE_NAME);
+                }
                 if wallet_path.is_file() {
                     std::fs::remove_file(&wallet_path)?;
                     println!("Deleted wallet file at {}", wallet_path);
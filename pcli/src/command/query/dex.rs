@@ -1,18 +1,16 @@
 use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
-use penumbra_crypto::dex::{lp::Reserves, BatchSwapOutputData, TradingPair};
-use penumbra_proto::client::v1alpha1::{BatchSwapOutputDataRequest, StubCpmmReservesRequest};
+use penumbra_crypto::{
+    dex::{execution::SwapExecution, BatchSwapOutputData, DirectedTradingPair, TradingPair},
+    Value,
+};
+use penumbra_proto::client::v1alpha1::{BatchSwapOutputDataRequest, SwapExecutionRequest};
 use penumbra_view::ViewClient;
 
 use crate::App;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum DexCmd {
-    /// Display information about constant-pair market maker reserves.
-    CPMMReserves {
-        /// The trading pair to query for CPMM Reserves.
-        trading_pair: TradingPair,
-    },
     /// Display information about a specific trading pair & height's batch swap.
     BatchOutputs {
         /// The height to query for batch outputs.
@@ -21,91 +19,55 @@ pub enum DexCmd {
         /// The trading pair to query for batch outputs.
         trading_pair: TradingPair,
     },
+    /// Display the route taken by one direction of a specific height's batch swap, hop by hop.
+    SwapExecution {
+        /// The height to query for the swap execution.
+        #[clap(long)]
+        height: u64,
+        /// The direction of the batch swap to query, as START:END.
+        trading_pair: DirectedTradingPair,
+    },
 }
 
 impl DexCmd {
-    pub async fn print_cpmm_reserves(
+    pub async fn get_batch_outputs(
         &self,
         app: &mut App,
+        height: &u64,
         trading_pair: &TradingPair,
-    ) -> Result<()> {
+    ) -> Result<BatchSwapOutputData> {
         let mut client = app.specific_client().await?;
-        let reserves_data: Reserves = client
-            .stub_cpmm_reserves(StubCpmmReservesRequest {
+        client
+            .batch_swap_output_data(BatchSwapOutputDataRequest {
+                height: *height,
                 trading_pair: Some((*trading_pair).into()),
             })
             .await?
             .into_inner()
             .try_into()
-            .context("cannot parse stub CPMM reserves data")?;
-        println!("Constant-Product Market Maker Reserves:");
-        let mut table = Table::new();
-        let view_client: &mut dyn ViewClient = app.view.as_mut().unwrap();
-        let asset_cache = view_client.assets().await?;
-        let asset_1 = asset_cache
-            .get(&trading_pair.asset_1())
-            .map(|base_denom| {
-                let display_denom = base_denom.best_unit_for(reserves_data.r1);
-                (
-                    format!("{}", display_denom),
-                    display_denom.format_value(reserves_data.r1),
-                )
-            })
-            .unwrap_or_else(|| {
-                (
-                    format!("{}", trading_pair.asset_1()),
-                    reserves_data.r1.to_string(),
-                )
-            });
-        let asset_2 = asset_cache
-            .get(&trading_pair.asset_2())
-            .map(|base_denom| {
-                let display_denom = base_denom.best_unit_for(reserves_data.r2);
-                (
-                    format!("{}", display_denom),
-                    display_denom.format_value(reserves_data.r2),
-                )
-            })
-            .unwrap_or_else(|| {
-                (
-                    format!("{}", trading_pair.asset_2()),
-                    reserves_data.r2.to_string(),
-                )
-            });
-        table.load_preset(presets::NOTHING);
-        table
-            .set_header(vec!["Denomination", "Reserve Amount"])
-            .add_row(vec![asset_1.0, asset_1.1])
-            .add_row(vec![asset_2.0, asset_2.1]);
-
-        println!("{}", table);
-
-        Ok(())
+            .context("cannot parse batch swap output data")
     }
 
-    pub async fn get_batch_outputs(
+    pub async fn get_swap_execution(
         &self,
         app: &mut App,
         height: &u64,
-        trading_pair: &TradingPair,
-    ) -> Result<BatchSwapOutputData> {
+        trading_pair: &DirectedTradingPair,
+    ) -> Result<SwapExecution> {
         let mut client = app.specific_client().await?;
         client
-            .batch_swap_output_data(BatchSwapOutputDataRequest {
+            .swap_execution(SwapExecutionRequest {
                 height: *height,
                 trading_pair: Some((*trading_pair).into()),
             })
             .await?
             .into_inner()
             .try_into()
-            .context("cannot parse batch swap output data")
+            .context("cannot parse swap execution")
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
-            DexCmd::CPMMReserves { trading_pair } => {
-                self.print_cpmm_reserves(app, trading_pair).await?;
-            }
             DexCmd::BatchOutputs {
                 height,
                 trading_pair,
@@ -168,6 +130,37 @@ impl DexCmd {
                     .add_row(vec![asset_1.0, asset_1.1, asset_1.2])
                     .add_row(vec![asset_2.0, asset_2.1, asset_2.2]);
 
+                println!("{}", table);
+            }
+            DexCmd::SwapExecution {
+                height,
+                trading_pair,
+            } => {
+                let execution = self.get_swap_execution(app, height, trading_pair).await?;
+
+                let view_client: &mut dyn ViewClient = app.view.as_mut().unwrap();
+                let asset_cache = view_client.assets().await?;
+
+                println!("Swap Execution for height {}:", height);
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Hop", "Input", "Output"]);
+                for (hop, trade) in execution.trades.iter().enumerate() {
+                    let input = Value {
+                        amount: trade.start_amount,
+                        asset_id: trade.path.start(),
+                    };
+                    let output = Value {
+                        amount: trade.end_amount,
+                        asset_id: trade.path.end(),
+                    };
+                    table.add_row(vec![
+                        (hop + 1).to_string(),
+                        input.format(&asset_cache),
+                        output.format(&asset_cache),
+                    ]);
+                }
+
                 println!("{}", table);
             }
         };
@@ -3,9 +3,13 @@ use std::{fs::File, io::Write};
 use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
-use penumbra_component::stake::validator::{self, ValidatorToml};
-use penumbra_crypto::stake::IdentityKey;
-use penumbra_proto::client::v1alpha1::ValidatorInfoRequest;
+use penumbra_component::stake::{
+    validator::{self, ValidatorToml},
+    FundingStreamPayout,
+};
+use penumbra_crypto::{stake::IdentityKey, Amount, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_proto::client::v1alpha1::{FundingStreamPayoutsRequest, ValidatorInfoRequest};
+use penumbra_view::ViewClient;
 
 use crate::App;
 
@@ -29,6 +33,11 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Show the payouts of a validator's commission to its funding streams.
+    Rewards {
+        /// The identity key of the validator to fetch.
+        identity_key: String,
+    },
 }
 
 impl ValidatorCmd {
@@ -203,6 +212,52 @@ impl ValidatorCmd {
                     println!("{}", toml::to_string_pretty(&validator)?);
                 }
             }
+            ValidatorCmd::Rewards { identity_key } => {
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+
+                let mut client = app.specific_client().await?;
+                let payouts = client
+                    .funding_stream_payouts(FundingStreamPayoutsRequest {
+                        chain_id: app.view().chain_params().await?.chain_id,
+                        identity_key: Some(identity_key.into()),
+                    })
+                    .await?
+                    .into_inner()
+                    .payouts
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<FundingStreamPayout>, _>>()?;
+
+                let asset_cache = app.view().assets().await?;
+                let stake = |amount: Amount| {
+                    Value {
+                        amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                    .format(&asset_cache)
+                };
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Epoch", "Address", "Amount"]);
+                table
+                    .get_column_mut(2)
+                    .unwrap()
+                    .set_cell_alignment(comfy_table::CellAlignment::Right);
+
+                let mut total = Amount::zero();
+                for payout in &payouts {
+                    table.add_row(vec![
+                        payout.epoch_index.to_string(),
+                        payout.address.to_string(),
+                        stake(payout.amount),
+                    ]);
+                    total = total + payout.amount;
+                }
+                table.add_row(vec!["Total".to_string(), String::new(), stake(total)]);
+
+                println!("{}", table);
+            }
         }
 
         Ok(())
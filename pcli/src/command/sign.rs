@@ -0,0 +1,137 @@
+use anyhow::Result;
+use camino::Utf8PathBuf;
+use penumbra_crypto::{
+    asset, keys::FullViewingKey, Address, Value, STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM,
+};
+use penumbra_transaction::plan::ActionPlan;
+use rand_core::OsRng;
+
+use crate::{
+    offline::{read_plan, write_auth_data},
+    App,
+};
+
+/// Authorize a transaction plan with the wallet's spend key.
+#[derive(Debug, clap::Parser)]
+pub struct SignCmd {
+    /// The transaction plan to authorize, as written by `pcli tx --plan-only`.
+    plan: Utf8PathBuf,
+    /// The file to write the authorization data to.
+    output: Utf8PathBuf,
+}
+
+impl SignCmd {
+    /// Signing never requires network access.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, app: &App) -> Result<()> {
+        let plan = read_plan(&self.plan)?;
+        let sk = app.spend_key()?;
+
+        // There's no view service to look up asset metadata while offline, so
+        // only the staking token can be displayed with its units.
+        let cache: asset::Cache = [STAKING_TOKEN_DENOM.clone()].into_iter().collect();
+        println!(
+            "Authorizing a transaction on chain {}, paying a fee of {}, with actions:",
+            plan.chain_id,
+            plan.fee.format(&cache),
+        );
+        for action in &plan.actions {
+            println!(
+                "  {}",
+                describe_action(action, sk.full_viewing_key(), &cache)
+            );
+        }
+
+        let auth_data = plan.authorize(OsRng, sk);
+        write_auth_data(&self.output, auth_data)?;
+        println!("Wrote authorization data to {}", self.output);
+
+        Ok(())
+    }
+}
+
+/// Describe a planned action, so that the user can check what they authorize.
+fn describe_action(action: &ActionPlan, fvk: &FullViewingKey, cache: &asset::Cache) -> String {
+    let staking = |amount| {
+        Value {
+            amount,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .format(cache)
+    };
+    let owner = |address: &Address| {
+        if fvk.address_index(address).is_some() {
+            " (one of your own addresses)"
+        } else {
+            ""
+        }
+    };
+
+    match action {
+        ActionPlan::Spend(spend) => format!("Spend {}", spend.note.value().format(cache)),
+        ActionPlan::Output(output) => format!(
+            "Output {} to {}{}",
+            output.value.format(cache),
+            output.dest_address,
+            owner(&output.dest_address),
+        ),
+        ActionPlan::Delegate(delegate) => format!(
+            "Delegate {} to {}",
+            staking(delegate.unbonded_amount),
+            delegate.validator_identity,
+        ),
+        ActionPlan::Undelegate(undelegate) => format!(
+            "Undelegate {} from {}",
+            staking(undelegate.unbonded_amount),
+            undelegate.validator_identity,
+        ),
+        ActionPlan::UndelegateClaim(_) => "Claim undelegated stake".to_string(),
+        ActionPlan::ValidatorDefinition(_) => "Upload a validator definition".to_string(),
+        ActionPlan::Swap(swap) => {
+            let plaintext = &swap.swap_plaintext;
+            format!(
+                "Swap {} and {}, claimable by {}{}",
+                Value {
+                    amount: plaintext.delta_1_i,
+                    asset_id: plaintext.trading_pair.asset_1(),
+                }
+                .format(cache),
+                Value {
+                    amount: plaintext.delta_2_i,
+                    asset_id: plaintext.trading_pair.asset_2(),
+                }
+                .format(cache),
+                plaintext.claim_address,
+                owner(&plaintext.claim_address),
+            )
+        }
+        ActionPlan::SwapClaim(_) => "Claim the outputs of a swap".to_string(),
+        ActionPlan::IBCAction(_) => "Relay an IBC message".to_string(),
+        ActionPlan::Ics20Withdrawal(withdrawal) => format!(
+            "Withdraw {} {} to {} on {}",
+            withdrawal.amount,
+            withdrawal.denom,
+            withdrawal.destination_chain_address,
+            withdrawal.destination_chain_id,
+        ),
+        ActionPlan::ProposalSubmit(submit) => format!(
+            "Submit a governance proposal, depositing {}",
+            staking(submit.deposit_amount),
+        ),
+        ActionPlan::ProposalWithdraw(_) => "Withdraw a governance proposal".to_string(),
+        ActionPlan::DelegatorVote(_) => "Vote on a proposal as a delegator".to_string(),
+        ActionPlan::ValidatorVote(_) => "Vote on a proposal as a validator".to_string(),
+        ActionPlan::ProposalDepositClaim(_) => "Claim a proposal deposit".to_string(),
+        ActionPlan::PositionOpen(_) => "Open a liquidity position".to_string(),
+        ActionPlan::PositionClose(close) => {
+            format!("Close liquidity position {}", close.position_id)
+        }
+        ActionPlan::PositionWithdraw(_) => "Withdraw a liquidity position".to_string(),
+        ActionPlan::PositionRewardClaim(_) => {
+            "Claim the rewards of a liquidity position".to_string()
+        }
+    }
+}
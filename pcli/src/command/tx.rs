@@ -2,11 +2,27 @@ use std::{fs::File, io::Write};
 
 use anyhow::{anyhow, Context, Result};
 use ark_ff::UniformRand;
+use camino::Utf8PathBuf;
 use decaf377::Fr;
+use ibc::{
+    clients::ics07_tendermint::client_state::ClientState as TendermintClientState,
+    core::{
+        ics03_connection::connection::ConnectionEnd,
+        ics04_channel::channel::ChannelEnd,
+        ics24_host::identifier::{ChannelId, PortId},
+    },
+};
 use penumbra_chain::Epoch;
 use penumbra_component::stake::rate::RateData;
 use penumbra_crypto::{
     asset,
+    dex::{
+        lp::{
+            position::{self, Position},
+            Reserves, TradingFunction,
+        },
+        TradingPair,
+    },
     keys::AddressIndex,
     stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken},
     transaction::Fee,
@@ -14,18 +30,34 @@ use penumbra_crypto::{
 };
 use penumbra_proto::client::v1alpha1::ValidatorPenaltyRequest;
 use penumbra_transaction::{
-    action::Proposal,
+    action::{Ics20Withdrawal, Proposal, Vote},
     plan::{SwapClaimPlan, UndelegateClaimPlan},
 };
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
-use rand_core::OsRng;
+use rand_core::{OsRng, RngCore};
 
-use crate::App;
+use crate::{
+    offline::{read_auth_data, read_plan},
+    App,
+};
 
+mod liquidity_position;
 mod proposal;
+use liquidity_position::PositionCmd;
 use proposal::ProposalCmd;
 
+#[derive(Debug, clap::Args)]
+pub struct TxArgs {
+    /// Write the transaction plan to this file instead of building and
+    /// broadcasting the transaction, so that it can be authorized offline with
+    /// `pcli sign`.
+    #[clap(long, global = true, value_name = "FILE")]
+    pub plan_only: Option<Utf8PathBuf>,
+    #[clap(subcommand)]
+    pub cmd: TxCmd,
+}
+
 #[derive(Debug, clap::Subcommand)]
 pub enum TxCmd {
     /// Send funds to a Penumbra address.
@@ -46,6 +78,36 @@ pub enum TxCmd {
         #[clap(long)]
         memo: Option<String>,
     },
+    /// Withdraw funds to an address on a counterparty chain over IBC.
+    ///
+    /// At least one of `--timeout-height` and `--timeout-timestamp` must be
+    /// set. If the transfer times out, the funds are returned to an ephemeral
+    /// address controlled by the `--source` account.
+    #[clap(display_order = 150)]
+    Withdraw {
+        /// The address on the counterparty chain to send funds to.
+        #[clap(long)]
+        to: String,
+        /// The number of the transfer channel to withdraw over, e.g. 0 for `channel-0`.
+        #[clap(long)]
+        channel: u64,
+        /// The height on the counterparty chain after which the transfer times out,
+        /// in the chain's current revision.
+        #[clap(long)]
+        timeout_height: Option<u64>,
+        /// The timestamp, in nanoseconds since the Unix epoch, after which the
+        /// transfer times out.
+        #[clap(long)]
+        timeout_timestamp: Option<u64>,
+        /// The amount to withdraw, written as a typed value 1.87penumbra, 12cubes, etc.
+        value: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -89,7 +151,10 @@ pub enum TxCmd {
         /// The identity key of the validator to delegate to.
         #[clap(long)]
         to: String,
-        /// The amount of stake to delegate.
+        /// The amount of stake to redelegate.
+        ///
+        /// The undelegated stake must finish unbonding before it can be claimed, so
+        /// the new delegation is funded with the same amount of spendable stake.
         amount: String,
         /// The transaction fee (paid in upenumbra).
         #[clap(long, default_value = "0")]
@@ -125,6 +190,27 @@ pub enum TxCmd {
     /// Submit or withdraw a governance proposal.
     #[clap(display_order = 400, subcommand)]
     Proposal(ProposalCmd),
+    /// Vote on a governance proposal in your role as a delegator.
+    ///
+    /// Every delegation note you held when voting on the proposal started is
+    /// used to vote. To vote on a proposal as a validator, use `pcli validator vote`.
+    #[clap(display_order = 400)]
+    Vote {
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// The proposal id to vote on.
+        #[clap(long = "on")]
+        proposal_id: u64,
+        /// The vote to cast.
+        vote: Vote,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Manage liquidity positions in the DEX.
+    #[clap(display_order = 500, subcommand)]
+    Position(PositionCmd),
     /// Consolidate many small notes into a few larger notes.
     ///
     /// Since Penumbra transactions reveal their arity (how many spends,
@@ -135,6 +221,17 @@ pub enum TxCmd {
     /// Currently, only zero-fee sweep transactions are implemented.
     #[clap(display_order = 990)]
     Sweep,
+    /// Build and broadcast a transaction from a plan that was authorized offline.
+    ///
+    /// The plan is written by `pcli tx --plan-only`, and the authorization
+    /// data by `pcli sign`.
+    #[clap(display_order = 995)]
+    BuildAndBroadcast {
+        /// The transaction plan file.
+        plan: Utf8PathBuf,
+        /// The authorization data file.
+        auth: Utf8PathBuf,
+    },
 }
 
 impl TxCmd {
@@ -142,6 +239,7 @@ impl TxCmd {
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::Withdraw { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
@@ -149,6 +247,9 @@ impl TxCmd {
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Redelegate { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
+            TxCmd::Vote { .. } => false,
+            TxCmd::Position(position_cmd) => position_cmd.offline(),
+            TxCmd::BuildAndBroadcast { .. } => false,
         }
     }
 
@@ -184,6 +285,71 @@ impl TxCmd {
                 .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Withdraw {
+                to,
+                channel,
+                timeout_height,
+                timeout_timestamp,
+                value,
+                fee,
+                source,
+            } => {
+                if timeout_height.is_none() && timeout_timestamp.is_none() {
+                    return Err(anyhow!(
+                        "at least one of --timeout-height and --timeout-timestamp must be set"
+                    ));
+                }
+
+                let value = value.parse::<Value>()?;
+                let denom = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&value.asset_id)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("unknown asset {}", value.asset_id))?;
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let source_channel = ChannelId::new(*channel);
+                let destination_chain_id = counterparty_chain_id(app, &source_channel).await?;
+
+                // If the funds are returned, they'll be sent to an ephemeral
+                // address, so that withdrawals can't be linked to each other.
+                let (return_address, _dtk) =
+                    app.fvk.ephemeral_address(OsRng, AddressIndex::new(*source));
+
+                let withdrawal = Ics20Withdrawal {
+                    destination_chain_id,
+                    denom,
+                    amount: value.amount,
+                    destination_chain_address: to.clone(),
+                    return_address,
+                    // A zero timeout height disables the height timeout, while
+                    // an unset timestamp is pushed out as far as possible.
+                    timeout_height: timeout_height.unwrap_or(0),
+                    timeout_time: timeout_timestamp.unwrap_or(u64::MAX),
+                    source_port: PortId::transfer(),
+                    source_channel,
+                };
+                withdrawal.validate()?;
+
+                let plan = Planner::new(OsRng)
+                    .ics20_withdrawal(withdrawal)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan withdrawal")?;
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Sweep if app.plan_only.is_some() => {
+                return Err(anyhow!(
+                    "sweeping submits several rounds of transactions, so it can't be planned offline"
+                ));
+            }
             TxCmd::Sweep => loop {
                 let specific_client = app.specific_client().await?;
                 let plans =
@@ -210,6 +376,12 @@ impl TxCmd {
                 fee,
                 source,
             } => {
+                if app.plan_only.is_some() {
+                    return Err(anyhow!(
+                        "a swap is claimed in a second transaction that depends on the first, so it can't be planned offline"
+                    ));
+                }
+
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
@@ -403,6 +575,7 @@ impl TxCmd {
                 // We want to claim them into the same address index that currently holds the tokens.
                 let notes = view.unspent_notes_by_address_and_asset(account_id).await?;
 
+                let mut claims = Vec::new();
                 for (address_index, notes_by_asset) in notes.into_iter() {
                     for (token, notes) in notes_by_asset
                         .into_iter()
@@ -427,53 +600,128 @@ impl TxCmd {
                             }
                         })
                     {
-                        println!("claiming {}", token.denom().default_unit());
-                        let validator_identity = token.validator();
-                        let start_epoch_index = token.start_epoch_index();
-                        let end_epoch_index = token.end_epoch_index();
-
-                        let penalty: Penalty = specific_client
-                            .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
-                                chain_id: params.chain_id.to_string(),
-                                identity_key: Some(validator_identity.into()),
-                                start_epoch_index,
-                                end_epoch_index,
-                            }))
-                            .await?
-                            .into_inner()
-                            .penalty
-                            .ok_or_else(|| {
-                                anyhow::anyhow!(
-                                    "no penalty returned for validator {}",
-                                    validator_identity
-                                )
-                            })?
-                            .try_into()?;
-
-                        let mut planner = Planner::new(OsRng);
-                        let unbonding_amount = notes.iter().map(|n| n.note.amount()).sum();
-                        for note in notes {
-                            planner.spend(note.note, note.position);
-                        }
-
-                        let plan = planner
-                            .undelegate_claim(UndelegateClaimPlan {
-                                validator_identity,
-                                start_epoch_index,
-                                end_epoch_index,
-                                penalty,
-                                unbonding_amount,
-                                balance_blinding: Fr::rand(&mut OsRng),
-                            })
-                            .fee(fee.clone())
-                            .plan(app.view.as_mut().unwrap(), &app.fvk, address_index)
-                            .await?;
-                        app.build_and_submit_transaction(plan).await?;
+                        claims.push((address_index, token, notes));
                     }
                 }
+
+                // Each token is claimed in its own transaction, but only one
+                // plan can be written.
+                if app.plan_only.is_some() && claims.len() > 1 {
+                    return Err(anyhow!(
+                        "{} unbonding tokens are ready to be claimed, each in its own transaction, so they can't be planned offline",
+                        claims.len()
+                    ));
+                }
+
+                for (address_index, token, notes) in claims {
+                    println!("claiming {}", token.denom().default_unit());
+                    let validator_identity = token.validator();
+                    let start_epoch_index = token.start_epoch_index();
+                    let end_epoch_index = token.end_epoch_index();
+
+                    let penalty: Penalty = specific_client
+                        .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
+                            chain_id: params.chain_id.to_string(),
+                            identity_key: Some(validator_identity.into()),
+                            start_epoch_index,
+                            end_epoch_index,
+                        }))
+                        .await?
+                        .into_inner()
+                        .penalty
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "no penalty returned for validator {}",
+                                validator_identity
+                            )
+                        })?
+                        .try_into()?;
+
+                    let mut planner = Planner::new(OsRng);
+                    let unbonding_amount = notes.iter().map(|n| n.note.amount()).sum();
+                    for note in notes {
+                        planner.spend(note.note, note.position);
+                    }
+
+                    let plan = planner
+                        .undelegate_claim(UndelegateClaimPlan {
+                            validator_identity,
+                            start_epoch_index,
+                            end_epoch_index,
+                            penalty,
+                            unbonding_amount,
+                            balance_blinding: Fr::rand(&mut OsRng),
+                        })
+                        .fee(fee.clone())
+                        .plan(app.view.as_mut().unwrap(), &app.fvk, address_index)
+                        .await?;
+                    app.build_and_submit_transaction(plan).await?;
+                }
             }
-            TxCmd::Redelegate { .. } => {
-                println!("Sorry, this command is not yet implemented");
+            TxCmd::Redelegate {
+                from,
+                to,
+                amount,
+                fee,
+                source,
+            } => {
+                let unbonded_amount = {
+                    let Value { amount, asset_id } = amount.parse::<Value>()?;
+                    if asset_id != *STAKING_TOKEN_ASSET_ID {
+                        return Err(anyhow!("staking can only be done with the staking token"));
+                    }
+                    amount
+                };
+
+                let from = from.parse::<IdentityKey>()?;
+                let to = to.parse::<IdentityKey>()?;
+                if from == to {
+                    return Err(anyhow!("can't redelegate to the same validator"));
+                }
+
+                let mut client = app.specific_client().await?;
+                let from_rate_data: RateData = client
+                    .next_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let to_rate_data: RateData = client
+                    .next_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                if from_rate_data.epoch_index != to_rate_data.epoch_index {
+                    return Err(anyhow!(
+                        "validator rates are from different epochs, please try again"
+                    ));
+                }
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let params = app.view.as_mut().unwrap().chain_params().await?;
+
+                let end_epoch_index = from_rate_data.epoch_index + params.unbonding_epochs;
+
+                let delegation_amount = from_rate_data.delegation_amount(unbonded_amount.into());
+
+                let mut planner = Planner::new(OsRng);
+
+                let plan = planner
+                    .fee(fee)
+                    .redelegate(
+                        delegation_amount.into(),
+                        from_rate_data,
+                        to_rate_data,
+                        end_epoch_index,
+                    )
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't build redelegate plan")?;
+
+                app.build_and_submit_transaction(plan).await?;
             }
             TxCmd::Proposal(ProposalCmd::Submit { file, fee, source }) => {
                 let proposal: Proposal = serde_json::from_reader(File::open(file)?)?;
@@ -572,27 +820,223 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Proposal(ProposalCmd::Vote {
-                proposal_id: _,
-                vote: _,
-                fee: _,
-                source: _,
+            TxCmd::Vote {
+                fee,
+                proposal_id,
+                vote,
+                source,
+            } => {
+                use penumbra_component::governance::state_key;
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let mut client = app.specific_client().await?;
+                let start_position: u64 = client
+                    .key_proto(state_key::proposal_voting_start_position(*proposal_id))
+                    .await?;
+                let start_height: u64 = client
+                    .key_proto(state_key::proposal_voting_start(*proposal_id))
+                    .await?;
+
+                let plan = plan::delegator_vote(
+                    &app.fvk,
+                    app.view.as_mut().unwrap(),
+                    OsRng,
+                    *proposal_id,
+                    *vote,
+                    start_position.into(),
+                    start_height,
+                    fee,
+                    AddressIndex::new(*source),
+                )
+                .await?;
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Position(PositionCmd::Open {
+                reserves_1,
+                reserves_2,
+                p,
+                q,
+                trading_fee,
+                fee,
+                source,
+            }) => {
+                let reserves_1 = reserves_1.parse::<Value>()?;
+                let reserves_2 = reserves_2.parse::<Value>()?;
+                if reserves_1.asset_id == reserves_2.asset_id {
+                    return Err(anyhow!(
+                        "a position must trade between two different assets"
+                    ));
+                }
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                // The trading pair is canonically ordered, so if the assets were given
+                // in the opposite order, the reserves and coefficients must be swapped too.
+                let pair = TradingPair::new(reserves_1.asset_id, reserves_2.asset_id);
+                let (reserves_1, reserves_2, p, q) = if pair.asset_1() == reserves_1.asset_id {
+                    (reserves_1, reserves_2, *p, *q)
+                } else {
+                    (reserves_2, reserves_1, *q, *p)
+                };
+
+                let mut nonce = [0u8; 32];
+                OsRng.fill_bytes(&mut nonce);
+                let position = Position {
+                    phi: TradingFunction::new(pair, *trading_fee, p.into(), q.into()),
+                    nonce,
+                };
+                let position_id = position.id();
+                let initial_reserves = Reserves {
+                    r1: reserves_1.amount,
+                    r2: reserves_2.amount,
+                };
+
+                let plan = Planner::new(OsRng)
+                    .position_open(position, initial_reserves)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan position open")?;
+
+                app.build_and_submit_transaction(plan).await?;
+                println!("opened position {}", position_id);
+            }
+            TxCmd::Position(PositionCmd::Close {
+                position_id,
+                fee,
+                source,
+            }) => {
+                let metadata = position_metadata(app, position_id).await?;
+                if metadata.state != position::State::Opened {
+                    anyhow::bail!(
+                        "position {} is {}, so it cannot be closed",
+                        position_id,
+                        metadata.state
+                    );
+                }
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let plan = Planner::new(OsRng)
+                    .position_close(*position_id)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan position close")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Position(PositionCmd::Withdraw {
+                position_id,
+                fee,
+                source,
+            }) => {
+                let metadata = position_metadata(app, position_id).await?;
+                if metadata.state != position::State::Closed {
+                    anyhow::bail!(
+                        "position {} is {}, but only closed positions can be withdrawn",
+                        position_id,
+                        metadata.state
+                    );
+                }
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                // The withdrawal must commit to exactly the position's final reserves,
+                // which we know from the chain state now that the position is closed.
+                let plan = Planner::new(OsRng)
+                    .position_withdraw(*position_id, metadata.position.phi.pair, metadata.reserves)
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan position withdrawal")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Position(PositionCmd::Claim {
+                position_id,
+                fee,
+                source,
             }) => {
-                println!("Sorry, delegator voting is not yet implemented");
-                // TODO: fill this in for delegator votes
-                // let plan = plan::delegator_vote(
-                //     &app.fvk,
-                //     &mut app.view,
-                //     OsRng,
-                //     *proposal_id,
-                //     *vote,
-                //     *fee,
-                //     *source,
-                // )
-                // .await?;
-                // app.build_and_submit_transaction(plan).await?;
+                let metadata = position_metadata(app, position_id).await?;
+                if metadata.state != position::State::Withdrawn {
+                    anyhow::bail!(
+                        "position {} is {}, but rewards can only be claimed for withdrawn positions",
+                        position_id,
+                        metadata.state
+                    );
+                }
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                // There are no liquidity incentives yet, so the rewards are always empty.
+                let plan = Planner::new(OsRng)
+                    .position_reward_claim(
+                        *position_id,
+                        metadata.position.phi.pair,
+                        Reserves::zero(),
+                    )
+                    .fee(fee)
+                    .plan(
+                        app.view.as_mut().unwrap(),
+                        &app.fvk,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't plan position reward claim")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::BuildAndBroadcast { plan, auth } => {
+                let plan = read_plan(plan)?;
+                let auth_data = read_auth_data(auth)?;
+                app.build_and_submit_authorized_transaction(plan, auth_data)
+                    .await?;
             }
         }
         Ok(())
     }
 }
+
+/// Look up the chain ID of the counterparty chain at the other end of a
+/// transfer channel.
+async fn counterparty_chain_id(app: &mut App, channel_id: &ChannelId) -> Result<String> {
+    use penumbra_component::ibc::state_key;
+
+    let mut client = app.specific_client().await?;
+    let channel: ChannelEnd = client
+        .key_domain(state_key::channel(channel_id, &PortId::transfer()))
+        .await
+        .with_context(|| format!("could not find transfer channel {}", channel_id))?;
+    let connection_id = channel
+        .connection_hops
+        .first()
+        .ok_or_else(|| anyhow!("channel {} has no connection", channel_id))?;
+    let connection: ConnectionEnd = client
+        .key_domain(state_key::connections::by_connection_id(connection_id))
+        .await
+        .with_context(|| format!("could not find connection {}", connection_id))?;
+    let client_state: TendermintClientState = client
+        .key_domain(state_key::client_state(connection.client_id()))
+        .await
+        .with_context(|| format!("could not find client {}", connection.client_id()))?;
+
+    Ok(client_state.chain_id.to_string())
+}
+
+/// Fetch the on-chain metadata for a liquidity position.
+async fn position_metadata(app: &mut App, id: &position::Id) -> Result<position::Metadata> {
+    let mut client = app.specific_client().await?;
+    client
+        .key_domain(penumbra_component::dex::state_key::position_by_id(id))
+        .await
+        .with_context(|| format!("could not find position {}", id))
+}
@@ -0,0 +1,79 @@
+use penumbra_crypto::dex::lp::position;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum PositionCmd {
+    /// Open a new liquidity position based on a trading function.
+    ///
+    /// The position's trading pair is formed by the denominations of the two
+    /// reserve amounts. The trading function is `phi(R) = p * R_1 + q * R_2`,
+    /// where `R_1` and `R_2` are the reserves of the first and second asset, in
+    /// the order they are given.
+    Open {
+        /// The initial reserves of the first asset, written as a typed value 100gm, 0.5penumbra, etc.
+        reserves_1: String,
+        /// The initial reserves of the second asset, written as a typed value 100gn, 0gm, etc.
+        reserves_2: String,
+        /// The coefficient of the first asset in the trading function.
+        #[clap(long)]
+        p: u64,
+        /// The coefficient of the second asset in the trading function.
+        #[clap(long)]
+        q: u64,
+        /// The fee charged to traders by the position, in basis points.
+        #[clap(long, default_value = "0")]
+        trading_fee: u32,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Close an opened liquidity position, so that it can no longer be traded against.
+    ///
+    /// This consumes the position's opened NFT and mints a closed NFT, which
+    /// can later be used to withdraw the position's reserves.
+    Close {
+        /// The ID of the position to close.
+        position_id: position::Id,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Withdraw the final reserves of a closed liquidity position.
+    Withdraw {
+        /// The ID of the position to withdraw.
+        position_id: position::Id,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+    /// Claim any rewards accumulated by a withdrawn liquidity position.
+    Claim {
+        /// The ID of the position to claim rewards for.
+        position_id: position::Id,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+    },
+}
+
+impl PositionCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            PositionCmd::Open { .. } => false,
+            PositionCmd::Close { .. } => false,
+            PositionCmd::Withdraw { .. } => false,
+            PositionCmd::Claim { .. } => false,
+        }
+    }
+}
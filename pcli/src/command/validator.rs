@@ -28,7 +28,7 @@ pub enum ValidatorCmd {
     /// Cast a vote on a proposal in your capacity as a validator.
     ///
     /// This is distinct from casting a vote as a delegator, which can be done using `pcli tx
-    /// proposal vote`.
+    /// vote`.
     Vote {
         /// The transaction fee (paid in upenumbra).
         #[clap(long, default_value = "0")]
@@ -96,8 +96,7 @@ impl ValidatorCmd {
 
     // TODO: move use of sk into custody service
     pub async fn exec(&self, app: &mut App) -> Result<()> {
-        let sk = app.wallet.spend_key.clone();
-        let fvk = sk.full_viewing_key().clone();
+        let fvk = app.fvk.clone();
         match self {
             ValidatorCmd::Identity => {
                 let ik = IdentityKey(fvk.spend_verification_key().clone());
@@ -128,6 +127,7 @@ impl ValidatorCmd {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 // Sign the validator definition with the wallet's spend key.
+                let sk = app.spend_key()?.clone();
                 let protobuf_serialized: ProtoValidator = new_validator.clone().into();
                 let v_bytes = protobuf_serialized.encode_to_vec();
                 let auth_sig = sk.spend_auth_key().sign(OsRng, &v_bytes);
@@ -158,6 +158,7 @@ impl ValidatorCmd {
                 vote,
             } => {
                 // TODO: support submitting a separate governance key.
+                let sk = app.spend_key()?.clone();
                 let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
                 // Currently this is always just copied from the identity key
                 let governance_key = GovernanceKey(identity_key.0);
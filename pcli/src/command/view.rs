@@ -1,7 +1,10 @@
 use anyhow::Result;
 
 use penumbra_crypto::FullViewingKey;
-use penumbra_proto::client::v1alpha1::oblivious_query_service_client::ObliviousQueryServiceClient;
+use penumbra_proto::client::v1alpha1::{
+    oblivious_query_service_client::ObliviousQueryServiceClient,
+    specific_query_service_client::SpecificQueryServiceClient,
+};
 use penumbra_view::ViewClient;
 use tonic::transport::Channel;
 
@@ -9,8 +12,12 @@ mod balance;
 use balance::BalanceCmd;
 mod address;
 use address::AddressCmd;
+mod positions;
+use positions::PositionsCmd;
 mod staked;
 use staked::StakedCmd;
+mod staking;
+use staking::StakingCmd;
 pub mod transaction_hashes;
 use transaction_hashes::TransactionHashesCmd;
 mod tx;
@@ -24,6 +31,10 @@ pub enum ViewCmd {
     Balance(BalanceCmd),
     /// View your staked delegation tokens.
     Staked(StakedCmd),
+    /// View the rewards earned by your delegations, and your unbonding stake.
+    Staking(StakingCmd),
+    /// View the liquidity positions controlled by your LP NFTs.
+    Positions(PositionsCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
     /// Synchronizes the client, privately scanning the chain state.
@@ -44,6 +55,8 @@ impl ViewCmd {
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
+            ViewCmd::Staking(staking_cmd) => staking_cmd.offline(),
+            ViewCmd::Positions(positions_cmd) => positions_cmd.offline(),
             ViewCmd::Reset(_) => true,
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
@@ -56,6 +69,7 @@ impl ViewCmd {
         full_viewing_key: &FullViewingKey,
         view_client: Option<&mut impl ViewClient>,
         oblivious_client: &mut ObliviousQueryServiceClient<Channel>,
+        specific_client: &mut SpecificQueryServiceClient<Channel>,
     ) -> Result<()> {
         match self {
             ViewCmd::Tx(tx_cmd) => {
@@ -86,6 +100,16 @@ impl ViewCmd {
                     .exec(full_viewing_key, view_client.unwrap(), oblivious_client)
                     .await?;
             }
+            ViewCmd::Staking(staking_cmd) => {
+                staking_cmd
+                    .exec(full_viewing_key, view_client.unwrap(), oblivious_client)
+                    .await?;
+            }
+            ViewCmd::Positions(positions_cmd) => {
+                positions_cmd
+                    .exec(full_viewing_key, view_client.unwrap(), specific_client)
+                    .await?;
+            }
         }
 
         Ok(())
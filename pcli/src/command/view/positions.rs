@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use penumbra_component::dex::state_key;
+use penumbra_crypto::{
+    dex::lp::{position, LpNft},
+    FullViewingKey, Value,
+};
+use penumbra_proto::client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient;
+use penumbra_view::ViewClient;
+use tonic::transport::Channel;
+
+#[derive(Debug, clap::Parser)]
+pub struct PositionsCmd {}
+
+impl PositionsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(
+        &self,
+        full_viewing_key: &FullViewingKey,
+        view_client: &mut impl ViewClient,
+        specific_client: &mut SpecificQueryServiceClient<Channel>,
+    ) -> Result<()> {
+        let asset_cache = view_client.assets().await?;
+
+        let notes = view_client
+            .unspent_notes_by_asset_and_address(full_viewing_key.hash())
+            .await?;
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Position ID", "NFT", "State", "Reserves"]);
+
+        // Each LP NFT we hold corresponds to a position we control; the state
+        // of the NFT records the last state transition we authorized, and the
+        // chain records the current state of the position.
+        for asset_id in notes.keys() {
+            let lpnft = match asset_cache
+                .get(asset_id)
+                .map(|denom| LpNft::try_from(denom.clone()))
+            {
+                Some(Ok(lpnft)) => lpnft,
+                _ => continue,
+            };
+
+            let position_id = lpnft.position_id();
+            let metadata: position::Metadata = specific_client
+                .key_domain(state_key::position_by_id(&position_id))
+                .await
+                .with_context(|| format!("could not fetch position {}", position_id))?;
+
+            let pair = metadata.position.phi.pair;
+            let reserves = [
+                Value {
+                    amount: metadata.reserves.r1,
+                    asset_id: pair.asset_1(),
+                },
+                Value {
+                    amount: metadata.reserves.r2,
+                    asset_id: pair.asset_2(),
+                },
+            ]
+            .iter()
+            .map(|value| value.format(&asset_cache))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+            table.add_row(vec![
+                position_id.to_string(),
+                lpnft.state().to_string(),
+                metadata.state.to_string(),
+                reserves,
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
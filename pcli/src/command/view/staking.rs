@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use futures::TryStreamExt;
+use penumbra_component::stake::validator;
+use penumbra_crypto::{stake::IdentityKey, FullViewingKey, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_proto::client::v1alpha1::{
+    oblivious_query_service_client::ObliviousQueryServiceClient, ValidatorInfoRequest,
+};
+use penumbra_view::ViewClient;
+use tonic::transport::Channel;
+
+#[derive(Debug, clap::Parser)]
+pub struct StakingCmd {}
+
+impl StakingCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(
+        &self,
+        full_viewing_key: &FullViewingKey,
+        view_client: &mut impl ViewClient,
+        oblivious_client: &mut ObliviousQueryServiceClient<Channel>,
+    ) -> Result<()> {
+        let asset_cache = view_client.assets().await?;
+
+        let names = oblivious_client
+            .validator_info(ValidatorInfoRequest {
+                show_inactive: true,
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(|info| {
+                let info = validator::Info::try_from(info)?;
+                Ok((info.validator.identity_key, info.validator.name))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        let name = |identity_key: &IdentityKey| {
+            names
+                .get(identity_key)
+                .cloned()
+                .unwrap_or_else(|| identity_key.to_string())
+        };
+
+        let report = view_client.staking_report(full_viewing_key.hash()).await?;
+
+        let stake = |amount: u64| {
+            Value {
+                amount: amount.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }
+            .format(&asset_cache)
+        };
+        // Rewards are negative if the validator was slashed.
+        let rewards = |amount: i64| {
+            let sign = if amount < 0 { "-" } else { "" };
+            format!("{}{}", sign, stake(amount.unsigned_abs()))
+        };
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Name",
+            "Value",
+            "Cost Basis",
+            "Unrealized Rewards",
+            "Realized Rewards",
+        ]);
+        for column in 1..5 {
+            table
+                .get_column_mut(column)
+                .unwrap()
+                .set_cell_alignment(comfy_table::CellAlignment::Right);
+        }
+
+        let (mut total_unrealized, mut total_realized) = (0i64, 0i64);
+        for delegation in &report.delegations {
+            table.add_row(vec![
+                name(&delegation.identity_key),
+                stake(delegation.current_value.into()),
+                stake(delegation.cost_basis.into()),
+                rewards(delegation.unrealized_rewards),
+                rewards(delegation.realized_rewards),
+            ]);
+            total_unrealized += delegation.unrealized_rewards;
+            total_realized += delegation.realized_rewards;
+        }
+        table.add_row(vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            rewards(total_unrealized),
+            rewards(total_realized),
+        ]);
+        println!("{}", table);
+
+        if report.unbondings.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Name", "Unbonding", "Ends In Epoch", "Claimable"]);
+        table
+            .get_column_mut(1)
+            .unwrap()
+            .set_cell_alignment(comfy_table::CellAlignment::Right);
+        for unbonding in &report.unbondings {
+            let claimable = match unbonding.epochs_remaining {
+                0 => "now".to_string(),
+                1 => "in 1 epoch".to_string(),
+                n => format!("in {} epochs", n),
+            };
+            table.add_row(vec![
+                name(&unbonding.identity_key),
+                stake(unbonding.amount.into()),
+                unbonding.end_epoch_index.to_string(),
+                claimable,
+            ]);
+        }
+        println!();
+        println!("Unbonding stake (current epoch {}):", report.epoch_index);
+        println!("{}", table);
+
+        Ok(())
+    }
+}
@@ -189,7 +189,9 @@ impl TxCmd {
                 anyhow::anyhow!("transaction {} not found in view service", self.hash,)
             })?;
         // Retrieve full TxP
-        let txp = view.transaction_perspective(self.hash.parse()?).await?;
+        let txp = view
+            .transaction_perspective(fvk.hash(), self.hash.parse()?)
+            .await?;
         // Generate TxV using TxP
         let txv = tx.decrypt_with_perspective(&txp);
 
@@ -298,6 +300,13 @@ impl TxCmd {
                     penumbra_transaction::ActionView::ValidatorVote(_) => {
                         ["Validator Vote".to_string(), "".to_string()]
                     }
+                    penumbra_transaction::ActionView::DelegatorVote(vote) => [
+                        format!(
+                            "Delegator Vote on Governance Proposal #{}",
+                            vote.body.proposal
+                        ),
+                        format!("{}", vote.body.vote),
+                    ],
                     penumbra_transaction::ActionView::PositionOpen(_) => {
                         ["Open Liquidity Position".to_string(), "".to_string()]
                     }
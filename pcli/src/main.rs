@@ -2,9 +2,10 @@
 use std::fs;
 
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use clap::Parser;
 use futures::StreamExt;
-use penumbra_crypto::FullViewingKey;
+use penumbra_crypto::{keys::SpendKey, FullViewingKey};
 use penumbra_proto::{
     custody::v1alpha1::custody_protocol_service_client::CustodyProtocolServiceClient,
     view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
@@ -16,8 +17,11 @@ mod box_grpc_svc;
 mod command;
 mod legacy;
 mod network;
+mod offline;
 mod opt;
+mod passphrase;
 mod warning;
+mod watch_only;
 
 use opt::Opt;
 use penumbra_wallet::KeyStore;
@@ -34,9 +38,15 @@ pub struct App {
     /// `.offline()` and Some(_) otherwise. Assuming `.offline()` has been implemenented
     /// correctly, this can be unwrapped safely.
     pub view: Option<ViewProtocolServiceClient<BoxGrpcService>>,
-    pub custody: CustodyProtocolServiceClient<BoxGrpcService>,
+    /// custody will be `None` for a watch-only wallet without a remote custody
+    /// service, in which case transactions can be planned but not authorized.
+    pub custody: Option<CustodyProtocolServiceClient<BoxGrpcService>>,
     pub fvk: FullViewingKey,
-    pub wallet: KeyStore,
+    /// wallet will be `None` for a watch-only wallet, which holds only the `fvk`.
+    pub wallet: Option<KeyStore>,
+    /// If set, transaction plans are written to this file instead of being
+    /// built and submitted, to be authorized offline.
+    pub plan_only: Option<Utf8PathBuf>,
     pub pd_url: Url,
 }
 
@@ -45,6 +55,17 @@ impl App {
         self.view.as_mut().unwrap()
     }
 
+    /// The spend key of the active account, which is unavailable for a
+    /// watch-only wallet.
+    pub fn spend_key(&self) -> Result<&SpendKey> {
+        self.wallet
+            .as_ref()
+            .map(KeyStore::spend_key)
+            .ok_or_else(|| {
+                anyhow::anyhow!("This command requires a spend key, but the wallet is watch-only")
+            })
+    }
+
     async fn sync(&mut self) -> Result<()> {
         let mut status_stream =
             ViewClient::status_stream(self.view.as_mut().unwrap(), self.fvk.hash()).await?;
@@ -133,12 +154,19 @@ async fn main() -> Result<()> {
     match &cmd {
         Command::Keys(_) => unreachable!("wallet command already executed"),
         Command::Debug(_) => unreachable!("debug command already executed"),
-        Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await?,
+        Command::Transaction(args) => args.cmd.exec(&mut app).await?,
+        Command::Sign(cmd) => cmd.exec(&app)?,
         Command::View(view_cmd) => {
             let mut oblivious_client = app.oblivious_client().await?;
+            let mut specific_client = app.specific_client().await?;
 
             view_cmd
-                .exec(&app.fvk, app.view.as_mut(), &mut oblivious_client)
+                .exec(
+                    &app.fvk,
+                    app.view.as_mut(),
+                    &mut oblivious_client,
+                    &mut specific_client,
+                )
                 .await?
         }
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
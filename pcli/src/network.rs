@@ -10,7 +10,7 @@ use penumbra_proto::{
     },
     DomainType,
 };
-use penumbra_transaction::{plan::TransactionPlan, Transaction};
+use penumbra_transaction::{plan::TransactionPlan, AuthorizationData, Transaction};
 use penumbra_view::ViewClient;
 use rand::Rng;
 use rand_core::OsRng;
@@ -18,19 +18,21 @@ use std::future::Future;
 use tonic::transport::Channel;
 use tracing::instrument;
 
-use crate::App;
+use crate::{offline, App};
 
 impl App {
     pub async fn build_and_submit_transaction(
         &mut self,
         plan: TransactionPlan,
     ) -> anyhow::Result<()> {
-        let await_detection_of_nullifier = plan.spend_plans().next().map(|spend_plan| {
-            // If we spend at least one note, then we should await detecting it (it doesn't matter
-            // which nullifier we wait for, since any will work)
-            self.fvk
-                .derive_nullifier(spend_plan.position, &spend_plan.note.commit())
-        });
+        // If we're only planning, write out the plan to be authorized offline.
+        if let Some(path) = &self.plan_only {
+            offline::write_plan(path, &plan)?;
+            println!("Wrote transaction plan to {}", path);
+            return Ok(());
+        }
+
+        let await_detection_of_nullifier = self.nullifier_to_await(&plan);
 
         let tx = self.build_transaction(plan).await?;
 
@@ -38,17 +40,61 @@ impl App {
             .await
     }
 
+    /// Builds a transaction from a plan that was authorized elsewhere, e.g.
+    /// with `pcli sign`, and submits it to the network.
+    pub async fn build_and_submit_authorized_transaction(
+        &mut self,
+        plan: TransactionPlan,
+        auth_data: AuthorizationData,
+    ) -> anyhow::Result<()> {
+        if auth_data.effect_hash != plan.effect_hash(&self.fvk) {
+            return Err(anyhow::anyhow!(
+                "authorization data does not match the transaction plan"
+            ));
+        }
+
+        let await_detection_of_nullifier = self.nullifier_to_await(&plan);
+
+        let account_id = self.fvk.hash();
+        let witness_data = self.view().witness(account_id, &plan).await?;
+        let tx = plan
+            .build_concurrent(&mut OsRng, &self.fvk, auth_data, witness_data)
+            .await?;
+
+        self.submit_transaction(&tx, await_detection_of_nullifier)
+            .await
+    }
+
+    /// The nullifier to await detection of after submitting a transaction
+    /// built from `plan`, if it spends any notes.
+    fn nullifier_to_await(&self, plan: &TransactionPlan) -> Option<Nullifier> {
+        plan.spend_plans().next().map(|spend_plan| {
+            // If we spend at least one note, then we should await detecting it (it doesn't matter
+            // which nullifier we wait for, since any will work)
+            self.fvk
+                .derive_nullifier(spend_plan.position, &spend_plan.note.commit())
+        })
+    }
+
     pub fn build_transaction(
         &mut self,
         plan: TransactionPlan,
     ) -> impl Future<Output = Result<Transaction>> + '_ {
-        penumbra_wallet::build_transaction(
-            &self.fvk,
-            self.view.as_mut().unwrap(),
-            &mut self.custody,
-            OsRng,
-            plan,
-        )
+        async move {
+            let custody = self.custody.as_mut().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The wallet is watch-only, so a remote custody service is required to authorize transactions (see --custody-address)"
+                )
+            })?;
+            penumbra_wallet::build_transaction(
+                &self.fvk,
+                self.view.as_mut().unwrap(),
+                custody,
+                OsRng,
+                plan,
+            )
+            .await
+        }
     }
 
     /// Submits a transaction to the network.
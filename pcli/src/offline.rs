@@ -0,0 +1,108 @@
+//! Reading and writing the files exchanged during offline signing.
+//!
+//! Transaction plans and authorization data are stored as the JSON encoding of
+//! their protos, so they can be inspected before signing.
+
+use std::{fs::OpenOptions, io::Write};
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use penumbra_proto::core::transaction::v1alpha1 as pb;
+use penumbra_transaction::{plan::TransactionPlan, AuthorizationData};
+
+/// Write a transaction plan to a new file at `path`.
+pub fn write_plan(path: &Utf8Path, plan: &TransactionPlan) -> Result<()> {
+    write_new(path, &serde_json::to_vec_pretty(plan)?)
+}
+
+/// Read a transaction plan written by [`write_plan`].
+pub fn read_plan(path: &Utf8Path) -> Result<TransactionPlan> {
+    let data = std::fs::read(path).with_context(|| format!("could not read plan file {}", path))?;
+    serde_json::from_slice(&data).with_context(|| format!("invalid plan file {}", path))
+}
+
+/// Write authorization data to a new file at `path`.
+pub fn write_auth_data(path: &Utf8Path, auth_data: AuthorizationData) -> Result<()> {
+    let auth_data: pb::AuthorizationData = auth_data.into();
+    write_new(path, &serde_json::to_vec_pretty(&auth_data)?)
+}
+
+/// Read authorization data written by [`write_auth_data`].
+pub fn read_auth_data(path: &Utf8Path) -> Result<AuthorizationData> {
+    let data = std::fs::read(path)
+        .with_context(|| format!("could not read authorization file {}", path))?;
+    let auth_data: pb::AuthorizationData = serde_json::from_slice(&data)
+        .with_context(|| format!("invalid authorization file {}", path))?;
+    auth_data.try_into()
+}
+
+/// Write `data` to a new file, refusing to overwrite an existing one.
+fn write_new(path: &Utf8Path, data: &[u8]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("could not create {}, refusing to overwrite it", path))?;
+    file.write_all(data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::{
+        keys::{SeedPhrase, SpendKey},
+        Note, Value, STAKING_TOKEN_ASSET_ID,
+    };
+    use penumbra_transaction::plan::{ActionPlan, OutputPlan, SpendPlan};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn authorization_data_round_trips_through_files() -> Result<()> {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let fvk = sk.full_viewing_key();
+        let (address, _) = fvk.payment_address(0u64.into());
+        let value = Value {
+            amount: 1_000_000u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        let plan = TransactionPlan {
+            actions: vec![
+                ActionPlan::Spend(SpendPlan::new(
+                    &mut OsRng,
+                    Note::generate(&mut OsRng, &address, value),
+                    0u64.into(),
+                )),
+                ActionPlan::Output(OutputPlan::new(&mut OsRng, value, address)),
+            ],
+            chain_id: "penumbra-testnet".to_string(),
+            ..Default::default()
+        };
+
+        let dir = tempfile::tempdir()?;
+        let dir = Utf8Path::from_path(dir.path()).expect("temporary directory path is UTF-8");
+        let plan_path = dir.join("plan.json");
+        let auth_path = dir.join("auth.json");
+
+        write_plan(&plan_path, &plan)?;
+        // Plan files are never overwritten.
+        assert!(write_plan(&plan_path, &plan).is_err());
+        let plan = read_plan(&plan_path)?;
+
+        write_auth_data(&auth_path, plan.authorize(OsRng, &sk))?;
+        let auth_data = read_auth_data(&auth_path)?;
+
+        // The authorization data read back must still authorize the plan.
+        let effect_hash = plan.effect_hash(fvk);
+        assert_eq!(auth_data.effect_hash, effect_hash);
+        assert_eq!(auth_data.spend_auths.len(), 1);
+        for (spend_plan, auth_sig) in plan.spend_plans().zip(&auth_data.spend_auths) {
+            fvk.spend_verification_key()
+                .randomize(&spend_plan.randomizer)
+                .verify(effect_hash.as_ref(), auth_sig)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
     box_grpc_svc::{self, BoxGrpcService},
-    legacy, App, Command,
+    legacy,
+    passphrase::WalletFile,
+    watch_only::{WatchOnlyWallet, WATCH_ONLY_FILE_NAME},
+    App, Command,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
@@ -18,8 +21,7 @@ use penumbra_proto::{
         view_protocol_service_server::ViewProtocolServiceServer,
     },
 };
-use penumbra_view::ViewService;
-use penumbra_wallet::KeyStore;
+use penumbra_view::{SyncMode, ViewService};
 use std::net::SocketAddr;
 use tracing_subscriber::EnvFilter;
 use url::Url;
@@ -51,6 +53,17 @@ pub struct Opt {
     /// If set, use a remote view service instead of local synchronization.
     #[clap(short, long, env = "PENUMBRA_VIEW_ADDRESS")]
     view_address: Option<SocketAddr>,
+    /// If set, use a remote custody service to authorize transactions, instead
+    /// of the spend key in the local wallet.
+    #[clap(long, env = "PENUMBRA_CUSTODY_ADDRESS")]
+    custody_address: Option<SocketAddr>,
+    /// If set, delegate note detection to this detection server, and only
+    /// download and scan the transactions it flags.
+    #[clap(long, env = "PENUMBRA_DETECTION_SERVER")]
+    detection_server: Option<Url>,
+    /// The number of accounts to detect notes for, when using a detection server.
+    #[clap(long, default_value_t = 1)]
+    detection_accounts: u32,
     /// The filter for `pcli`'s log messages.
     #[clap( long, default_value_t = EnvFilter::new("warn"), env = "RUST_LOG")]
     trace_filter: EnvFilter,
@@ -75,21 +88,64 @@ impl Opt {
             legacy::migrate(&legacy_wallet_path, custody_path.as_path())?;
         }
 
-        // Build the custody service...
-        let wallet = KeyStore::load(custody_path)?;
-        let soft_kms = SoftKms::new(wallet.spend_key.clone().into());
-        let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
-        let custody = CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc));
+        // Load the wallet, which is either a full wallet holding spend keys, or
+        // a watch-only wallet holding only a full viewing key...
+        let watch_only_path = self.data_path.join(WATCH_ONLY_FILE_NAME);
+        let (wallet, fvks) = if custody_path.exists() {
+            let wallet = WalletFile::load(custody_path)?.wallet;
+            let fvks = wallet
+                .accounts()
+                .iter()
+                .map(|account| account.spend_key.full_viewing_key().clone())
+                .collect::<Vec<_>>();
+            (Some(wallet), fvks)
+        } else if watch_only_path.exists() {
+            let watch_only = WatchOnlyWallet::load(watch_only_path)?;
+            (None, vec![watch_only.full_viewing_key])
+        } else {
+            return Err(anyhow!(
+                "No wallet found in {}, generate or import one with `pcli keys`",
+                self.data_path
+            ));
+        };
+        let fvk = match &wallet {
+            Some(wallet) => wallet.spend_key().full_viewing_key().clone(),
+            None => fvks[0].clone(),
+        };
 
-        let fvk = wallet.spend_key.full_viewing_key().clone();
+        // ...then build the custody service...
+        let custody = if let Some(address) = self.custody_address {
+            // Use a remote custody service.
+            tracing::info!(%address, "using remote custody service");
 
-        // ...and the view service...
+            let ep = tonic::transport::Endpoint::new(format!("http://{}", address))?;
+            Some(CustodyProtocolServiceClient::new(
+                box_grpc_svc::connect(ep).await?,
+            ))
+        } else if let Some(wallet) = &wallet {
+            // Use an in-memory custody service holding the wallet's spend key.
+            let soft_kms = SoftKms::new(wallet.spend_key().clone().into())?;
+            let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
+            Some(CustodyProtocolServiceClient::new(box_grpc_svc::local(
+                custody_svc,
+            )))
+        } else {
+            // A watch-only wallet can still view, but not authorize transactions.
+            None
+        };
+
+        // ...and the view service, which tracks every account in the wallet...
         let view = if !self.cmd.offline() {
-            Some(self.view_client(&fvk).await?)
+            Some(self.view_client(&fvks).await?)
         } else {
             None
         };
 
+        let plan_only = match &self.cmd {
+            Command::Transaction(args) => args.plan_only.clone(),
+            _ => None,
+        };
+
         let mut pd_url = format!("http://{}", self.node)
             .parse::<Url>()
             .with_context(|| format!("Invalid node URL: {}", self.node))?;
@@ -102,15 +158,27 @@ impl Opt {
             custody,
             fvk,
             wallet,
+            plan_only,
             pd_url,
         };
         Ok((app, self.cmd))
     }
 
+    /// The [`SyncMode`] for a local view service, based on the command-line options.
+    fn sync_mode(&self) -> SyncMode {
+        match &self.detection_server {
+            Some(url) => SyncMode::Detection {
+                url: url.to_string(),
+                num_accounts: self.detection_accounts,
+            },
+            None => SyncMode::TrialDecryption,
+        }
+    }
+
     /// Constructs a [`ViewProtocolServiceClient`] based on the command-line options.
     async fn view_client(
         &self,
-        fvk: &FullViewingKey,
+        fvks: &[FullViewingKey],
     ) -> Result<ViewProtocolServiceClient<BoxGrpcService>> {
         let svc = if let Some(address) = self.view_address {
             // Use a remote view service.
@@ -123,9 +191,14 @@ impl Opt {
             let path = self.data_path.join(crate::VIEW_FILE_NAME);
             tracing::info!(%path, "using local view service");
 
-            let svc =
-                ViewService::load_or_initialize(path, fvk, self.node.to_string(), self.pd_port)
-                    .await?;
+            let svc = ViewService::load_or_initialize(
+                path,
+                fvks,
+                self.node.to_string(),
+                self.pd_port,
+                self.sync_mode(),
+            )
+            .await?;
 
             // Now build the view and custody clients, doing gRPC with ourselves
             let svc = ViewProtocolServiceServer::new(svc);
@@ -0,0 +1,87 @@
+use anyhow::{anyhow, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use penumbra_wallet::KeyStore;
+
+/// The environment variable used to supply the wallet passphrase non-interactively.
+pub const PASSPHRASE_ENV_VAR: &str = "PCLI_PASSPHRASE";
+
+/// Read the wallet passphrase, from the environment if it's set there, or by
+/// prompting the user otherwise.
+pub fn read_passphrase() -> Result<String> {
+    match std::env::var(PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) => Ok(passphrase),
+        Err(_) => Ok(rpassword::prompt_password("Enter wallet passphrase: ")?),
+    }
+}
+
+/// Read a passphrase for a new wallet, from the environment if it's set there,
+/// or by prompting the user otherwise.
+pub fn read_new_passphrase() -> Result<String> {
+    match std::env::var(PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) if !passphrase.is_empty() => Ok(passphrase),
+        Ok(_) => Err(anyhow!("{} must not be empty", PASSPHRASE_ENV_VAR)),
+        Err(_) => prompt_new_passphrase(),
+    }
+}
+
+/// Prompt the user for a new passphrase, asking for it twice to catch typos.
+pub fn prompt_new_passphrase() -> Result<String> {
+    let passphrase = rpassword::prompt_password("Enter new wallet passphrase: ")?;
+    if passphrase.is_empty() {
+        return Err(anyhow!("The passphrase must not be empty"));
+    }
+    let confirmation = rpassword::prompt_password("Confirm new wallet passphrase: ")?;
+    if passphrase != confirmation {
+        return Err(anyhow!("The passphrases do not match"));
+    }
+    Ok(passphrase)
+}
+
+/// A wallet file loaded from disk, remembering whether (and under which
+/// passphrase) it was encrypted, so that it can be written back the same way.
+pub struct WalletFile {
+    pub wallet: KeyStore,
+    path: Utf8PathBuf,
+    passphrase: Option<String>,
+}
+
+impl WalletFile {
+    /// Load the wallet at `path`, asking for the passphrase if it's encrypted.
+    pub fn load(path: impl AsRef<Utf8Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        if KeyStore::is_encrypted(&path)? {
+            let passphrase = read_passphrase()?;
+            let wallet = KeyStore::load_encrypted(&path, &passphrase)?;
+            Ok(Self {
+                wallet,
+                path,
+                passphrase: Some(passphrase),
+            })
+        } else {
+            let wallet = KeyStore::load(&path)?;
+            Ok(Self {
+                wallet,
+                path,
+                passphrase: None,
+            })
+        }
+    }
+
+    /// Change the passphrase the wallet will be encrypted under when it's saved.
+    pub fn set_passphrase(&mut self, passphrase: String) {
+        self.passphrase = Some(passphrase);
+    }
+
+    /// The passphrase the wallet will be encrypted under when it's saved, if any.
+    pub fn passphrase(&self) -> Option<&str> {
+        self.passphrase.as_deref()
+    }
+
+    /// Write the wallet back to the file it was loaded from.
+    pub fn save(&self) -> Result<()> {
+        match &self.passphrase {
+            Some(passphrase) => self.wallet.update_encrypted(&self.path, passphrase),
+            None => self.wallet.update(&self.path),
+        }
+    }
+}
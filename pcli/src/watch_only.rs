@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use camino::Utf8Path;
+use penumbra_crypto::FullViewingKey;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// The path to the watch-only wallet file, used instead of the custody file
+/// when `pcli` holds only a full viewing key.
+pub const WATCH_ONLY_FILE_NAME: &str = "watch-only.json";
+
+/// A wallet holding a full viewing key but no spend key.
+///
+/// Transactions planned with a watch-only wallet must be authorized by a
+/// remote custody service.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchOnlyWallet {
+    #[serde_as(as = "DisplayFromStr")]
+    pub full_viewing_key: FullViewingKey,
+}
+
+impl WatchOnlyWallet {
+    /// Create a watch-only wallet from the string encoding of a full viewing key.
+    pub fn from_fvk_str(full_viewing_key: &str) -> Result<Self> {
+        Ok(Self {
+            full_viewing_key: FullViewingKey::from_str(full_viewing_key)
+                .map_err(|_| anyhow!("The provided string is not a valid full viewing key"))?,
+        })
+    }
+
+    /// Write the watch-only wallet to the provided path.
+    pub fn save(&self, path: impl AsRef<Utf8Path>) -> Result<()> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(anyhow!(
+                "Wallet file already exists at {}, refusing to overwrite it",
+                path
+            ));
+        }
+        std::fs::write(path, serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// Read the watch-only wallet from the provided path.
+    pub fn load(path: impl AsRef<Utf8Path>) -> Result<Self> {
+        serde_json::from_slice(std::fs::read(path.as_ref())?.as_slice()).map_err(Into::into)
+    }
+}
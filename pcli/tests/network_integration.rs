@@ -42,6 +42,7 @@ fn load_wallet_into_tmpdir() -> TempDir {
             "import",
             "phrase",
             SEED_PHRASE,
+            "--unencrypted",
         ])
         .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
     setup_cmd
@@ -54,6 +55,41 @@ fn load_wallet_into_tmpdir() -> TempDir {
 /// Look up a currently active validator on the testnet.
 /// Will return the most bonded, which means the Penumbra Labs CI validator.
 fn get_validator() -> String {
+    // We retrieve the first match, which results in most trusted.
+    get_validators().swap_remove(0)
+}
+
+/// Get the wallet's total balance of the delegation token for `validator`,
+/// in base units.
+fn get_delegation_balance(tmpdir: &TempDir, validator: &str) -> u64 {
+    let mut balance_cmd = Command::cargo_bin("pcli").unwrap();
+    balance_cmd
+        .args([
+            "--data-path",
+            tmpdir.path().to_str().unwrap(),
+            "view",
+            "balance",
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    let stdout_vec = balance_cmd.unwrap().stdout;
+
+    // Balances are displayed in the best unit for their amount.
+    let balance_regex = Regex::new(&format!(r"([0-9.]+)(m|u)?delegation_{}", validator)).unwrap();
+    balance_regex
+        .captures_iter(std::str::from_utf8(&stdout_vec).unwrap())
+        .map(|captures| {
+            let amount: f64 = captures[1].parse().unwrap();
+            let scale = match captures.get(2).map(|unit| unit.as_str()) {
+                Some("u") => 1.0,
+                Some("m") => 1_000.0,
+                _ => 1_000_000.0,
+            };
+            (amount * scale).round() as u64
+        })
+        .sum()
+}
+
+fn get_validators() -> Vec<String> {
     let tmpdir = load_wallet_into_tmpdir();
     let mut validator_cmd = Command::cargo_bin("pcli").unwrap();
     validator_cmd
@@ -67,13 +103,15 @@ fn get_validator() -> String {
         .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
     validator_cmd.assert().success();
 
-    // Pull out one of the validators from stdout.
+    // Pull out all of the validators from stdout, in order.
     let stdout_vec = validator_cmd.unwrap().stdout;
     let validator_regex = Regex::new(r"penumbravalid1\w{58}").unwrap();
-    let captures = validator_regex.captures(std::str::from_utf8(&stdout_vec).unwrap());
-
-    // We retrieve the first match via index 0, which results in most trusted.
-    captures.unwrap()[0].to_string()
+    let validators = validator_regex
+        .find_iter(std::str::from_utf8(&stdout_vec).unwrap())
+        .map(|m| m.as_str().to_string())
+        .collect::<Vec<_>>();
+    assert!(!validators.is_empty(), "no validators found");
+    validators
 }
 
 #[ignore]
@@ -243,6 +281,105 @@ fn delegate_and_undelegate() {
     sync_cmd.assert().success();
 }
 
+#[ignore]
+#[test]
+fn redelegate() {
+    let tmpdir = load_wallet_into_tmpdir();
+
+    // Get two validators from the testnet.
+    let validators = get_validators();
+    assert!(
+        validators.len() >= 2,
+        "redelegation test requires at least two validators"
+    );
+    let (from, to) = (&validators[0], &validators[1]);
+
+    // Delegate a tiny bit of penumbra to the first validator.
+    let mut delegate_cmd = Command::cargo_bin("pcli").unwrap();
+    delegate_cmd
+        .args([
+            "--data-path",
+            tmpdir.path().to_str().unwrap(),
+            "tx",
+            "delegate",
+            "1penumbra",
+            "--to",
+            from.as_str(),
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    delegate_cmd.assert().success();
+
+    // Wait for a couple blocks for the transaction to be confirmed.
+    let block_time = time::Duration::from_secs(2 * BLOCK_TIME_SECONDS);
+    thread::sleep(block_time);
+
+    let from_balance = get_delegation_balance(&tmpdir, from);
+    assert!(from_balance > 0, "delegation to {} was not confirmed", from);
+
+    // Now redelegate to the second validator. We attempt `num_attempts` times in case an
+    // epoch boundary passes while we prepare the redelegation. See issue #1522.
+    let num_attempts = 3;
+    let mut redelegated = false;
+    for _ in 0..num_attempts {
+        let mut redelegate_cmd = Command::cargo_bin("pcli").unwrap();
+        redelegate_cmd
+            .args([
+                "--data-path",
+                tmpdir.path().to_str().unwrap(),
+                "tx",
+                "redelegate",
+                "0.5penumbra",
+                "--from",
+                from.as_str(),
+                "--to",
+                to.as_str(),
+            ])
+            .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+        let redelegation_result = redelegate_cmd.assert().try_success();
+
+        // If the redelegation command succeeded, we can exit this loop.
+        if redelegation_result.is_ok() {
+            redelegated = true;
+            break;
+        }
+
+        // Wait for a couple blocks for the transaction to be confirmed.
+        let block_time = time::Duration::from_secs(2 * BLOCK_TIME_SECONDS);
+        thread::sleep(block_time);
+    }
+
+    assert!(
+        redelegated,
+        "redelegation failed after {} attempts",
+        num_attempts
+    );
+
+    // Wait for a couple blocks for the transaction to be confirmed.
+    let block_time = time::Duration::from_secs(2 * BLOCK_TIME_SECONDS);
+    thread::sleep(block_time);
+
+    // Check that the redelegated stake left the first validator.
+    assert!(
+        get_delegation_balance(&tmpdir, from) < from_balance,
+        "balance of delegation tokens for {} did not decrease",
+        from
+    );
+
+    // Check we have some of the delegation token for the second validator now.
+    let mut balance_cmd = Command::cargo_bin("pcli").unwrap();
+    balance_cmd
+        .args([
+            "--data-path",
+            tmpdir.path().to_str().unwrap(),
+            "view",
+            "balance",
+        ])
+        .timeout(std::time::Duration::from_secs(TIMEOUT_COMMAND_SECONDS));
+    balance_cmd
+        .assert()
+        .stdout(predicate::str::is_match(to.as_str()).unwrap());
+}
+
 #[ignore]
 #[test]
 fn swap() {
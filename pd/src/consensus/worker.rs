@@ -13,6 +13,8 @@ pub struct Worker {
     queue: mpsc::Receiver<Message>,
     storage: Storage,
     app: App,
+    /// The version of the storage the app is currently built on.
+    app_version: u64,
 }
 
 fn trace_events(events: &[abci::Event]) {
@@ -29,12 +31,15 @@ fn trace_events(events: &[abci::Event]) {
 impl Worker {
     #[instrument(skip(storage, queue), name = "consensus::Worker::new")]
     pub async fn new(storage: Storage, queue: mpsc::Receiver<Message>) -> Result<Self> {
-        let app = App::new(storage.latest_snapshot());
+        let snapshot = storage.latest_snapshot();
+        let app_version = snapshot.version();
+        let app = App::new(snapshot);
 
         Ok(Self {
             queue,
             storage,
             app,
+            app_version,
         })
     }
 
@@ -110,6 +115,7 @@ impl Worker {
 
         // Note: App::commit resets internal components, so we don't need to do that ourselves.
         let app_hash = self.app.commit(self.storage.clone()).await;
+        self.app_version = self.storage.latest_version();
 
         tracing::info!(
             consensus_params = ?init_chain.consensus_params,
@@ -132,6 +138,17 @@ impl Worker {
         // We don't need to print the block height, because it will already be
         // included in the span modeling the abci request handling.
         tracing::info!(time = ?begin_block.header.time, "beginning block");
+
+        // If this node was bootstrapped using state sync, the storage was
+        // restored from a snapshot after the app was constructed, so we need
+        // to rebuild the app on top of the restored state.
+        if self.storage.latest_version() != self.app_version {
+            let snapshot = self.storage.latest_snapshot();
+            tracing::info!(version = ?snapshot.version(), "rebuilding app over restored state");
+            self.app_version = snapshot.version();
+            self.app = App::new(snapshot);
+        }
+
         let events = self.app.begin_block(&begin_block).await;
         Ok(abci::response::BeginBlock { events })
     }
@@ -191,6 +208,7 @@ impl Worker {
 
     async fn commit(&mut self) -> Result<abci::response::Commit> {
         let app_hash = self.app.commit(self.storage.clone()).await;
+        self.app_version = self.storage.latest_version();
         tracing::info!(?app_hash, "committed block");
 
         Ok(abci::response::Commit {
@@ -0,0 +1,272 @@
+use std::pin::Pin;
+
+use futures::stream::StreamExt;
+use penumbra_chain::StateReadExt as _;
+use penumbra_crypto::fmd::DetectionKey;
+use penumbra_proto::{
+    client::v1alpha1::{
+        detection_service_server::DetectionService, DetectedTransactionsRequest,
+        DetectedTransactionsResponse,
+    },
+    DomainType,
+};
+use penumbra_storage::Storage;
+use penumbra_transaction::Transaction;
+use tendermint_rpc::{Client, HttpClient};
+use tokio::sync::mpsc;
+use tonic::Status;
+use tracing::{instrument, Instrument};
+
+/// Implements the detection service, which examines the clues of each
+/// transaction on behalf of light clients.
+///
+/// Transactions are fetched from the upstream Tendermint node, since their
+/// clues are not part of the chain state.
+#[derive(Clone, Debug)]
+pub struct Detection {
+    /// Storage interface, used to follow the chain height.
+    storage: Storage,
+    /// Address of the upstream Tendermint server to fetch blocks from.
+    tendermint_url: url::Url,
+}
+
+impl Detection {
+    pub fn new(storage: Storage, tendermint_url: url::Url) -> Self {
+        Self {
+            storage,
+            tendermint_url,
+        }
+    }
+}
+
+/// Fetch the block at `height`, and return the transactions in it that are
+/// detected by [`detect_in_block`].
+async fn detect(
+    client: &HttpClient,
+    detection_keys: &[DetectionKey],
+    height: u64,
+) -> anyhow::Result<DetectedTransactionsResponse> {
+    // The genesis state has no corresponding Tendermint block.
+    if height == 0 {
+        return Ok(DetectedTransactionsResponse {
+            height,
+            transactions: Vec::new(),
+        });
+    }
+
+    let block = client
+        .block(tendermint::block::Height::try_from(height)?)
+        .await?
+        .block;
+
+    Ok(detect_in_block(detection_keys, height, block.data.iter()))
+}
+
+/// Return the transactions of the block at `height` with a clue that matches
+/// at least one of the `detection_keys`.
+///
+/// Transactions with IBC actions are always returned, because inbound
+/// transfers and refunds mint notes that have no clues. Transactions that
+/// can't be decoded are logged and skipped.
+fn detect_in_block(
+    detection_keys: &[DetectionKey],
+    height: u64,
+    block_data: impl IntoIterator<Item = impl AsRef<[u8]>>,
+) -> DetectedTransactionsResponse {
+    let mut transactions = Vec::new();
+    for (index, tx_bytes) in block_data.into_iter().enumerate() {
+        // A transaction that can't be decoded can't match any detection key,
+        // so skip it rather than failing the whole stream.
+        let transaction = match Transaction::decode(tx_bytes.as_ref()) {
+            Ok(transaction) => transaction,
+            Err(error) => {
+                tracing::warn!(%height, %index, ?error, "skipping undecodable transaction");
+                continue;
+            }
+        };
+        let detected = transaction.ibc_actions().next().is_some()
+            || transaction.fmd_clues().iter().any(|clue| {
+                detection_keys
+                    .iter()
+                    .any(|detection_key| detection_key.examine(clue))
+            });
+        if detected {
+            transactions.push(transaction.into());
+        }
+    }
+
+    DetectedTransactionsResponse {
+        height,
+        transactions,
+    }
+}
+
+#[tonic::async_trait]
+impl DetectionService for Detection {
+    type DetectedTransactionsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<DetectedTransactionsResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn detected_transactions(
+        &self,
+        request: tonic::Request<DetectedTransactionsRequest>,
+    ) -> Result<tonic::Response<Self::DetectedTransactionsStream>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        snapshot
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {}", e)))?;
+
+        let DetectedTransactionsRequest {
+            start_height,
+            end_height,
+            keep_alive,
+            detection_keys,
+            ..
+        } = request.into_inner();
+
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|bytes| {
+                let bytes: [u8; 32] = bytes
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("detection key must be 32 bytes"))?;
+                Ok(DetectionKey::from_bytes(bytes)?)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("invalid detection key: {:#}", e))
+            })?;
+        if detection_keys.is_empty() {
+            return Err(tonic::Status::invalid_argument(
+                "at least one detection key is required",
+            ));
+        }
+
+        let current_height = snapshot.get_block_height().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting block height: {}", e))
+        })?;
+
+        // Treat end_height = 0 as end_height = current_height, as for compact
+        // block ranges.
+        let end_height = if end_height == 0 {
+            current_height
+        } else {
+            std::cmp::min(end_height, current_height)
+        };
+
+        // generic bounds on HttpClient::new are not well-constructed, so we have to
+        // render the URL as a String, then borrow it, then re-parse the borrowed &str
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).map_err(|e| {
+            tonic::Status::unavailable(format!("error connecting to tendermint: {}", e))
+        })?;
+        let mut state_rx = self.storage.subscribe();
+
+        let (tx, rx) = mpsc::channel(10);
+        let txerr = tx.clone();
+        tokio::spawn(
+            async move {
+                let result = async {
+                    // Phase 1: catch up from the start height.
+                    for height in start_height..=end_height {
+                        tx.send(Ok(detect(&client, &detection_keys, height).await?))
+                            .await?;
+                    }
+
+                    // If the client didn't request a keep-alive, we're done.
+                    if !keep_alive {
+                        return Ok::<(), anyhow::Error>(());
+                    }
+
+                    // Phase 2: examine each new block as it's created.  Several
+                    // blocks may have been created since we were last notified,
+                    // so keep track of the next height to send.
+                    let mut next_height = end_height + 1;
+                    loop {
+                        let current_height = state_rx.borrow_and_update().version();
+                        for height in next_height..=current_height {
+                            tx.send(Ok(detect(&client, &detection_keys, height).await?))
+                                .await?;
+                        }
+                        next_height = next_height.max(current_height + 1);
+                        state_rx.changed().await?;
+                    }
+                }
+                .await;
+
+                if let Err(e) = result {
+                    // Notify the client of the error before the task exits.
+                    let _ = txerr
+                        .send(Err(tonic::Status::internal(e.to_string())))
+                        .await;
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(tonic::Response::new(
+            tokio_stream::wrappers::ReceiverStream::new(rx).boxed(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::fmd::ClueKey;
+    use penumbra_proto::core::ibc::v1alpha1::IbcAction;
+    use penumbra_transaction::{Action, TransactionBody};
+    use prost::Message as _;
+
+    use super::*;
+
+    /// A transaction with a clue for each of the `clue_keys`.
+    fn transaction_with_clues(clue_keys: &[ClueKey], actions: Vec<Action>) -> Vec<u8> {
+        let fmd_clues = clue_keys
+            .iter()
+            .enumerate()
+            .map(|(i, clue_key)| {
+                clue_key
+                    .expand()
+                    .unwrap()
+                    .create_clue_deterministic(16, [i as u8; 32])
+                    .unwrap()
+            })
+            .collect();
+        Transaction {
+            transaction_body: TransactionBody {
+                actions,
+                fmd_clues,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn detect_flags_transactions_by_clue_and_all_ibc_transactions() {
+        let ours = DetectionKey::from_field(1u64.into());
+        let theirs = DetectionKey::from_field(2u64.into());
+
+        let block_data = vec![
+            // A transaction for us, among others.
+            transaction_with_clues(&[theirs.clue_key(), ours.clue_key()], Vec::new()),
+            // A transaction for someone else.
+            transaction_with_clues(&[theirs.clue_key()], Vec::new()),
+            // An IBC transaction, which mints notes without clues.
+            transaction_with_clues(&[], vec![Action::IBCAction(IbcAction { raw_action: None })]),
+            // Garbage that doesn't decode as a transaction.
+            b"not a transaction".to_vec(),
+        ];
+
+        let response = detect_in_block(&[ours], 7, &block_data);
+        assert_eq!(response.height, 7);
+        let detected = response
+            .transactions
+            .into_iter()
+            .map(|transaction| transaction.encode_to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(detected, vec![block_data[0].clone(), block_data[2].clone()]);
+    }
+}
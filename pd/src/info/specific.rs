@@ -5,16 +5,17 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use penumbra_chain::AppHashRead;
 use penumbra_chain::StateReadExt as _;
+use penumbra_component::dex::StateReadExt as _;
 use penumbra_component::shielded_pool::{StateReadExt as _, SupplyRead as _};
 use penumbra_component::stake::StateReadExt as _;
-use penumbra_component::stubdex::StateReadExt as _;
 use penumbra_crypto::asset::{self, Asset};
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
         specific_query_service_server::SpecificQueryService, AssetInfoRequest, AssetInfoResponse,
-        BatchSwapOutputDataRequest, KeyValueRequest, KeyValueResponse, StubCpmmReservesRequest,
-        ValidatorStatusRequest,
+        BatchSwapOutputDataRequest, FundingStreamPayoutsRequest, FundingStreamPayoutsResponse,
+        KeyValueRequest, KeyValueResponse, StubCpmmReservesRequest, SwapExecutionRequest,
+        SwapExecutionResponse, ValidatorStatusRequest,
     },
 };
 
@@ -282,32 +283,70 @@ impl SpecificQueryService for Info {
     }
 
     #[instrument(skip(self, request))]
-    /// Get the batch swap data associated with a given trading pair and height.
-    async fn stub_cpmm_reserves(
+    /// Get the execution of one direction of the batch swap in a trading pair at a given height.
+    async fn swap_execution(
         &self,
-        request: tonic::Request<StubCpmmReservesRequest>,
-    ) -> Result<tonic::Response<StubCpmmReservesResponse>, Status> {
+        request: tonic::Request<SwapExecutionRequest>,
+    ) -> Result<tonic::Response<SwapExecutionResponse>, Status> {
         let state = self.storage.latest_snapshot();
         let request_inner = request.into_inner();
+        let height = request_inner.height;
         let trading_pair = request_inner
             .trading_pair
             .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
             .try_into()
             .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
 
-        let cpmm_reserves = state
-            .stub_cpmm_reserves(&trading_pair)
+        let swap_execution = state
+            .swap_execution(height, &trading_pair)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?;
 
-        match cpmm_reserves {
-            Some(reserves) => Ok(tonic::Response::new(StubCpmmReservesResponse {
-                reserves: Some(reserves.into()),
-            })),
-            None => Err(Status::not_found("CPMM reserves not found")),
+        match swap_execution {
+            Some(swap_execution) => Ok(tonic::Response::new(swap_execution.into())),
+            None => Err(Status::not_found("swap execution not found")),
         }
     }
 
+    #[instrument(skip(self, request))]
+    /// Get the payouts of a validator's commission to its funding streams.
+    async fn funding_stream_payouts(
+        &self,
+        request: tonic::Request<FundingStreamPayoutsRequest>,
+    ) -> Result<tonic::Response<FundingStreamPayoutsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {}", e)))?;
+
+        let id = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let payouts = state.funding_stream_payouts(&id).await.map_err(|e| {
+            Status::unavailable(format!("error getting funding stream payouts: {}", e))
+        })?;
+
+        Ok(tonic::Response::new(FundingStreamPayoutsResponse {
+            payouts: payouts.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[instrument(skip(self, _request))]
+    /// The stub CPMM has been retired in favor of liquidity positions.
+    async fn stub_cpmm_reserves(
+        &self,
+        _request: tonic::Request<StubCpmmReservesRequest>,
+    ) -> Result<tonic::Response<StubCpmmReservesResponse>, Status> {
+        Err(Status::unimplemented(
+            "the stub CPMM has been retired in favor of liquidity positions",
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn next_validator_rate(
         &self,
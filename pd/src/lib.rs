@@ -2,6 +2,7 @@
 #![allow(clippy::clone_on_copy)]
 
 mod consensus;
+mod detection;
 mod info;
 mod mempool;
 mod metrics;
@@ -15,6 +16,7 @@ use request_ext::RequestExt;
 
 pub use crate::metrics::register_metrics;
 pub use consensus::Consensus;
+pub use detection::Detection;
 pub use info::Info;
 pub use mempool::Mempool;
 pub use penumbra_component::app::App;
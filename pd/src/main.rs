@@ -15,6 +15,7 @@ use pd::testnet::{
     generate::testnet_generate, get_testnet_dir, join::testnet_join, parse_tm_address,
 };
 use penumbra_proto::client::v1alpha1::{
+    detection_service_server::DetectionServiceServer,
     oblivious_query_service_server::ObliviousQueryServiceServer,
     specific_query_service_server::SpecificQueryServiceServer,
     tendermint_proxy_service_server::TendermintProxyServiceServer,
@@ -65,6 +66,14 @@ enum RootCommand {
         /// Proxy Tendermint requests against the gRPC server to this address.
         #[clap(short, long, default_value = "http://127.0.0.1:26657")]
         tendermint_addr: url::Url,
+        /// Export a state sync snapshot every this many blocks. If 0, no
+        /// snapshots are exported, but the node can still restore from
+        /// snapshots served by its peers.
+        #[clap(long, default_value = "0")]
+        snapshot_interval: u64,
+        /// The number of recent state sync snapshots to keep on disk.
+        #[clap(long, default_value = "2")]
+        snapshot_keep_recent: usize,
     },
     /// Generate, join, or reset a testnet.
     Testnet {
@@ -98,6 +107,9 @@ enum TestnetCommand {
         /// Path to CSV file containing initial allocations [default: latest testnet].
         #[clap(long, parse(from_os_str))]
         allocations_input_file: Option<PathBuf>,
+        /// Number of penumbra tokens deposited into the DAO at genesis.
+        #[clap(long, default_value = "1000000")]
+        dao_allocation: u64,
         /// Path to JSON file containing initial validator configs [default: latest testnet].
         #[clap(long, parse(from_os_str))]
         validators_input_file: Option<PathBuf>,
@@ -177,12 +189,17 @@ async fn main() -> anyhow::Result<()> {
             grpc_port,
             metrics_port,
             tendermint_addr,
+            snapshot_interval,
+            snapshot_keep_recent,
         } => {
             tracing::info!(?host, ?abci_port, ?grpc_port, "starting pd");
 
             let mut rocks_path = home.clone();
             rocks_path.push("rocksdb");
 
+            let mut snapshot_path = home.clone();
+            snapshot_path.push("snapshots");
+
             let storage = Storage::load(rocks_path)
                 .await
                 .context("Unable to initialize RocksDB storage")?;
@@ -190,8 +207,14 @@ async fn main() -> anyhow::Result<()> {
             let consensus = pd::Consensus::new(storage.clone()).await?;
             let mempool = pd::Mempool::new(storage.clone()).await?;
             let info = pd::Info::new(storage.clone());
-            let tm_proxy = pd::TendermintProxy::new(tendermint_addr);
-            let snapshot = pd::Snapshot {};
+            let tm_proxy = pd::TendermintProxy::new(tendermint_addr.clone());
+            let detection = pd::Detection::new(storage.clone(), tendermint_addr);
+            let snapshot = pd::Snapshot::new(
+                storage.clone(),
+                snapshot_path,
+                snapshot_interval,
+                snapshot_keep_recent,
+            );
 
             let abci_server = tokio::task::Builder::new()
                 .name("abci_server")
@@ -229,6 +252,7 @@ async fn main() -> anyhow::Result<()> {
                         .add_service(tonic_web::enable(TendermintProxyServiceServer::new(
                             tm_proxy.clone(),
                         )))
+                        .add_service(tonic_web::enable(DetectionServiceServer::new(detection)))
                         .serve(
                             format!("{}:{}", host, grpc_port)
                                 .parse()
@@ -331,6 +355,7 @@ async fn main() -> anyhow::Result<()> {
                     unbonding_epochs,
                     active_validator_limit,
                     allocations_input_file,
+                    dao_allocation,
                     validators_input_file,
                     chain_id,
                     preserve_chain_id,
@@ -368,6 +393,7 @@ async fn main() -> anyhow::Result<()> {
                 starting_ip,
                 validators_input_file,
                 allocations_input_file,
+                dao_allocation,
             )?;
         }
     }
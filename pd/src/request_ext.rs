@@ -1,6 +1,9 @@
 use sha2::{Digest, Sha256};
 use tendermint::abci::{
-    request::{BeginBlock, CheckTx, DeliverTx, EndBlock, InitChain, Query},
+    request::{
+        ApplySnapshotChunk, BeginBlock, CheckTx, DeliverTx, EndBlock, InitChain, LoadSnapshotChunk,
+        OfferSnapshot, Query,
+    },
     ConsensusRequest, InfoRequest, MempoolRequest, Request, SnapshotRequest,
 };
 use tracing::error_span;
@@ -71,9 +74,15 @@ impl RequestExt for SnapshotRequest {
         let p = error_span!("abci");
         match self {
             SnapshotRequest::ListSnapshots => error_span!(parent: &p, "ListSnapshots"),
-            SnapshotRequest::OfferSnapshot(_) => error_span!(parent: &p, "OfferSnapshot"),
-            SnapshotRequest::LoadSnapshotChunk(_) => error_span!(parent: &p, "LoadSnapshotChunk"),
-            SnapshotRequest::ApplySnapshotChunk(_) => error_span!(parent: &p, "ApplySnapshotChunk"),
+            SnapshotRequest::OfferSnapshot(OfferSnapshot { snapshot, .. }) => {
+                error_span!(parent: &p, "OfferSnapshot", height = ?snapshot.height.value())
+            }
+            SnapshotRequest::LoadSnapshotChunk(LoadSnapshotChunk { height, chunk, .. }) => {
+                error_span!(parent: &p, "LoadSnapshotChunk", height = ?height.value(), chunk)
+            }
+            SnapshotRequest::ApplySnapshotChunk(ApplySnapshotChunk { index, .. }) => {
+                error_span!(parent: &p, "ApplySnapshotChunk", index)
+            }
         }
     }
 }
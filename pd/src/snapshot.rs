@@ -1,15 +1,311 @@
+//! Support for ABCI state sync, allowing new nodes to bootstrap from a recent
+//! snapshot of the chain state, rather than replaying every block from genesis.
+//!
+//! Snapshots are exported periodically from the [`Storage`] into a local
+//! [`SnapshotStore`], and served to peers over ABCI.  A fresh node stages
+//! the chunks it is offered in its [`Storage`] as they arrive, then rebuilds
+//! the JMT and checks its root against the app hash provided by Tendermint
+//! before committing the restored state.
 use std::{
     future::Future,
+    path::PathBuf,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
-use futures::FutureExt;
-use tendermint::abci::{SnapshotRequest, SnapshotResponse};
+use anyhow::Result;
+use futures::{FutureExt, StreamExt};
+use penumbra_chain::AppHash;
+use penumbra_storage::Storage;
+use tendermint::abci::{
+    request, response, response::ApplySnapshotChunkResult, types, SnapshotRequest, SnapshotResponse,
+};
+use tokio::sync::Mutex;
 use tower_abci::BoxError;
+use tracing::Instrument;
+
+use crate::RequestExt;
+
+mod format;
+mod store;
+
+use format::{ChunkBuilder, Metadata, FORMAT};
+use store::{Manifest, SnapshotStore};
 
+/// Implements the ABCI snapshot service.
 #[derive(Clone, Debug)]
-pub struct Snapshot {}
+pub struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    /// The snapshot currently being restored, if any.
+    restoring: Arc<Mutex<Option<Restoring>>>,
+}
+
+/// The state of a snapshot being restored from chunks sent by peers.
+#[derive(Debug)]
+struct Restoring {
+    height: u64,
+    /// The app hash at `height`, provided by Tendermint from a verified header.
+    app_hash: Vec<u8>,
+    metadata: Metadata,
+    /// Whether each chunk has been received and staged.
+    staged: Vec<bool>,
+}
+
+impl Snapshot {
+    /// Creates a new snapshot service, storing snapshots in `dir`.
+    ///
+    /// If `interval` is nonzero, this also spawns a task exporting a snapshot
+    /// every `interval` blocks, keeping the `keep_recent` most recent snapshots.
+    pub fn new(storage: Storage, dir: PathBuf, interval: u64, keep_recent: usize) -> Self {
+        let store = SnapshotStore::new(dir);
+
+        if interval > 0 {
+            tokio::task::Builder::new()
+                .name("snapshot::Exporter")
+                .spawn(export_snapshots(
+                    storage.clone(),
+                    store.clone(),
+                    interval,
+                    keep_recent,
+                ))
+                .expect("failed to spawn snapshot exporter");
+        }
+
+        Self {
+            storage,
+            store,
+            restoring: Default::default(),
+        }
+    }
+
+    async fn list_snapshots(&self) -> Result<response::ListSnapshots> {
+        let snapshots = self
+            .store
+            .list()
+            .await?
+            .into_iter()
+            .map(abci_snapshot)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(response::ListSnapshots { snapshots })
+    }
+
+    async fn load_snapshot_chunk(
+        &self,
+        load: request::LoadSnapshotChunk,
+    ) -> Result<response::LoadSnapshotChunk> {
+        let chunk = self
+            .store
+            .load_chunk(load.height.value(), load.format, load.chunk)
+            .await?;
+
+        Ok(response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        })
+    }
+
+    async fn offer_snapshot(&self, offer: request::OfferSnapshot) -> response::OfferSnapshot {
+        let snapshot = offer.snapshot;
+        tracing::info!(height = ?snapshot.height, format = snapshot.format, chunks = snapshot.chunks, "offered snapshot");
+
+        if snapshot.format != FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+
+        // We can only restore into an empty storage; if we have any state,
+        // Tendermint shouldn't be offering us snapshots at all.
+        if self.storage.latest_version() != u64::MAX {
+            tracing::warn!("rejecting snapshot, storage is already initialized");
+            return response::OfferSnapshot::Abort;
+        }
+
+        let metadata = match Metadata::decode(snapshot.metadata.as_ref()) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                tracing::warn!(?e, "rejecting snapshot with malformed metadata");
+                return response::OfferSnapshot::Reject;
+            }
+        };
+        if metadata.chunk_hashes.len() != snapshot.chunks as usize
+            || metadata.snapshot_hash()[..] != snapshot.hash[..]
+        {
+            tracing::warn!("rejecting snapshot with inconsistent metadata");
+            return response::OfferSnapshot::Reject;
+        }
+
+        // Discard anything staged for an earlier snapshot that wasn't restored.
+        if let Err(e) = self.storage.clear_restore().await {
+            tracing::error!(?e, "could not clear previously staged snapshot");
+            return response::OfferSnapshot::Abort;
+        }
+
+        *self.restoring.lock().await = Some(Restoring {
+            height: snapshot.height.value(),
+            app_hash: offer.app_hash.as_bytes().to_vec(),
+            staged: vec![false; metadata.chunk_hashes.len()],
+            metadata,
+        });
+
+        response::OfferSnapshot::Accept
+    }
+
+    async fn apply_snapshot_chunk(
+        &self,
+        apply: request::ApplySnapshotChunk,
+    ) -> Result<response::ApplySnapshotChunk> {
+        let mut restoring_guard = self.restoring.lock().await;
+        let restoring = match restoring_guard.as_mut() {
+            Some(restoring) => restoring,
+            None => {
+                tracing::warn!("received snapshot chunk without an accepted snapshot");
+                return Ok(apply_result(ApplySnapshotChunkResult::Abort));
+            }
+        };
+
+        let index = apply.index as usize;
+        let expected_hash = match restoring.metadata.chunk_hashes.get(index) {
+            Some(hash) => *hash,
+            None => {
+                tracing::warn!(index, "received out-of-range snapshot chunk");
+                *restoring_guard = None;
+                return Ok(apply_result(ApplySnapshotChunkResult::RejectSnapshot));
+            }
+        };
+
+        // Check the chunk against the metadata before accepting it, so that a
+        // bad chunk from one peer can be refetched from another.
+        if format::chunk_hash(apply.chunk.as_ref()) != expected_hash {
+            tracing::warn!(index, sender = ?apply.sender, "received snapshot chunk with bad hash");
+            return Ok(response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![apply.index],
+                reject_senders: vec![apply.sender],
+            });
+        }
+
+        // The chunk matches the snapshot's metadata, so if it can't be
+        // decoded, the snapshot itself is bad.
+        let entries = match format::decode_chunk(apply.chunk.as_ref()) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!(index, ?e, "rejecting snapshot with malformed chunk");
+                *restoring_guard = None;
+                self.storage.clear_restore().await?;
+                return Ok(apply_result(ApplySnapshotChunkResult::RejectSnapshot));
+            }
+        };
+        // Stage the chunk on disk, rather than holding the whole state in memory.
+        self.storage.stage_restore(entries).await?;
+        restoring.staged[index] = true;
+
+        if restoring.staged.iter().any(|staged| !staged) {
+            return Ok(apply_result(ApplySnapshotChunkResult::Accept));
+        }
+
+        // We've received every chunk, so we can attempt the restore. Whether
+        // or not it succeeds, this snapshot is no longer being restored.
+        let restoring = restoring_guard
+            .take()
+            .expect("restoring state was checked above");
+
+        let restore = self.storage.prepare_restore(restoring.height).await?;
+        let app_hash = AppHash::from(restore.root_hash());
+        if app_hash.0[..] != restoring.app_hash[..] {
+            tracing::warn!(
+                ?app_hash,
+                expected = ?hex::encode(&restoring.app_hash),
+                "rejecting snapshot, restored state does not match app hash"
+            );
+            self.storage.clear_restore().await?;
+            return Ok(apply_result(ApplySnapshotChunkResult::RejectSnapshot));
+        }
+
+        restore.commit().await?;
+        tracing::info!(
+            height = restoring.height,
+            ?app_hash,
+            "restored state from snapshot"
+        );
+
+        Ok(apply_result(ApplySnapshotChunkResult::Accept))
+    }
+}
+
+/// Converts a local snapshot's manifest into the ABCI representation of the snapshot.
+fn abci_snapshot(manifest: Manifest) -> Result<types::Snapshot> {
+    Ok(types::Snapshot {
+        height: manifest.height.try_into()?,
+        format: manifest.format,
+        chunks: manifest.metadata.chunk_hashes.len().try_into()?,
+        hash: manifest.metadata.snapshot_hash().to_vec().into(),
+        metadata: manifest.metadata.encode()?.into(),
+    })
+}
+
+fn apply_result(result: ApplySnapshotChunkResult) -> response::ApplySnapshotChunk {
+    response::ApplySnapshotChunk {
+        result,
+        refetch_chunks: Vec::new(),
+        reject_senders: Vec::new(),
+    }
+}
+
+/// Exports a snapshot of each committed version of the state whose height is a
+/// multiple of `interval`.
+async fn export_snapshots(
+    storage: Storage,
+    store: SnapshotStore,
+    interval: u64,
+    keep_recent: usize,
+) -> Result<()> {
+    let mut snapshot_rx = storage.subscribe();
+
+    while snapshot_rx.changed().await.is_ok() {
+        let snapshot = snapshot_rx.borrow().clone();
+        let height = snapshot.version();
+        if height % interval != 0 {
+            continue;
+        }
+
+        let span = tracing::error_span!("export_snapshot", height);
+        let result = async {
+            let start = std::time::Instant::now();
+            let mut writer = store.begin(height).await?;
+            let mut builder = ChunkBuilder::default();
+
+            let mut entries = snapshot.export();
+            while let Some(entry) = entries.next().await {
+                if let Some(chunk) = builder.push(entry?)? {
+                    writer.write_chunk(chunk).await?;
+                }
+            }
+            if let Some(chunk) = builder.finish()? {
+                writer.write_chunk(chunk).await?;
+            }
+
+            let manifest = writer.finish().await?;
+            tracing::info!(
+                chunks = manifest.metadata.chunk_hashes.len(),
+                elapsed = ?start.elapsed(),
+                "exported snapshot"
+            );
+
+            store.prune(keep_recent).await
+        }
+        .instrument(span)
+        .await;
+
+        // A failed export shouldn't take down the node, so we just log it and
+        // try again at the next interval.
+        if let Err(e) = result {
+            tracing::error!(?e, height, "failed to export snapshot");
+        }
+    }
+
+    Ok(())
+}
 
 impl tower_service::Service<SnapshotRequest> for Snapshot {
     type Response = SnapshotResponse;
@@ -22,17 +318,39 @@ impl tower_service::Service<SnapshotRequest> for Snapshot {
     }
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
-        // No-op, we don't implement snapshot support
+        let span = req.create_span();
+        let self2 = self.clone();
+
         use SnapshotRequest as Request;
         use SnapshotResponse as Response;
         async move {
             Ok(match req {
-                Request::ListSnapshots => Response::ListSnapshots(Default::default()),
-                Request::OfferSnapshot(_) => Response::OfferSnapshot(Default::default()),
-                Request::LoadSnapshotChunk(_) => Response::LoadSnapshotChunk(Default::default()),
-                Request::ApplySnapshotChunk(_) => Response::ApplySnapshotChunk(Default::default()),
+                Request::ListSnapshots => {
+                    Response::ListSnapshots(self2.list_snapshots().await.unwrap_or_else(|e| {
+                        tracing::error!(?e, "failed to list snapshots");
+                        Default::default()
+                    }))
+                }
+                Request::OfferSnapshot(offer) => {
+                    Response::OfferSnapshot(self2.offer_snapshot(offer).await)
+                }
+                Request::LoadSnapshotChunk(load) => Response::LoadSnapshotChunk(
+                    self2.load_snapshot_chunk(load).await.unwrap_or_else(|e| {
+                        tracing::error!(?e, "failed to load snapshot chunk");
+                        Default::default()
+                    }),
+                ),
+                Request::ApplySnapshotChunk(apply) => Response::ApplySnapshotChunk(
+                    self2.apply_snapshot_chunk(apply).await.unwrap_or_else(|e| {
+                        // If we can't stage or restore from a snapshot whose
+                        // chunks all matched its metadata, the snapshot itself is bad.
+                        tracing::error!(?e, "failed to restore snapshot");
+                        apply_result(ApplySnapshotChunkResult::RejectSnapshot)
+                    }),
+                ),
             })
         }
+        .instrument(span)
         .boxed()
     }
 }
@@ -0,0 +1,121 @@
+//! The on-the-wire format of state sync snapshots.
+
+use anyhow::Result;
+use penumbra_storage::ExportedEntry;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The snapshot format produced and accepted by this version of `pd`.
+///
+/// This should be incremented whenever the encoding of chunks or metadata changes.
+pub const FORMAT: u32 = 1;
+
+/// The target size of a single chunk, comfortably below Tendermint's 16 MiB
+/// limit on chunk messages.
+pub const TARGET_CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// A single entry of exported state, as encoded in a chunk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Entry {
+    Consensus { key: String, value: Vec<u8> },
+    Nonconsensus { key: Vec<u8>, value: Vec<u8> },
+}
+
+impl Entry {
+    /// The approximate encoded size of this entry, used to split chunks.
+    fn size(&self) -> usize {
+        match self {
+            Entry::Consensus { key, value } => key.len() + value.len(),
+            Entry::Nonconsensus { key, value } => key.len() + value.len(),
+        }
+    }
+}
+
+impl From<ExportedEntry> for Entry {
+    fn from(entry: ExportedEntry) -> Self {
+        match entry {
+            ExportedEntry::Consensus { key, value } => Entry::Consensus { key, value },
+            ExportedEntry::Nonconsensus { key, value } => Entry::Nonconsensus { key, value },
+        }
+    }
+}
+
+impl From<Entry> for ExportedEntry {
+    fn from(entry: Entry) -> Self {
+        match entry {
+            Entry::Consensus { key, value } => ExportedEntry::Consensus { key, value },
+            Entry::Nonconsensus { key, value } => ExportedEntry::Nonconsensus { key, value },
+        }
+    }
+}
+
+/// Accumulates exported entries into encoded chunks of roughly [`TARGET_CHUNK_SIZE`].
+#[derive(Default)]
+pub struct ChunkBuilder {
+    pending: Vec<Entry>,
+    pending_size: usize,
+}
+
+impl ChunkBuilder {
+    /// Adds an entry, returning a completed chunk if the target size was reached.
+    pub fn push(&mut self, entry: ExportedEntry) -> Result<Option<Vec<u8>>> {
+        let entry = Entry::from(entry);
+        self.pending_size += entry.size();
+        self.pending.push(entry);
+
+        if self.pending_size >= TARGET_CHUNK_SIZE {
+            self.finish()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Encodes any remaining entries into a final chunk.
+    pub fn finish(&mut self) -> Result<Option<Vec<u8>>> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let chunk = bincode::serialize(&self.pending)?;
+        self.pending.clear();
+        self.pending_size = 0;
+        Ok(Some(chunk))
+    }
+}
+
+/// Decodes the entries contained in a chunk.
+pub fn decode_chunk(chunk: &[u8]) -> Result<Vec<ExportedEntry>> {
+    let entries: Vec<Entry> = bincode::deserialize(chunk)?;
+    Ok(entries.into_iter().map(Into::into).collect())
+}
+
+/// Computes the hash of a chunk, used to check chunks received from peers.
+pub fn chunk_hash(chunk: &[u8]) -> [u8; 32] {
+    Sha256::digest(chunk).into()
+}
+
+/// The metadata describing a snapshot, sent to peers in the ABCI snapshot's
+/// `metadata` field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The hash of each chunk, in order.
+    pub chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl Metadata {
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+
+    /// The hash identifying the snapshot, committing to all of its chunks.
+    pub fn snapshot_hash(&self) -> [u8; 32] {
+        let mut h = Sha256::new();
+        for chunk_hash in &self.chunk_hashes {
+            h.update(chunk_hash);
+        }
+        h.finalize().into()
+    }
+}
@@ -0,0 +1,149 @@
+//! The local, on-disk store of exported state sync snapshots.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::format::{chunk_hash, Metadata, FORMAT};
+
+/// The file in each snapshot directory recording its [`Manifest`].
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// The contents of a snapshot's manifest file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub height: u64,
+    pub format: u32,
+    pub metadata: Metadata,
+}
+
+/// A directory of snapshots, laid out as `<dir>/<height>/{manifest.json, 0, 1, ...}`.
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// Lists the heights of all complete snapshots, in ascending order.
+    async fn heights(&self) -> Result<Vec<u64>> {
+        let mut heights = Vec::new();
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            // No snapshots have been written yet.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(heights),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            // Incomplete snapshots are written to `<height>.tmp`, which won't parse.
+            if let Some(height) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                heights.push(height);
+            }
+        }
+        heights.sort_unstable();
+        Ok(heights)
+    }
+
+    /// Lists the manifests of all complete snapshots, in ascending height order.
+    pub async fn list(&self) -> Result<Vec<Manifest>> {
+        let mut manifests = Vec::new();
+        for height in self.heights().await? {
+            let path = self.snapshot_dir(height).join(MANIFEST_FILE);
+            let manifest = tokio::fs::read(&path)
+                .await
+                .with_context(|| format!("could not read snapshot manifest {}", path.display()))?;
+            manifests.push(serde_json::from_slice(&manifest)?);
+        }
+        Ok(manifests)
+    }
+
+    /// Loads a single chunk of the snapshot at the given height.
+    pub async fn load_chunk(&self, height: u64, format: u32, index: u32) -> Result<Vec<u8>> {
+        if format != FORMAT {
+            return Err(anyhow::anyhow!("unsupported snapshot format {}", format));
+        }
+        let path = self.snapshot_dir(height).join(index.to_string());
+        tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("could not read snapshot chunk {}", path.display()))
+    }
+
+    /// Begins writing a new snapshot at the given height.
+    ///
+    /// The snapshot is written to a temporary directory and moved into place
+    /// by [`SnapshotWriter::finish`], so a partially written snapshot is never listed.
+    pub async fn begin(&self, height: u64) -> Result<SnapshotWriter> {
+        let tmp_dir = self.dir.join(format!("{}.tmp", height));
+        if tokio::fs::metadata(&tmp_dir).await.is_ok() {
+            tokio::fs::remove_dir_all(&tmp_dir).await?;
+        }
+        tokio::fs::create_dir_all(&tmp_dir).await?;
+
+        Ok(SnapshotWriter {
+            height,
+            tmp_dir,
+            final_dir: self.snapshot_dir(height),
+            chunk_hashes: Vec::new(),
+        })
+    }
+
+    /// Deletes all but the `keep_recent` most recent snapshots.
+    pub async fn prune(&self, keep_recent: usize) -> Result<()> {
+        let heights = self.heights().await?;
+        let prune_count = heights.len().saturating_sub(keep_recent);
+        for height in heights.into_iter().take(prune_count) {
+            tracing::debug!(height, "pruning old snapshot");
+            tokio::fs::remove_dir_all(self.snapshot_dir(height)).await?;
+        }
+        Ok(())
+    }
+}
+
+/// A snapshot being written to a [`SnapshotStore`], one chunk at a time.
+pub struct SnapshotWriter {
+    height: u64,
+    tmp_dir: PathBuf,
+    final_dir: PathBuf,
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotWriter {
+    /// Writes the next chunk of the snapshot.
+    pub async fn write_chunk(&mut self, chunk: Vec<u8>) -> Result<()> {
+        let index = self.chunk_hashes.len();
+        self.chunk_hashes.push(chunk_hash(&chunk));
+        tokio::fs::write(self.tmp_dir.join(index.to_string()), chunk).await?;
+        Ok(())
+    }
+
+    /// Writes the snapshot's manifest and moves it into place.
+    pub async fn finish(self) -> Result<Manifest> {
+        let manifest = Manifest {
+            height: self.height,
+            format: FORMAT,
+            metadata: Metadata {
+                chunk_hashes: self.chunk_hashes,
+            },
+        };
+        tokio::fs::write(
+            self.tmp_dir.join(MANIFEST_FILE),
+            serde_json::to_vec_pretty(&manifest)?,
+        )
+        .await?;
+
+        tokio::fs::rename(&self.tmp_dir, &self.final_dir).await?;
+        Ok(manifest)
+    }
+}
@@ -4,7 +4,10 @@
 use crate::testnet::{generate_tm_config, parse_tm_address, write_configs, ValidatorKeys};
 use anyhow::{Context, Result};
 use penumbra_chain::genesis;
-use penumbra_chain::{genesis::Allocation, params::ChainParameters};
+use penumbra_chain::{
+    genesis::{Allocation, DaoAllocation},
+    params::ChainParameters,
+};
 use penumbra_component::stake::{validator::Validator, FundingStream, FundingStreams};
 use penumbra_crypto::{
     keys::SpendKey,
@@ -35,6 +38,7 @@ pub fn testnet_generate(
     starting_ip: Ipv4Addr,
     validators_input_file: Option<PathBuf>,
     allocations_input_file: Option<PathBuf>,
+    dao_allocation: u64,
 ) -> anyhow::Result<()> {
     let genesis_time = Time::from_unix_timestamp(
         SystemTime::now()
@@ -176,6 +180,14 @@ pub fn testnet_generate(
             ..Default::default()
         },
         validators: validators.into_iter().map(Into::into).collect(),
+        dao_allocations: if dao_allocation > 0 {
+            vec![DaoAllocation {
+                amount: dao_allocation,
+                denom: "penumbra".to_string(),
+            }]
+        } else {
+            Vec::new()
+        },
     };
 
     // Create the genesis data shared by all nodes
@@ -4,6 +4,12 @@ fn main() {
         "src/gen/output_vk.bin",
         "src/gen/spend_pk.bin",
         "src/gen/spend_vk.bin",
+        "src/gen/swap_pk.bin",
+        "src/gen/swap_vk.bin",
+        "src/gen/swapclaim_pk.bin",
+        "src/gen/swapclaim_vk.bin",
+        "src/gen/undelegateclaim_pk.bin",
+        "src/gen/undelegateclaim_vk.bin",
     ];
     for file in proof_parameter_files {
         println!("cargo:rerun-if-changed={file}");
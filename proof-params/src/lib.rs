@@ -19,6 +19,37 @@ pub static OUTPUT_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
 pub static OUTPUT_PROOF_VERIFICATION_KEY: Lazy<VerifyingKey<Bls12_377>> =
     Lazy::new(output_verification_parameters);
 
+/// Proving key for the swap proof.
+pub static SWAP_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> = Lazy::new(swap_proving_parameters);
+
+/// Verifying key for the swap proof.
+pub static SWAP_PROOF_VERIFICATION_KEY: Lazy<VerifyingKey<Bls12_377>> =
+    Lazy::new(swap_verification_parameters);
+
+/// Proving key for the swap claim proof.
+pub static SWAPCLAIM_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
+    Lazy::new(swapclaim_proving_parameters);
+
+/// Verifying key for the swap claim proof.
+pub static SWAPCLAIM_PROOF_VERIFICATION_KEY: Lazy<VerifyingKey<Bls12_377>> =
+    Lazy::new(swapclaim_verification_parameters);
+
+/// Proving key for the undelegate claim proof.
+pub static UNDELEGATECLAIM_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
+    Lazy::new(undelegateclaim_proving_parameters);
+
+/// Verifying key for the undelegate claim proof.
+pub static UNDELEGATECLAIM_PROOF_VERIFICATION_KEY: Lazy<VerifyingKey<Bls12_377>> =
+    Lazy::new(undelegateclaim_verification_parameters);
+
+/// Proving key for the delegator vote proof.
+pub static DELEGATORVOTE_PROOF_PROVING_KEY: Lazy<ProvingKey<Bls12_377>> =
+    Lazy::new(delegatorvote_proving_parameters);
+
+/// Verifying key for the delegator vote proof.
+pub static DELEGATORVOTE_PROOF_VERIFICATION_KEY: Lazy<VerifyingKey<Bls12_377>> =
+    Lazy::new(delegatorvote_verification_parameters);
+
 fn spend_proving_parameters() -> ProvingKey<Bls12_377> {
     let pk_params = include_bytes!("gen/spend_pk.bin");
     ProvingKey::deserialize(&pk_params[..]).expect("can deserialize ProvingKey")
@@ -39,17 +70,62 @@ fn output_verification_parameters() -> VerifyingKey<Bls12_377> {
     VerifyingKey::deserialize(&vk_params[..]).expect("can deserialize VerifyingKey")
 }
 
+fn swap_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/swap_pk.bin");
+    ProvingKey::deserialize(&pk_params[..]).expect("can deserialize ProvingKey")
+}
+
+fn swap_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/swap_vk.bin");
+    VerifyingKey::deserialize(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
+fn swapclaim_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/swapclaim_pk.bin");
+    ProvingKey::deserialize(&pk_params[..]).expect("can deserialize ProvingKey")
+}
+
+fn swapclaim_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/swapclaim_vk.bin");
+    VerifyingKey::deserialize(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
+fn undelegateclaim_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/undelegateclaim_pk.bin");
+    ProvingKey::deserialize(&pk_params[..]).expect("can deserialize ProvingKey")
+}
+
+fn undelegateclaim_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/undelegateclaim_vk.bin");
+    VerifyingKey::deserialize(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
+fn delegatorvote_proving_parameters() -> ProvingKey<Bls12_377> {
+    let pk_params = include_bytes!("gen/delegatorvote_pk.bin");
+    ProvingKey::deserialize(&pk_params[..]).expect("can deserialize ProvingKey")
+}
+
+fn delegatorvote_verification_parameters() -> VerifyingKey<Bls12_377> {
+    let vk_params = include_bytes!("gen/delegatorvote_vk.bin");
+    VerifyingKey::deserialize(&vk_params[..]).expect("can deserialize VerifyingKey")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_ff::UniformRand;
+    use ark_ff::{UniformRand, Zero};
     use decaf377::Fr;
     use penumbra_crypto::{
         asset,
+        dex::{swap::SwapPlaintext, BatchSwapOutputData, TradingPair},
         keys::{SeedPhrase, SpendKey},
-        proofs::groth16::SpendProof,
-        rdsa::{SpendAuth, VerificationKey},
-        Note, Value,
+        proofs::groth16::{
+            DelegatorVoteProof, SpendProof, SwapClaimProof, SwapProof, UndelegateClaimProof,
+        },
+        rdsa::{SigningKey, SpendAuth, VerificationKey},
+        stake::{IdentityKey, Penalty, UnbondingToken},
+        transaction::Fee,
+        Balance, Note, Value,
     };
     use penumbra_tct as tct;
     use rand_core::OsRng;
@@ -106,4 +182,215 @@ mod tests {
         let proof_result = proof.verify(vk, anchor, balance_commitment, nf, rk);
         assert!(proof_result.is_ok());
     }
+
+    fn swap_plaintext() -> SwapPlaintext {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk_recipient = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_recipient = sk_recipient.full_viewing_key();
+        let ivk_recipient = fvk_recipient.incoming();
+        let (claim_address, _dtk_d) = ivk_recipient.payment_address(0u32.into());
+
+        let trading_pair = TradingPair::new(
+            asset::REGISTRY.parse_denom("gm").unwrap().id(),
+            asset::REGISTRY.parse_denom("gn").unwrap().id(),
+        );
+        SwapPlaintext::new(
+            &mut OsRng,
+            trading_pair,
+            100000u64.into(),
+            1u64.into(),
+            Fee(Value {
+                amount: 3u64.into(),
+                asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+            }),
+            claim_address,
+        )
+    }
+
+    #[test]
+    /// This test guards against drift in the current constraints versus the provided
+    /// proving/verification key.
+    fn swap_proof_parameters_vs_current_swap_circuit() {
+        let pk = &*SWAP_PROOF_PROVING_KEY;
+        let vk = &*SWAP_PROOF_VERIFICATION_KEY;
+
+        let swap_plaintext = swap_plaintext();
+        let fee_blinding = Fr::rand(&mut OsRng);
+        let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
+        let swap_commitment = swap_plaintext.swap_commitment();
+        let balance_commitment = (Balance::default()
+            - Value {
+                amount: swap_plaintext.delta_1_i,
+                asset_id: swap_plaintext.trading_pair.asset_1(),
+            }
+            - Value {
+                amount: swap_plaintext.delta_2_i,
+                asset_id: swap_plaintext.trading_pair.asset_2(),
+            })
+        .commit(Fr::zero())
+            + fee_commitment;
+
+        let proof = SwapProof::prove(
+            &mut OsRng,
+            pk,
+            swap_plaintext,
+            fee_blinding,
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(vk, balance_commitment, swap_commitment, fee_commitment);
+        assert!(proof_result.is_ok());
+    }
+
+    #[test]
+    /// This test guards against drift in the current constraints versus the provided
+    /// proving/verification key.
+    fn swap_claim_proof_parameters_vs_current_swap_claim_circuit() {
+        let pk = &*SWAPCLAIM_PROOF_PROVING_KEY;
+        let vk = &*SWAPCLAIM_PROOF_VERIFICATION_KEY;
+
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let nk = *sk.nullifier_key();
+
+        let swap_plaintext = swap_plaintext();
+        let swap_commitment = swap_plaintext.swap_commitment();
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, swap_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(swap_commitment).unwrap();
+        let position = state_commitment_proof.position();
+        let nullifier = nk.derive_nullifier(position, &swap_commitment);
+
+        let epoch_duration = 20;
+        let output_data = BatchSwapOutputData {
+            delta_1: 200000,
+            delta_2: 2,
+            lambda_1: 4,
+            lambda_2: 300000,
+            height: epoch_duration * u64::from(position.epoch()) + u64::from(position.block()),
+            trading_pair: swap_plaintext.trading_pair,
+            success: true,
+        };
+        let (output_1_note, output_2_note) = swap_plaintext.output_notes(&output_data);
+        let note_commitment_1 = output_1_note.commit();
+        let note_commitment_2 = output_2_note.commit();
+        let fee = swap_plaintext.claim_fee.clone();
+
+        let proof = SwapClaimProof::prove(
+            &mut OsRng,
+            pk,
+            swap_plaintext,
+            state_commitment_proof,
+            nk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(
+            vk,
+            anchor,
+            nullifier,
+            output_data,
+            epoch_duration,
+            note_commitment_1,
+            note_commitment_2,
+            fee,
+        );
+        assert!(proof_result.is_ok());
+    }
+
+    #[test]
+    /// This test guards against drift in the current constraints versus the provided
+    /// proving/verification key.
+    fn undelegate_claim_proof_parameters_vs_current_undelegate_claim_circuit() {
+        let pk = &*UNDELEGATECLAIM_PROOF_PROVING_KEY;
+        let vk = &*UNDELEGATECLAIM_PROOF_VERIFICATION_KEY;
+
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let unbonding_id = UnbondingToken::new(identity_key, 0, 1).id();
+        let unbonding_amount = 1000000u64.into();
+        let penalty = Penalty(1_0000);
+        let balance_blinding = Fr::rand(&mut OsRng);
+        let balance_commitment = penalty
+            .balance_for_claim(unbonding_id, unbonding_amount)
+            .commit(balance_blinding);
+
+        let proof = UndelegateClaimProof::prove(
+            &mut OsRng,
+            pk,
+            unbonding_amount,
+            balance_blinding,
+            balance_commitment,
+            unbonding_id,
+            penalty,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(vk, balance_commitment, unbonding_id, penalty);
+        assert!(proof_result.is_ok());
+    }
+
+    #[test]
+    /// This test guards against drift in the current constraints versus the provided
+    /// proving/verification key.
+    fn delegator_vote_proof_parameters_vs_current_delegator_vote_circuit() {
+        let pk = &*DELEGATORVOTE_PROOF_PROVING_KEY;
+        let vk = &*DELEGATORVOTE_PROOF_VERIFICATION_KEY;
+
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk_sender = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let fvk_sender = sk_sender.full_viewing_key();
+        let ivk_sender = fvk_sender.incoming();
+        let (sender, _dtk_d) = ivk_sender.payment_address(0u32.into());
+
+        let value_to_send = Value {
+            amount: 1u64.into(),
+            asset_id: asset::REGISTRY.parse_denom("upenumbra").unwrap().id(),
+        };
+
+        let note = Note::generate(&mut OsRng, &sender, value_to_send);
+        let note_commitment = note.commit();
+        let spend_auth_randomizer = Fr::rand(&mut OsRng);
+        let rsk = sk_sender.spend_auth_key().randomize(&spend_auth_randomizer);
+        let nk = *sk_sender.nullifier_key();
+        let ak: VerificationKey<SpendAuth> = sk_sender.spend_auth_key().into();
+        let mut sct = tct::Tree::new();
+        sct.insert(tct::Witness::Keep, note_commitment).unwrap();
+        let anchor = sct.root();
+        let state_commitment_proof = sct.witness(note_commitment).unwrap();
+        let start_position = sct.position().unwrap();
+        let v_blinding = Fr::zero();
+        let balance_commitment = value_to_send.commit(v_blinding);
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        let nf = nk.derive_nullifier(0.into(), &note_commitment);
+
+        let proof = DelegatorVoteProof::prove(
+            &mut OsRng,
+            pk,
+            state_commitment_proof,
+            note,
+            v_blinding,
+            spend_auth_randomizer,
+            ak,
+            nk,
+            anchor,
+            balance_commitment,
+            nf,
+            rk,
+            start_position,
+        )
+        .expect("can create proof");
+
+        let proof_result = proof.verify(vk, anchor, balance_commitment, nf, rk, start_position);
+        assert!(proof_result.is_ok());
+    }
 }
@@ -182,6 +182,47 @@ pub struct BatchSwapOutputDataResponse {
         super::super::core::dex::v1alpha1::BatchSwapOutputData,
     >,
 }
+/// Requests the execution of one direction of a batch swap at a given height,
+/// including each hop of the route it was filled along.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionRequest {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::dex::v1alpha1::DirectedTradingPair,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapExecutionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub swap_execution: ::core::option::Option<
+        super::super::core::dex::v1alpha1::SwapExecution,
+    >,
+}
+/// Requests the payouts of a validator's commission to its funding streams, in
+/// every epoch indexed by the node.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingStreamPayoutsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingStreamPayoutsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub payouts: ::prost::alloc::vec::Vec<
+        super::super::core::stake::v1alpha1::FundingStreamPayout,
+    >,
+}
 /// Requests CPMM reserves data associated with a given trading pair from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -455,6 +496,46 @@ pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "2")]
     pub block: ::core::option::Option<super::super::super::tendermint::types::Block>,
 }
+/// Requests the transactions in a range of blocks with clues matching any of
+/// the provided detection keys.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectedTransactionsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The start height of the range.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The end height of the range, defaults to the latest block height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// If set, keeps the connection alive past `end_height`,
+    /// streaming detected transactions as new blocks are created.
+    #[prost(bool, tag = "4")]
+    pub keep_alive: bool,
+    /// The detection keys to examine clues with.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// The detected transactions in a single block.
+///
+/// One response is sent for every block in the requested range, in order,
+/// even if no transactions in it were detected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectedTransactionsResponse {
+    /// The height of the block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The transactions in the block with at least one matching clue, along
+    /// with any transactions with IBC actions, which can create notes without
+    /// clues.
+    #[prost(message, repeated, tag = "2")]
+    pub transactions: ::prost::alloc::vec::Vec<
+        super::super::core::transaction::v1alpha1::Transaction,
+    >,
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod oblivious_query_service_client {
@@ -806,6 +887,44 @@ pub mod specific_query_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn swap_execution(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SwapExecutionRequest>,
+        ) -> Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/SwapExecution",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn funding_stream_payouts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FundingStreamPayoutsRequest>,
+        ) -> Result<tonic::Response<super::FundingStreamPayoutsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/FundingStreamPayouts",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         pub async fn stub_cpmm_reserves(
             &mut self,
             request: impl tonic::IntoRequest<super::StubCpmmReservesRequest>,
@@ -1088,6 +1207,106 @@ pub mod tendermint_proxy_service_client {
         }
     }
 }
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod detection_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Detects transactions of interest to light clients using fuzzy message
+    /// detection.
+    ///
+    /// Clients provide only their detection keys, which let the server test
+    /// whether a transaction's clues match, with a false positive rate set by the
+    /// chain's FMD parameters, but not decrypt or link the transaction's contents.
+    #[derive(Debug, Clone)]
+    pub struct DetectionServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DetectionServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DetectionServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DetectionServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            DetectionServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn detected_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetectedTransactionsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::DetectedTransactionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.DetectionService/DetectedTransactions",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+}
 /// Generated server implementations.
 #[cfg(feature = "rpc")]
 pub mod oblivious_query_service_server {
@@ -1471,6 +1690,14 @@ pub mod specific_query_service_server {
             &self,
             request: tonic::Request<super::BatchSwapOutputDataRequest>,
         ) -> Result<tonic::Response<super::BatchSwapOutputDataResponse>, tonic::Status>;
+        async fn swap_execution(
+            &self,
+            request: tonic::Request<super::SwapExecutionRequest>,
+        ) -> Result<tonic::Response<super::SwapExecutionResponse>, tonic::Status>;
+        async fn funding_stream_payouts(
+            &self,
+            request: tonic::Request<super::FundingStreamPayoutsRequest>,
+        ) -> Result<tonic::Response<super::FundingStreamPayoutsResponse>, tonic::Status>;
         async fn stub_cpmm_reserves(
             &self,
             request: tonic::Request<super::StubCpmmReservesRequest>,
@@ -1763,6 +1990,86 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/SwapExecution" => {
+                    #[allow(non_camel_case_types)]
+                    struct SwapExecutionSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::SwapExecutionRequest>
+                    for SwapExecutionSvc<T> {
+                        type Response = super::SwapExecutionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapExecutionRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).swap_execution(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SwapExecutionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/FundingStreamPayouts" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundingStreamPayoutsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::FundingStreamPayoutsRequest>
+                    for FundingStreamPayoutsSvc<T> {
+                        type Response = super::FundingStreamPayoutsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FundingStreamPayoutsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).funding_stream_payouts(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FundingStreamPayoutsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.client.v1alpha1.SpecificQueryService/StubCPMMReserves" => {
                     #[allow(non_camel_case_types)]
                     struct StubCPMMReservesSvc<T: SpecificQueryService>(pub Arc<T>);
@@ -2335,3 +2642,170 @@ pub mod tendermint_proxy_service_server {
         const NAME: &'static str = "penumbra.client.v1alpha1.TendermintProxyService";
     }
 }
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod detection_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DetectionServiceServer.
+    #[async_trait]
+    pub trait DetectionService: Send + Sync + 'static {
+        /// Server streaming response type for the DetectedTransactions method.
+        type DetectedTransactionsStream: futures_core::Stream<
+                Item = Result<super::DetectedTransactionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn detected_transactions(
+            &self,
+            request: tonic::Request<super::DetectedTransactionsRequest>,
+        ) -> Result<tonic::Response<Self::DetectedTransactionsStream>, tonic::Status>;
+    }
+    /// Detects transactions of interest to light clients using fuzzy message
+    /// detection.
+    ///
+    /// Clients provide only their detection keys, which let the server test
+    /// whether a transaction's clues match, with a false positive rate set by the
+    /// chain's FMD parameters, but not decrypt or link the transaction's contents.
+    #[derive(Debug)]
+    pub struct DetectionServiceServer<T: DetectionService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DetectionService> DetectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for DetectionServiceServer<T>
+    where
+        T: DetectionService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.client.v1alpha1.DetectionService/DetectedTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetectedTransactionsSvc<T: DetectionService>(pub Arc<T>);
+                    impl<
+                        T: DetectionService,
+                    > tonic::server::ServerStreamingService<
+                        super::DetectedTransactionsRequest,
+                    > for DetectedTransactionsSvc<T> {
+                        type Response = super::DetectedTransactionsResponse;
+                        type ResponseStream = T::DetectedTransactionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DetectedTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).detected_transactions(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetectedTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: DetectionService> Clone for DetectionServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: DetectionService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: DetectionService> tonic::server::NamedService
+    for DetectionServiceServer<T> {
+        const NAME: &'static str = "penumbra.client.v1alpha1.DetectionService";
+    }
+}
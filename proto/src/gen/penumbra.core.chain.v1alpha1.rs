@@ -208,6 +208,8 @@ pub struct GenesisAppState {
     pub validators: ::prost::alloc::vec::Vec<super::super::stake::v1alpha1::Validator>,
     #[prost(message, repeated, tag = "3")]
     pub allocations: ::prost::alloc::vec::Vec<genesis_app_state::Allocation>,
+    #[prost(message, repeated, tag = "4")]
+    pub dao_allocations: ::prost::alloc::vec::Vec<genesis_app_state::DaoAllocation>,
 }
 /// Nested message and enum types in `GenesisAppState`.
 pub mod genesis_app_state {
@@ -223,4 +225,13 @@ pub mod genesis_app_state {
             super::super::super::crypto::v1alpha1::Address,
         >,
     }
+    /// An allocation deposited into the DAO at genesis.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DaoAllocation {
+        #[prost(uint64, tag = "1")]
+        pub amount: u64,
+        #[prost(string, tag = "2")]
+        pub denom: ::prost::alloc::string::String,
+    }
 }
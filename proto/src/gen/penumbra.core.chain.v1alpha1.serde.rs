@@ -890,6 +890,9 @@ impl serde::Serialize for GenesisAppState {
         if !self.allocations.is_empty() {
             len += 1;
         }
+        if !self.dao_allocations.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState", len)?;
         if let Some(v) = self.chain_params.as_ref() {
             struct_ser.serialize_field("chainParams", v)?;
@@ -900,6 +903,9 @@ impl serde::Serialize for GenesisAppState {
         if !self.allocations.is_empty() {
             struct_ser.serialize_field("allocations", &self.allocations)?;
         }
+        if !self.dao_allocations.is_empty() {
+            struct_ser.serialize_field("daoAllocations", &self.dao_allocations)?;
+        }
         struct_ser.end()
     }
 }
@@ -914,6 +920,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             "chainParams",
             "validators",
             "allocations",
+            "dao_allocations",
+            "daoAllocations",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -921,6 +929,7 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             ChainParams,
             Validators,
             Allocations,
+            DaoAllocations,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -945,6 +954,7 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             "chainParams" | "chain_params" => Ok(GeneratedField::ChainParams),
                             "validators" => Ok(GeneratedField::Validators),
                             "allocations" => Ok(GeneratedField::Allocations),
+                            "daoAllocations" | "dao_allocations" => Ok(GeneratedField::DaoAllocations),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -967,6 +977,7 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                 let mut chain_params__ = None;
                 let mut validators__ = None;
                 let mut allocations__ = None;
+                let mut dao_allocations__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainParams => {
@@ -987,12 +998,19 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             allocations__ = Some(map.next_value()?);
                         }
+                        GeneratedField::DaoAllocations => {
+                            if dao_allocations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoAllocations"));
+                            }
+                            dao_allocations__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(GenesisAppState {
                     chain_params: chain_params__,
                     validators: validators__.unwrap_or_default(),
                     allocations: allocations__.unwrap_or_default(),
+                    dao_allocations: dao_allocations__.unwrap_or_default(),
                 })
             }
         }
@@ -1126,6 +1144,116 @@ impl<'de> serde::Deserialize<'de> for genesis_app_state::Allocation {
         deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState.Allocation", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for genesis_app_state::DaoAllocation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount != 0 {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState.DaoAllocation", len)?;
+        if self.amount != 0 {
+            struct_ser.serialize_field("amount", ToString::to_string(&self.amount).as_str())?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for genesis_app_state::DaoAllocation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "denom",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Denom,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "denom" => Ok(GeneratedField::Denom),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = genesis_app_state::DaoAllocation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.chain.v1alpha1.GenesisAppState.DaoAllocation")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<genesis_app_state::DaoAllocation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut denom__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(genesis_app_state::DaoAllocation {
+                    amount: amount__.unwrap_or_default(),
+                    denom: denom__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState.DaoAllocation", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for KnownAssets {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -497,6 +497,32 @@ pub struct PositionRewardClaim {
         super::super::crypto::v1alpha1::BalanceCommitment,
     >,
 }
+/// Contains the data needed to build a `PositionWithdraw` action.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionWithdrawPlan {
+    /// The position's final reserves, which the withdrawal commits to.
+    #[prost(message, optional, tag = "1")]
+    pub reserves: ::core::option::Option<Reserves>,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The trading pair of the position, needed to determine the asset IDs of the reserves.
+    #[prost(message, optional, tag = "3")]
+    pub pair: ::core::option::Option<TradingPair>,
+}
+/// Contains the data needed to build a `PositionRewardClaim` action.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardClaimPlan {
+    /// The position's accumulated rewards, which the claim commits to.
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<Reserves>,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The trading pair of the position, needed to determine the asset IDs of the rewards.
+    #[prost(message, optional, tag = "3")]
+    pub pair: ::core::option::Option<TradingPair>,
+}
 /// Contains a path for a trade, including the trading pair (with direction), the trading
 /// function defining their relationship, and the route taken between the two assets.
 #[allow(clippy::derive_partial_eq_without_eq)]
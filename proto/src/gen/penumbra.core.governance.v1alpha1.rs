@@ -77,34 +77,15 @@ pub struct DelegatorVoteBody {
     /// The randomized validating key for the spend authorization signature.
     #[prost(bytes = "vec", tag = "4")]
     pub rk: ::prost::alloc::vec::Vec<u8>,
-    /// A commitment to the value voted for "yes".
-    ///
-    /// A rational voter will place all their voting weight on one vote.
-    #[prost(message, optional, tag = "5")]
-    pub yes_balance_commitment: ::core::option::Option<
-        super::super::crypto::v1alpha1::BalanceCommitment,
-    >,
-    /// A commitment to the value voted for "no".
-    ///
-    /// A rational voter will place all their voting weight on one vote.
-    #[prost(message, optional, tag = "6")]
-    pub no_balance_commitment: ::core::option::Option<
-        super::super::crypto::v1alpha1::BalanceCommitment,
-    >,
-    /// A commitment to the value voted for "abstain".
-    ///
-    /// A rational voter will place all their voting weight on one vote.
-    #[prost(message, optional, tag = "7")]
-    pub abstain_balance_commitment: ::core::option::Option<
-        super::super::crypto::v1alpha1::BalanceCommitment,
-    >,
-    /// A commitment to the value voted for "no with veto".
-    ///
-    /// A rational voter will place all their voting weight on one vote.
-    #[prost(message, optional, tag = "8")]
-    pub no_with_veto_balance_commitment: ::core::option::Option<
-        super::super::crypto::v1alpha1::BalanceCommitment,
-    >,
+    /// The position of the state commitment tree when voting on the proposal started.
+    #[prost(uint64, tag = "9")]
+    pub start_position: u64,
+    /// The vote.
+    #[prost(message, optional, tag = "10")]
+    pub vote: ::core::option::Option<Vote>,
+    /// The value of the delegation note being voted with.
+    #[prost(message, optional, tag = "11")]
+    pub value: ::core::option::Option<super::super::crypto::v1alpha1::Value>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -137,6 +118,9 @@ pub struct DelegatorVotePlan {
     /// The randomizer to use for the proof of spend capability.
     #[prost(bytes = "vec", tag = "5")]
     pub randomizer: ::prost::alloc::vec::Vec<u8>,
+    /// The position of the state commitment tree when voting on the proposal started.
+    #[prost(uint64, tag = "6")]
+    pub start_position: u64,
 }
 /// A vote on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
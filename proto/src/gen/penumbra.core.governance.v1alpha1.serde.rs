@@ -143,16 +143,13 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             len += 1;
         }
-        if self.yes_balance_commitment.is_some() {
+        if self.start_position != 0 {
             len += 1;
         }
-        if self.no_balance_commitment.is_some() {
-            len += 1;
-        }
-        if self.abstain_balance_commitment.is_some() {
+        if self.vote.is_some() {
             len += 1;
         }
-        if self.no_with_veto_balance_commitment.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVoteBody", len)?;
@@ -165,17 +162,14 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             struct_ser.serialize_field("rk", pbjson::private::base64::encode(&self.rk).as_str())?;
         }
-        if let Some(v) = self.yes_balance_commitment.as_ref() {
-            struct_ser.serialize_field("yesBalanceCommitment", v)?;
+        if self.start_position != 0 {
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
         }
-        if let Some(v) = self.no_balance_commitment.as_ref() {
-            struct_ser.serialize_field("noBalanceCommitment", v)?;
-        }
-        if let Some(v) = self.abstain_balance_commitment.as_ref() {
-            struct_ser.serialize_field("abstainBalanceCommitment", v)?;
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
         }
-        if let Some(v) = self.no_with_veto_balance_commitment.as_ref() {
-            struct_ser.serialize_field("noWithVetoBalanceCommitment", v)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
         }
         struct_ser.end()
     }
@@ -190,14 +184,10 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             "proposal",
             "nullifier",
             "rk",
-            "yes_balance_commitment",
-            "yesBalanceCommitment",
-            "no_balance_commitment",
-            "noBalanceCommitment",
-            "abstain_balance_commitment",
-            "abstainBalanceCommitment",
-            "no_with_veto_balance_commitment",
-            "noWithVetoBalanceCommitment",
+            "start_position",
+            "startPosition",
+            "vote",
+            "value",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -205,10 +195,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             Proposal,
             Nullifier,
             Rk,
-            YesBalanceCommitment,
-            NoBalanceCommitment,
-            AbstainBalanceCommitment,
-            NoWithVetoBalanceCommitment,
+            StartPosition,
+            Vote,
+            Value,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -233,10 +222,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             "proposal" => Ok(GeneratedField::Proposal),
                             "nullifier" => Ok(GeneratedField::Nullifier),
                             "rk" => Ok(GeneratedField::Rk),
-                            "yesBalanceCommitment" | "yes_balance_commitment" => Ok(GeneratedField::YesBalanceCommitment),
-                            "noBalanceCommitment" | "no_balance_commitment" => Ok(GeneratedField::NoBalanceCommitment),
-                            "abstainBalanceCommitment" | "abstain_balance_commitment" => Ok(GeneratedField::AbstainBalanceCommitment),
-                            "noWithVetoBalanceCommitment" | "no_with_veto_balance_commitment" => Ok(GeneratedField::NoWithVetoBalanceCommitment),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "value" => Ok(GeneratedField::Value),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -259,10 +247,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                 let mut proposal__ = None;
                 let mut nullifier__ = None;
                 let mut rk__ = None;
-                let mut yes_balance_commitment__ = None;
-                let mut no_balance_commitment__ = None;
-                let mut abstain_balance_commitment__ = None;
-                let mut no_with_veto_balance_commitment__ = None;
+                let mut start_position__ = None;
+                let mut vote__ = None;
+                let mut value__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -289,29 +276,25 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::YesBalanceCommitment => {
-                            if yes_balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("yesBalanceCommitment"));
-                            }
-                            yes_balance_commitment__ = map.next_value()?;
-                        }
-                        GeneratedField::NoBalanceCommitment => {
-                            if no_balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noBalanceCommitment"));
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
                             }
-                            no_balance_commitment__ = map.next_value()?;
+                            start_position__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::AbstainBalanceCommitment => {
-                            if abstain_balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("abstainBalanceCommitment"));
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
                             }
-                            abstain_balance_commitment__ = map.next_value()?;
+                            vote__ = map.next_value()?;
                         }
-                        GeneratedField::NoWithVetoBalanceCommitment => {
-                            if no_with_veto_balance_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noWithVetoBalanceCommitment"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            no_with_veto_balance_commitment__ = map.next_value()?;
+                            value__ = map.next_value()?;
                         }
                     }
                 }
@@ -319,10 +302,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                     proposal: proposal__.unwrap_or_default(),
                     nullifier: nullifier__.unwrap_or_default(),
                     rk: rk__.unwrap_or_default(),
-                    yes_balance_commitment: yes_balance_commitment__,
-                    no_balance_commitment: no_balance_commitment__,
-                    abstain_balance_commitment: abstain_balance_commitment__,
-                    no_with_veto_balance_commitment: no_with_veto_balance_commitment__,
+                    start_position: start_position__.unwrap_or_default(),
+                    vote: vote__,
+                    value: value__,
                 })
             }
         }
@@ -352,6 +334,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.randomizer.is_empty() {
             len += 1;
         }
+        if self.start_position != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVotePlan", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -368,6 +353,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.randomizer.is_empty() {
             struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
         }
+        if self.start_position != 0 {
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -384,6 +372,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             "stakedNote",
             "position",
             "randomizer",
+            "start_position",
+            "startPosition",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -393,6 +383,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             StakedNote,
             Position,
             Randomizer,
+            StartPosition,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -419,6 +410,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                             "stakedNote" | "staked_note" => Ok(GeneratedField::StakedNote),
                             "position" => Ok(GeneratedField::Position),
                             "randomizer" => Ok(GeneratedField::Randomizer),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -443,6 +435,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                 let mut staked_note__ = None;
                 let mut position__ = None;
                 let mut randomizer__ = None;
+                let mut start_position__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -481,6 +474,14 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(DelegatorVotePlan {
@@ -489,6 +490,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     staked_note: staked_note__,
                     position: position__.unwrap_or_default(),
                     randomizer: randomizer__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
                 })
             }
         }
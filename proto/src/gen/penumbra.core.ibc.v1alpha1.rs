@@ -68,6 +68,9 @@ pub struct Ics20Withdrawal {
     /// we MUST verify a nonexistence proof before accepting the timeout, to
     /// prevent relayer censorship attacks. The core IBC implementation does this
     /// in its handling of validation of timeouts.
+    ///
+    /// the height is in the counterparty chain's current revision, as given by
+    /// its chain id; zero means the transfer has no height timeout.
     #[prost(uint64, tag = "6")]
     pub timeout_height: u64,
     /// the timestamp at which this transfer expires.
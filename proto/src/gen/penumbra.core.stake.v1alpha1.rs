@@ -59,6 +59,24 @@ pub struct FundingStream {
     #[prost(uint32, tag = "2")]
     pub rate_bps: u32,
 }
+/// A payout of a validator's commission to one of its funding streams, made at
+/// the end of an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingStreamPayout {
+    /// The identity key of the validator whose commission was paid out.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::crypto::v1alpha1::IdentityKey>,
+    /// The index of the epoch the commission was earned in.
+    #[prost(uint64, tag = "2")]
+    pub epoch_index: u64,
+    /// The destination address of the funding stream.
+    #[prost(string, tag = "3")]
+    pub address: ::prost::alloc::string::String,
+    /// The amount of the staking token paid out.
+    #[prost(message, optional, tag = "4")]
+    pub amount: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
+}
 /// Describes the reward and exchange rates and voting power for a validator in some epoch.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -73,7 +73,8 @@ pub mod action {
         ProposalWithdraw(super::super::super::governance::v1alpha1::ProposalWithdraw),
         #[prost(message, tag = "20")]
         ValidatorVote(super::super::super::governance::v1alpha1::ValidatorVote),
-        /// DelegatorVote delegator_vote = 21;
+        #[prost(message, tag = "21")]
+        DelegatorVote(super::super::super::governance::v1alpha1::DelegatorVote),
         #[prost(message, tag = "22")]
         ProposalDepositClaim(
             super::super::super::governance::v1alpha1::ProposalDepositClaim,
@@ -189,11 +190,12 @@ pub mod action_view {
         ProposalWithdraw(super::super::super::governance::v1alpha1::ProposalWithdraw),
         #[prost(message, tag = "20")]
         ValidatorVote(super::super::super::governance::v1alpha1::ValidatorVote),
+        #[prost(message, tag = "21")]
+        DelegatorVote(super::super::super::governance::v1alpha1::DelegatorVote),
         #[prost(message, tag = "22")]
         ProposalDepositClaim(
             super::super::super::governance::v1alpha1::ProposalDepositClaim,
         ),
-        /// DelegatorVote delegator_vote = 21;
         #[prost(message, tag = "30")]
         PositionOpen(super::super::super::dex::v1alpha1::PositionOpen),
         #[prost(message, tag = "31")]
@@ -398,6 +400,12 @@ pub struct AuthorizationData {
     pub spend_auths: ::prost::alloc::vec::Vec<
         super::super::crypto::v1alpha1::SpendAuthSignature,
     >,
+    /// The required delegator vote authorizations, returned in the same order as the
+    /// DelegatorVote actions in the original request.
+    #[prost(message, repeated, tag = "3")]
+    pub delegator_vote_auths: ::prost::alloc::vec::Vec<
+        super::super::crypto::v1alpha1::SpendAuthSignature,
+    >,
 }
 /// The data required for proving when building a transaction from a plan.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -480,9 +488,11 @@ pub mod action_plan {
         #[prost(message, tag = "31")]
         PositionClose(super::super::super::dex::v1alpha1::PositionClose),
         #[prost(message, tag = "32")]
-        PositionWithdraw(super::super::super::dex::v1alpha1::PositionWithdraw),
+        PositionWithdraw(super::super::super::dex::v1alpha1::PositionWithdrawPlan),
         #[prost(message, tag = "34")]
-        PositionRewardClaim(super::super::super::dex::v1alpha1::PositionRewardClaim),
+        PositionRewardClaim(
+            super::super::super::dex::v1alpha1::PositionRewardClaimPlan,
+        ),
         /// We don't need any extra information (yet) to understand delegations,
         /// because we don't yet use flow encryption.
         #[prost(message, tag = "40")]
@@ -493,6 +503,9 @@ pub mod action_plan {
         Undelegate(super::super::super::stake::v1alpha1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::stake::v1alpha1::UndelegateClaimPlan),
+        /// The withdrawal is fully determined by the plan.
+        #[prost(message, tag = "200")]
+        Ics20Withdrawal(super::super::super::ibc::v1alpha1::Ics20Withdrawal),
     }
 }
 /// Describes a plan for forming a `Clue`.
@@ -39,6 +39,9 @@ impl serde::Serialize for Action {
                 action::Action::ValidatorVote(v) => {
                     struct_ser.serialize_field("validatorVote", v)?;
                 }
+                action::Action::DelegatorVote(v) => {
+                    struct_ser.serialize_field("delegatorVote", v)?;
+                }
                 action::Action::ProposalDepositClaim(v) => {
                     struct_ser.serialize_field("proposalDepositClaim", v)?;
                 }
@@ -93,6 +96,8 @@ impl<'de> serde::Deserialize<'de> for Action {
             "proposalWithdraw",
             "validator_vote",
             "validatorVote",
+            "delegator_vote",
+            "delegatorVote",
             "proposal_deposit_claim",
             "proposalDepositClaim",
             "position_open",
@@ -122,6 +127,7 @@ impl<'de> serde::Deserialize<'de> for Action {
             ProposalSubmit,
             ProposalWithdraw,
             ValidatorVote,
+            DelegatorVote,
             ProposalDepositClaim,
             PositionOpen,
             PositionClose,
@@ -161,6 +167,7 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "proposalSubmit" | "proposal_submit" => Ok(GeneratedField::ProposalSubmit),
                             "proposalWithdraw" | "proposal_withdraw" => Ok(GeneratedField::ProposalWithdraw),
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
+                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
@@ -253,6 +260,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("validatorVote"));
                             }
                             action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::ValidatorVote)
+;
+                        }
+                        GeneratedField::DelegatorVote => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action::Action::DelegatorVote)
 ;
                         }
                         GeneratedField::ProposalDepositClaim => {
@@ -396,6 +410,9 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::Ics20Withdrawal(v) => {
+                    struct_ser.serialize_field("ics20Withdrawal", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -439,6 +456,8 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "ics20_withdrawal",
+            "ics20Withdrawal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -461,6 +480,7 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Ics20Withdrawal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -500,6 +520,7 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -646,6 +667,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Ics20Withdrawal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
+                            }
+                            action__ = map.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Ics20Withdrawal)
 ;
                         }
                     }
@@ -699,6 +727,9 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::ValidatorVote(v) => {
                     struct_ser.serialize_field("validatorVote", v)?;
                 }
+                action_view::ActionView::DelegatorVote(v) => {
+                    struct_ser.serialize_field("delegatorVote", v)?;
+                }
                 action_view::ActionView::ProposalDepositClaim(v) => {
                     struct_ser.serialize_field("proposalDepositClaim", v)?;
                 }
@@ -753,6 +784,8 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "proposalWithdraw",
             "validator_vote",
             "validatorVote",
+            "delegator_vote",
+            "delegatorVote",
             "proposal_deposit_claim",
             "proposalDepositClaim",
             "position_open",
@@ -782,6 +815,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             ProposalSubmit,
             ProposalWithdraw,
             ValidatorVote,
+            DelegatorVote,
             ProposalDepositClaim,
             PositionOpen,
             PositionClose,
@@ -821,6 +855,7 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "proposalSubmit" | "proposal_submit" => Ok(GeneratedField::ProposalSubmit),
                             "proposalWithdraw" | "proposal_withdraw" => Ok(GeneratedField::ProposalWithdraw),
                             "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
+                            "delegatorVote" | "delegator_vote" => Ok(GeneratedField::DelegatorVote),
                             "proposalDepositClaim" | "proposal_deposit_claim" => Ok(GeneratedField::ProposalDepositClaim),
                             "positionOpen" | "position_open" => Ok(GeneratedField::PositionOpen),
                             "positionClose" | "position_close" => Ok(GeneratedField::PositionClose),
@@ -913,6 +948,13 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("validatorVote"));
                             }
                             action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ValidatorVote)
+;
+                        }
+                        GeneratedField::DelegatorVote => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVote"));
+                            }
+                            action_view__ = map.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::DelegatorVote)
 ;
                         }
                         GeneratedField::ProposalDepositClaim => {
@@ -1310,6 +1352,9 @@ impl serde::Serialize for AuthorizationData {
         if !self.spend_auths.is_empty() {
             len += 1;
         }
+        if !self.delegator_vote_auths.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1alpha1.AuthorizationData", len)?;
         if let Some(v) = self.effect_hash.as_ref() {
             struct_ser.serialize_field("effectHash", v)?;
@@ -1317,6 +1362,9 @@ impl serde::Serialize for AuthorizationData {
         if !self.spend_auths.is_empty() {
             struct_ser.serialize_field("spendAuths", &self.spend_auths)?;
         }
+        if !self.delegator_vote_auths.is_empty() {
+            struct_ser.serialize_field("delegatorVoteAuths", &self.delegator_vote_auths)?;
+        }
         struct_ser.end()
     }
 }
@@ -1331,12 +1379,15 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
             "effectHash",
             "spend_auths",
             "spendAuths",
+            "delegator_vote_auths",
+            "delegatorVoteAuths",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             EffectHash,
             SpendAuths,
+            DelegatorVoteAuths,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1360,6 +1411,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                         match value {
                             "effectHash" | "effect_hash" => Ok(GeneratedField::EffectHash),
                             "spendAuths" | "spend_auths" => Ok(GeneratedField::SpendAuths),
+                            "delegatorVoteAuths" | "delegator_vote_auths" => Ok(GeneratedField::DelegatorVoteAuths),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1381,6 +1433,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
             {
                 let mut effect_hash__ = None;
                 let mut spend_auths__ = None;
+                let mut delegator_vote_auths__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::EffectHash => {
@@ -1395,11 +1448,18 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                             }
                             spend_auths__ = Some(map.next_value()?);
                         }
+                        GeneratedField::DelegatorVoteAuths => {
+                            if delegator_vote_auths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVoteAuths"));
+                            }
+                            delegator_vote_auths__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(AuthorizationData {
                     effect_hash: effect_hash__,
                     spend_auths: spend_auths__.unwrap_or_default(),
+                    delegator_vote_auths: delegator_vote_auths__.unwrap_or_default(),
                 })
             }
         }
@@ -33,7 +33,7 @@ pub struct AuthorizeResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreAuthorization {
-    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1")]
+    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1, 2, 3")]
     pub pre_authorization: ::core::option::Option<pre_authorization::PreAuthorization>,
 }
 /// Nested message and enum types in `PreAuthorization`.
@@ -50,11 +50,42 @@ pub mod pre_authorization {
         #[prost(bytes = "vec", tag = "2")]
         pub sig: ::prost::alloc::vec::Vec<u8>,
     }
+    /// An ECDSA-based preauthorization over the secp256k1 curve, containing an
+    /// ECDSA signature over the SHA-256 hash of the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Secp256k1 {
+        /// The SEC1-encoded secp256k1 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ECDSA signature over the `TransactionPlan`, either as 64 bytes `r || s`
+        /// or DER-encoded.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    /// An ECDSA-based preauthorization over the NIST P-256 curve, as used by
+    /// WebAuthn authenticators and other hardware-backed keys, containing an
+    /// ECDSA signature over the SHA-256 hash of the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct P256 {
+        /// The SEC1-encoded P-256 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ECDSA signature over the `TransactionPlan`, either as 64 bytes `r || s`
+        /// or DER-encoded.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum PreAuthorization {
         #[prost(message, tag = "1")]
         Ed25519(Ed25519),
+        #[prost(message, tag = "2")]
+        Secp256k1(Secp256k1),
+        #[prost(message, tag = "3")]
+        P256(P256),
     }
 }
 /// Generated client implementations.
@@ -233,6 +233,12 @@ impl serde::Serialize for PreAuthorization {
                 pre_authorization::PreAuthorization::Ed25519(v) => {
                     struct_ser.serialize_field("ed25519", v)?;
                 }
+                pre_authorization::PreAuthorization::Secp256k1(v) => {
+                    struct_ser.serialize_field("secp256k1", v)?;
+                }
+                pre_authorization::PreAuthorization::P256(v) => {
+                    struct_ser.serialize_field("p256", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -246,11 +252,15 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
     {
         const FIELDS: &[&str] = &[
             "ed25519",
+            "secp256k1",
+            "p256",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ed25519,
+            Secp256k1,
+            P256,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -273,6 +283,8 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                     {
                         match value {
                             "ed25519" => Ok(GeneratedField::Ed25519),
+                            "secp256k1" => Ok(GeneratedField::Secp256k1),
+                            "p256" => Ok(GeneratedField::P256),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -300,6 +312,20 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                                 return Err(serde::de::Error::duplicate_field("ed25519"));
                             }
                             pre_authorization__ = map.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Ed25519)
+;
+                        }
+                        GeneratedField::Secp256k1 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secp256k1"));
+                            }
+                            pre_authorization__ = map.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Secp256k1)
+;
+                        }
+                        GeneratedField::P256 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("p256"));
+                            }
+                            pre_authorization__ = map.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::P256)
 ;
                         }
                     }
@@ -424,3 +450,227 @@ impl<'de> serde::Deserialize<'de> for pre_authorization::Ed25519 {
         deserializer.deserialize_struct("penumbra.custody.v1alpha1.PreAuthorization.Ed25519", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for pre_authorization::P256 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1alpha1.PreAuthorization.P256", len)?;
+        if !self.vk.is_empty() {
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::P256 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::P256;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1alpha1.PreAuthorization.P256")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<pre_authorization::P256, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(pre_authorization::P256 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1alpha1.PreAuthorization.P256", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::Secp256k1 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1alpha1.PreAuthorization.Secp256k1", len)?;
+        if !self.vk.is_empty() {
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::Secp256k1 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::Secp256k1;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1alpha1.PreAuthorization.Secp256k1")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<pre_authorization::Secp256k1, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(pre_authorization::Secp256k1 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1alpha1.PreAuthorization.Secp256k1", FIELDS, GeneratedVisitor)
+    }
+}
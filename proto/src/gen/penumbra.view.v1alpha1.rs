@@ -95,6 +95,11 @@ pub struct AddressByIndexRequest {
     pub address_index: ::core::option::Option<
         super::super::core::crypto::v1alpha1::AddressIndex,
     >,
+    /// Identifies the FVK to use.  If unset, the view service's default account is used.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -107,6 +112,11 @@ pub struct AddressByIndexResponse {
 pub struct IndexByAddressRequest {
     #[prost(message, optional, tag = "1")]
     pub address: ::core::option::Option<super::super::core::crypto::v1alpha1::Address>,
+    /// Identifies the FVK to use.  If unset, the view service's default account is used.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -124,6 +134,11 @@ pub struct EphemeralAddressRequest {
     pub address_index: ::core::option::Option<
         super::super::core::crypto::v1alpha1::AddressIndex,
     >,
+    /// Identifies the FVK to use.  If unset, the view service's default account is used.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -246,6 +261,38 @@ pub struct NotesRequest {
     #[prost(message, optional, tag = "15")]
     pub token: ::core::option::Option<ViewAuthToken>,
 }
+/// A query for the liquidity positions controlled by the view service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionsRequest {
+    /// If set, return positions whose LPNFTs were spent as well as unspent ones.
+    #[prost(bool, tag = "2")]
+    pub include_spent: bool,
+    /// If set, only return positions in the specified state.
+    #[prost(message, optional, tag = "3")]
+    pub position_state: ::core::option::Option<
+        super::super::core::dex::v1alpha1::PositionState,
+    >,
+    /// Identifies the FVK for the positions to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountId,
+    >,
+    /// Authorizes the request.
+    #[prost(message, optional, tag = "15")]
+    pub token: ::core::option::Option<ViewAuthToken>,
+}
+/// A query for the staking report of an account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingRequest {
+    /// Identifies the FVK for the delegations to query.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<super::super::core::crypto::v1alpha1::AccountId>,
+    /// Authorizes the request.
+    #[prost(message, optional, tag = "15")]
+    pub token: ::core::option::Option<ViewAuthToken>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WitnessRequest {
@@ -447,6 +494,11 @@ pub struct TransactionsResponse {
 pub struct TransactionPerspectiveRequest {
     #[prost(bytes = "vec", tag = "1")]
     pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Identifies the FVK to use.  If unset, the view service's default account is used.
+    #[prost(message, optional, tag = "14")]
+    pub account_id: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AccountId,
+    >,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -466,6 +518,18 @@ pub struct NotesResponse {
     #[prost(message, optional, tag = "1")]
     pub note_record: ::core::option::Option<SpendableNoteRecord>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position_record: ::core::option::Option<PositionRecord>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingResponse {
+    #[prost(message, optional, tag = "1")]
+    pub report: ::core::option::Option<StakingReport>,
+}
 /// A note plaintext with associated metadata about its status.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -525,6 +589,86 @@ pub struct SwapRecord {
     #[prost(message, optional, tag = "7")]
     pub source: ::core::option::Option<super::super::core::chain::v1alpha1::NoteSource>,
 }
+/// An LPNFT note, together with the position it records ownership of.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRecord {
+    /// The LPNFT note itself.
+    #[prost(message, optional, tag = "1")]
+    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+    /// The LPNFT, identifying the position and its state.
+    #[prost(message, optional, tag = "2")]
+    pub lpnft: ::core::option::Option<super::super::core::dex::v1alpha1::LpNft>,
+}
+/// The staking rewards of an account's delegations, and the state of its
+/// unbonding tokens.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingReport {
+    /// The index of the epoch the report was computed in.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+    /// The account's delegations, by validator.
+    #[prost(message, repeated, tag = "2")]
+    pub delegations: ::prost::alloc::vec::Vec<DelegationReport>,
+    /// The account's unbonding tokens.
+    #[prost(message, repeated, tag = "3")]
+    pub unbondings: ::prost::alloc::vec::Vec<UnbondingReport>,
+}
+/// The rewards of an account's delegations to a single validator.
+///
+/// Amounts of stake are in units of the staking token. Rewards are signed,
+/// since a slashed validator's delegations can be worth less than was paid
+/// for them.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationReport {
+    /// The identity key of the validator delegated to.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The amount of the validator's delegation tokens currently held.
+    #[prost(message, optional, tag = "2")]
+    pub delegation_amount: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// The stake paid for the delegation tokens currently held.
+    #[prost(message, optional, tag = "3")]
+    pub cost_basis: ::core::option::Option<super::super::core::crypto::v1alpha1::Amount>,
+    /// The stake the delegation tokens currently held would unbond to.
+    #[prost(message, optional, tag = "4")]
+    pub current_value: ::core::option::Option<super::super::core::crypto::v1alpha1::Amount>,
+    /// The rewards realized by undelegating from the validator.
+    #[prost(int64, tag = "5")]
+    pub realized_rewards: i64,
+    /// The rewards accrued by the delegation tokens currently held.
+    #[prost(int64, tag = "6")]
+    pub unrealized_rewards: i64,
+}
+/// Unbonding tokens held by an account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingReport {
+    /// The identity key of the validator undelegated from.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::IdentityKey,
+    >,
+    /// The index of the epoch in which unbonding started.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The index of the epoch in which unbonding completes.
+    #[prost(uint64, tag = "3")]
+    pub end_epoch_index: u64,
+    /// The amount of unbonding tokens held.
+    #[prost(message, optional, tag = "4")]
+    pub amount: ::core::option::Option<super::super::core::crypto::v1alpha1::Amount>,
+    /// The number of epochs until the tokens can be claimed, or zero if they can
+    /// be claimed now.
+    #[prost(uint64, tag = "5")]
+    pub epochs_remaining: u64,
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_protocol_service_client {
@@ -670,6 +814,50 @@ pub mod view_protocol_service_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
+        /// Queries for the LPNFT notes recording ownership of liquidity positions.
+        pub async fn positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/Positions",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// Queries for the staking rewards of an account's delegations, and the state
+        /// of its unbonding tokens.
+        pub async fn staking(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StakingRequest>,
+        ) -> Result<tonic::Response<super::StakingResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/Staking",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -1138,6 +1326,23 @@ pub mod view_protocol_service_server {
             &self,
             request: tonic::Request<super::NotesRequest>,
         ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status>;
+        /// Server streaming response type for the Positions method.
+        type PositionsStream: futures_core::Stream<
+                Item = Result<super::PositionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for the LPNFT notes recording ownership of liquidity positions.
+        async fn positions(
+            &self,
+            request: tonic::Request<super::PositionsRequest>,
+        ) -> Result<tonic::Response<Self::PositionsStream>, tonic::Status>;
+        /// Queries for the staking rewards of an account's delegations, and the state
+        /// of its unbonding tokens.
+        async fn staking(
+            &self,
+            request: tonic::Request<super::StakingRequest>,
+        ) -> Result<tonic::Response<super::StakingResponse>, tonic::Status>;
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -1437,6 +1642,83 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/Positions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<super::PositionsRequest>
+                    for PositionsSvc<T> {
+                        type Response = super::PositionsResponse;
+                        type ResponseStream = T::PositionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).positions(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/Staking" => {
+                    #[allow(non_camel_case_types)]
+                    struct StakingSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::UnaryService<super::StakingRequest>
+                    for StakingSvc<T> {
+                        type Response = super::StakingResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StakingRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).staking(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StakingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/Witness" => {
                     #[allow(non_camel_case_types)]
                     struct WitnessSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -9,10 +9,16 @@ impl serde::Serialize for AddressByIndexRequest {
         if self.address_index.is_some() {
             len += 1;
         }
+        if self.account_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AddressByIndexRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -25,11 +31,14 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
         const FIELDS: &[&str] = &[
             "address_index",
             "addressIndex",
+            "account_id",
+            "accountId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
+            AccountId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -52,6 +61,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                     {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -72,6 +82,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address_index__ = None;
+                let mut account_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -80,10 +91,17 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                             }
                             address_index__ = map.next_value()?;
                         }
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(AddressByIndexRequest {
                     address_index: address_index__,
+                    account_id: account_id__,
                 })
             }
         }
@@ -704,6 +722,192 @@ impl<'de> serde::Deserialize<'de> for ChainParametersResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.ChainParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DelegationReport {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.cost_basis.is_some() {
+            len += 1;
+        }
+        if self.current_value.is_some() {
+            len += 1;
+        }
+        if self.realized_rewards != 0 {
+            len += 1;
+        }
+        if self.unrealized_rewards != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.DelegationReport", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
+        }
+        if let Some(v) = self.cost_basis.as_ref() {
+            struct_ser.serialize_field("costBasis", v)?;
+        }
+        if let Some(v) = self.current_value.as_ref() {
+            struct_ser.serialize_field("currentValue", v)?;
+        }
+        if self.realized_rewards != 0 {
+            struct_ser.serialize_field("realizedRewards", ToString::to_string(&self.realized_rewards).as_str())?;
+        }
+        if self.unrealized_rewards != 0 {
+            struct_ser.serialize_field("unrealizedRewards", ToString::to_string(&self.unrealized_rewards).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegationReport {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "delegation_amount",
+            "delegationAmount",
+            "cost_basis",
+            "costBasis",
+            "current_value",
+            "currentValue",
+            "realized_rewards",
+            "realizedRewards",
+            "unrealized_rewards",
+            "unrealizedRewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            DelegationAmount,
+            CostBasis,
+            CurrentValue,
+            RealizedRewards,
+            UnrealizedRewards,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "costBasis" | "cost_basis" => Ok(GeneratedField::CostBasis),
+                            "currentValue" | "current_value" => Ok(GeneratedField::CurrentValue),
+                            "realizedRewards" | "realized_rewards" => Ok(GeneratedField::RealizedRewards),
+                            "unrealizedRewards" | "unrealized_rewards" => Ok(GeneratedField::UnrealizedRewards),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegationReport;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.DelegationReport")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DelegationReport, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut delegation_amount__ = None;
+                let mut cost_basis__ = None;
+                let mut current_value__ = None;
+                let mut realized_rewards__ = None;
+                let mut unrealized_rewards__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                            }
+                            delegation_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::CostBasis => {
+                            if cost_basis__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("costBasis"));
+                            }
+                            cost_basis__ = map.next_value()?;
+                        }
+                        GeneratedField::CurrentValue => {
+                            if current_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentValue"));
+                            }
+                            current_value__ = map.next_value()?;
+                        }
+                        GeneratedField::RealizedRewards => {
+                            if realized_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realizedRewards"));
+                            }
+                            realized_rewards__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnrealizedRewards => {
+                            if unrealized_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unrealizedRewards"));
+                            }
+                            unrealized_rewards__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(DelegationReport {
+                    identity_key: identity_key__,
+                    delegation_amount: delegation_amount__,
+                    cost_basis: cost_basis__,
+                    current_value: current_value__,
+                    realized_rewards: realized_rewards__.unwrap_or_default(),
+                    unrealized_rewards: unrealized_rewards__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.DelegationReport", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -715,10 +919,16 @@ impl serde::Serialize for EphemeralAddressRequest {
         if self.address_index.is_some() {
             len += 1;
         }
+        if self.account_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -731,11 +941,14 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
         const FIELDS: &[&str] = &[
             "address_index",
             "addressIndex",
+            "account_id",
+            "accountId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
+            AccountId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -758,6 +971,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                     {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -778,6 +992,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address_index__ = None;
+                let mut account_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -786,10 +1001,17 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                             }
                             address_index__ = map.next_value()?;
                         }
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(EphemeralAddressRequest {
                     address_index: address_index__,
+                    account_id: account_id__,
                 })
             }
         }
@@ -1060,10 +1282,16 @@ impl serde::Serialize for IndexByAddressRequest {
         if self.address.is_some() {
             len += 1;
         }
+        if self.account_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", len)?;
         if let Some(v) = self.address.as_ref() {
             struct_ser.serialize_field("address", v)?;
         }
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1075,11 +1303,14 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     {
         const FIELDS: &[&str] = &[
             "address",
+            "account_id",
+            "accountId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Address,
+            AccountId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1102,6 +1333,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     {
                         match value {
                             "address" => Ok(GeneratedField::Address),
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1122,6 +1354,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address__ = None;
+                let mut account_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Address => {
@@ -1130,10 +1363,17 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                             }
                             address__ = map.next_value()?;
                         }
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(IndexByAddressRequest {
                     address: address__,
+                    account_id: account_id__,
                 })
             }
         }
@@ -1979,7 +2219,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for PositionRecord {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1987,32 +2227,378 @@ impl serde::Serialize for SpendableNoteRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.note.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        if self.address_index.is_some() {
+        if self.lpnft.is_some() {
             len += 1;
         }
-        if self.nullifier.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PositionRecord", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
-        if self.height_created != 0 {
-            len += 1;
+        if let Some(v) = self.lpnft.as_ref() {
+            struct_ser.serialize_field("lpnft", v)?;
         }
-        if self.height_spent.is_some() {
-            len += 1;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
+            "lpnft",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteRecord,
+            Lpnft,
         }
-        if self.position != 0 {
-            len += 1;
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "lpnft" => Ok(GeneratedField::Lpnft),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
         }
-        if self.source.is_some() {
-            len += 1;
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.PositionRecord")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_record__ = None;
+                let mut lpnft__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map.next_value()?;
+                        }
+                        GeneratedField::Lpnft => {
+                            if lpnft__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpnft"));
+                            }
+                            lpnft__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(PositionRecord {
+                    note_record: note_record__,
+                    lpnft: lpnft__,
+                })
+            }
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PositionRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.include_spent {
+            len += 1;
+        }
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.account_id.is_some() {
+            len += 1;
+        }
+        if self.token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PositionsRequest", len)?;
+        if self.include_spent {
+            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
+        }
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
+        if let Some(v) = self.token.as_ref() {
+            struct_ser.serialize_field("token", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "include_spent",
+            "includeSpent",
+            "position_state",
+            "positionState",
+            "account_id",
+            "accountId",
+            "token",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncludeSpent,
+            PositionState,
+            AccountId,
+            Token,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
+                            "token" => Ok(GeneratedField::Token),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.PositionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut include_spent__ = None;
+                let mut position_state__ = None;
+                let mut account_id__ = None;
+                let mut token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IncludeSpent => {
+                            if include_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSpent"));
+                            }
+                            include_spent__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
+                            }
+                            token__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(PositionsRequest {
+                    include_spent: include_spent__.unwrap_or_default(),
+                    position_state: position_state__,
+                    account_id: account_id__,
+                    token: token__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PositionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PositionsResponse", len)?;
+        if let Some(v) = self.position_record.as_ref() {
+            struct_ser.serialize_field("positionRecord", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_record",
+            "positionRecord",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionRecord,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionRecord" | "position_record" => Ok(GeneratedField::PositionRecord),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.PositionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PositionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_record__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PositionRecord => {
+                            if position_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionRecord"));
+                            }
+                            position_record__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(PositionsResponse {
+                    position_record: position_record__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PositionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.height_spent.is_some() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
             struct_ser.serialize_field("noteCommitment", v)?;
         }
         if let Some(v) = self.note.as_ref() {
@@ -2030,46 +2616,443 @@ impl serde::Serialize for SpendableNoteRecord {
         if let Some(v) = self.height_spent.as_ref() {
             struct_ser.serialize_field("heightSpent", ToString::to_string(&v).as_str())?;
         }
-        if self.position != 0 {
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        if self.position != 0 {
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "note",
+            "address_index",
+            "addressIndex",
+            "nullifier",
+            "height_created",
+            "heightCreated",
+            "height_spent",
+            "heightSpent",
+            "position",
+            "source",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            Note,
+            AddressIndex,
+            Nullifier,
+            HeightCreated,
+            HeightSpent,
+            Position,
+            Source,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "note" => Ok(GeneratedField::Note),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
+                            "position" => Ok(GeneratedField::Position),
+                            "source" => Ok(GeneratedField::Source),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpendableNoteRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.SpendableNoteRecord")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut note__ = None;
+                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut height_created__ = None;
+                let mut height_spent__ = None;
+                let mut position__ = None;
+                let mut source__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightSpent => {
+                            if height_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSpent"));
+                            }
+                            height_spent__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(SpendableNoteRecord {
+                    note_commitment: note_commitment__,
+                    note: note__,
+                    address_index: address_index__,
+                    nullifier: nullifier__,
+                    height_created: height_created__.unwrap_or_default(),
+                    height_spent: height_spent__,
+                    position: position__.unwrap_or_default(),
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingReport {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if !self.delegations.is_empty() {
+            len += 1;
+        }
+        if !self.unbondings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StakingReport", len)?;
+        if self.epoch_index != 0 {
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
+        }
+        if !self.unbondings.is_empty() {
+            struct_ser.serialize_field("unbondings", &self.unbondings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingReport {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "delegations",
+            "unbondings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochIndex,
+            Delegations,
+            Unbondings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            "unbondings" => Ok(GeneratedField::Unbondings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingReport;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StakingReport")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StakingReport, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_index__ = None;
+                let mut delegations__ = None;
+                let mut unbondings__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
+                            }
+                            delegations__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Unbondings => {
+                            if unbondings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondings"));
+                            }
+                            unbondings__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(StakingReport {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    delegations: delegations__.unwrap_or_default(),
+                    unbondings: unbondings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StakingReport", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account_id.is_some() {
+            len += 1;
+        }
+        if self.token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StakingRequest", len)?;
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
+        if let Some(v) = self.token.as_ref() {
+            struct_ser.serialize_field("token", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_id",
+            "accountId",
+            "token",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccountId,
+            Token,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
+                            "token" => Ok(GeneratedField::Token),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StakingRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StakingRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account_id__ = None;
+                let mut token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
+                            }
+                            token__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(StakingRequest {
+                    account_id: account_id__,
+                    token: token__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StakingRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.report.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StakingResponse", len)?;
+        if let Some(v) = self.report.as_ref() {
+            struct_ser.serialize_field("report", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+impl<'de> serde::Deserialize<'de> for StakingResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "note",
-            "address_index",
-            "addressIndex",
-            "nullifier",
-            "height_created",
-            "heightCreated",
-            "height_spent",
-            "heightSpent",
-            "position",
-            "source",
+            "report",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            Note,
-            AddressIndex,
-            Nullifier,
-            HeightCreated,
-            HeightSpent,
-            Position,
-            Source,
+            Report,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2091,14 +3074,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "note" => Ok(GeneratedField::Note),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
-                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
-                            "position" => Ok(GeneratedField::Position),
-                            "source" => Ok(GeneratedField::Source),
+                            "report" => Ok(GeneratedField::Report),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2108,95 +3084,33 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpendableNoteRecord;
+            type Value = StakingResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.SpendableNoteRecord")
+                formatter.write_str("struct penumbra.view.v1alpha1.StakingResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StakingResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut note__ = None;
-                let mut address_index__ = None;
-                let mut nullifier__ = None;
-                let mut height_created__ = None;
-                let mut height_spent__ = None;
-                let mut position__ = None;
-                let mut source__ = None;
+                let mut report__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map.next_value()?;
-                        }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
-                            }
-                            note__ = map.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map.next_value()?;
-                        }
-                        GeneratedField::HeightCreated => {
-                            if height_created__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightCreated"));
-                            }
-                            height_created__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::HeightSpent => {
-                            if height_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightSpent"));
-                            }
-                            height_spent__ = 
-                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
-                            ;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
+                        GeneratedField::Report => {
+                            if report__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("report"));
                             }
-                            source__ = map.next_value()?;
+                            report__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(SpendableNoteRecord {
-                    note_commitment: note_commitment__,
-                    note: note__,
-                    address_index: address_index__,
-                    nullifier: nullifier__,
-                    height_created: height_created__.unwrap_or_default(),
-                    height_spent: height_spent__,
-                    position: position__.unwrap_or_default(),
-                    source: source__,
+                Ok(StakingResponse {
+                    report: report__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StakingResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for StatusRequest {
@@ -3503,10 +4417,16 @@ impl serde::Serialize for TransactionPerspectiveRequest {
         if !self.tx_hash.is_empty() {
             len += 1;
         }
+        if self.account_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPerspectiveRequest", len)?;
         if !self.tx_hash.is_empty() {
             struct_ser.serialize_field("txHash", pbjson::private::base64::encode(&self.tx_hash).as_str())?;
         }
+        if let Some(v) = self.account_id.as_ref() {
+            struct_ser.serialize_field("accountId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3519,11 +4439,14 @@ impl<'de> serde::Deserialize<'de> for TransactionPerspectiveRequest {
         const FIELDS: &[&str] = &[
             "tx_hash",
             "txHash",
+            "account_id",
+            "accountId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TxHash,
+            AccountId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3546,6 +4469,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPerspectiveRequest {
                     {
                         match value {
                             "txHash" | "tx_hash" => Ok(GeneratedField::TxHash),
+                            "accountId" | "account_id" => Ok(GeneratedField::AccountId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3566,6 +4490,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPerspectiveRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut tx_hash__ = None;
+                let mut account_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::TxHash => {
@@ -3576,10 +4501,17 @@ impl<'de> serde::Deserialize<'de> for TransactionPerspectiveRequest {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::AccountId => {
+                            if account_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountId"));
+                            }
+                            account_id__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionPerspectiveRequest {
                     tx_hash: tx_hash__.unwrap_or_default(),
+                    account_id: account_id__,
                 })
             }
         }
@@ -4695,6 +5627,175 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UnbondingReport {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.epochs_remaining != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.UnbondingReport", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if self.epochs_remaining != 0 {
+            struct_ser.serialize_field("epochsRemaining", ToString::to_string(&self.epochs_remaining).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondingReport {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+            "amount",
+            "epochs_remaining",
+            "epochsRemaining",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            StartEpochIndex,
+            EndEpochIndex,
+            Amount,
+            EpochsRemaining,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "epochsRemaining" | "epochs_remaining" => Ok(GeneratedField::EpochsRemaining),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondingReport;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.UnbondingReport")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnbondingReport, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                let mut amount__ = None;
+                let mut epochs_remaining__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map.next_value()?;
+                        }
+                        GeneratedField::EpochsRemaining => {
+                            if epochs_remaining__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochsRemaining"));
+                            }
+                            epochs_remaining__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UnbondingReport {
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                    amount: amount__,
+                    epochs_remaining: epochs_remaining__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.UnbondingReport", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ViewAuthRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -30,6 +30,11 @@
 //! values, backed directly by RocksDB.  This is intended for use building
 //! application-specific indexes of the verifiable consensus state.
 //!
+//! The complete contents of a [`Snapshot`] can be exported with
+//! [`Snapshot::export`] and used to restore an empty [`Storage`], by staging
+//! it with [`Storage::stage_restore`] and then calling
+//! [`Storage::prepare_restore`], e.g., to bootstrap a node using state sync.
+//!
 //! While the primary key-value store records byte values, it is intended for
 //! use with Protobuf-encoded data.  To this end, the [`StateRead`] and
 //! [`StateWrite`] traits have provided methods that use the
@@ -51,8 +56,8 @@ pub use crate::metrics::register_metrics;
 pub use delta::{ArcStateDeltaExt, StateDelta};
 pub use jmt::{ics23_spec, RootHash};
 pub use read::StateRead;
-pub use snapshot::Snapshot;
-pub use storage::{Storage, TempStorage};
+pub use snapshot::{ExportedEntry, Snapshot};
+pub use storage::{Restore, Storage, TempStorage};
 pub use write::StateWrite;
 
 pub mod future;
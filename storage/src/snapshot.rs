@@ -11,6 +11,15 @@ use crate::{metrics, StateRead};
 mod rocks_wrapper;
 use rocks_wrapper::RocksDbSnapshot;
 
+/// A single entry of the state exported by [`Snapshot::export`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExportedEntry {
+    /// A key-value pair in the verifiable, JMT-backed consensus state.
+    Consensus { key: String, value: Vec<u8> },
+    /// A key-value pair in the nonconsensus index.
+    Nonconsensus { key: Vec<u8>, value: Vec<u8> },
+}
+
 /// A snapshot of the underlying storage at a specific state version, suitable
 /// for read-only access by multiple threads, e.g., RPC calls.
 ///
@@ -96,6 +105,79 @@ impl Snapshot {
             .await?
     }
 
+    /// Exports the entire contents of this snapshot, both the verifiable
+    /// consensus state and the nonconsensus index, as a stream of
+    /// [`ExportedEntry`]s.
+    ///
+    /// The consensus entries are exported as key-value pairs rather than as
+    /// JMT nodes, so that a [`Storage`](crate::Storage) restored from them with
+    /// [`Storage::prepare_restore`](crate::Storage::prepare_restore) recomputes
+    /// (and can therefore verify) the root hash of the tree.
+    pub fn export(&self) -> tokio_stream::wrappers::ReceiverStream<Result<ExportedEntry>> {
+        let span = Span::current();
+        let self2 = self.clone();
+
+        let (tx, rx) = mpsc::channel(100);
+
+        tokio::task::Builder::new()
+            .name("Snapshot::export")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    // Unlike the prefix streams, a truncated export is not a
+                    // valid export, so forward any error to the consumer.
+                    if let Err(e) = self2.export_inner(&tx) {
+                        let _ = tx.blocking_send(Err(e));
+                    }
+                })
+            })
+            .expect("should be able to spawn_blocking");
+
+        tokio_stream::wrappers::ReceiverStream::new(rx)
+    }
+
+    /// Internal helper function used by `export`.
+    fn export_inner(&self, tx: &mpsc::Sender<Result<ExportedEntry>>) -> Result<()> {
+        let keys_cf = self
+            .0
+            .db
+            .cf_handle("jmt_keys")
+            .expect("jmt_keys column family not found");
+        let iter = self
+            .0
+            .snapshot
+            .iterator_cf(keys_cf, rocksdb::IteratorMode::Start);
+        for i in iter {
+            // As in `prefix_raw`, we use the key preimages to look up the values in the JMT.
+            let (key_preimage, _key_hash) = i?;
+            let key = std::str::from_utf8(key_preimage.as_ref())
+                .expect("saved jmt keys are utf-8 strings")
+                .to_string();
+            let value = self
+                .get_jmt(key.as_bytes().into())?
+                .expect("keys in jmt_keys should have a corresponding value in jmt");
+            tx.blocking_send(Ok(ExportedEntry::Consensus { key, value }))?;
+        }
+
+        let nonconsensus_cf = self
+            .0
+            .db
+            .cf_handle("nonconsensus")
+            .expect("nonconsensus column family not found");
+        let iter = self
+            .0
+            .snapshot
+            .iterator_cf(nonconsensus_cf, rocksdb::IteratorMode::Start);
+        for i in iter {
+            let (key, value) = i?;
+            tx.blocking_send(Ok(ExportedEntry::Nonconsensus {
+                key: key.into(),
+                value: value.into(),
+            }))?;
+        }
+
+        Ok(())
+    }
+
     /// Internal helper function used by `get_raw` and `prefix_raw`.
     ///
     /// Reads from the JMT will fail if the root is missing; this method
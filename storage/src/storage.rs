@@ -10,12 +10,17 @@ use rocksdb::{Options, DB};
 use tokio::sync::watch;
 use tracing::Span;
 
-use crate::{cache::Cache, snapshot::Snapshot};
+use crate::{cache::Cache, snapshot::Snapshot, ExportedEntry};
 use crate::{snapshot_cache::SnapshotCache, StateDelta};
 
+mod restore;
 mod temp;
+pub use restore::Restore;
 pub use temp::TempStorage;
 
+/// The number of recent [`Snapshot`]s kept in the [`SnapshotCache`].
+const SNAPSHOT_CACHE_SIZE: usize = 10;
+
 /// A handle for a storage instance, backed by RocksDB.
 ///
 /// The handle is cheaply clonable; all clones share the same backing data store.
@@ -51,7 +56,7 @@ impl Storage {
                     let db = Arc::new(DB::open_cf(
                         &opts,
                         path,
-                        ["jmt", "nonconsensus", "jmt_keys"],
+                        ["jmt", "nonconsensus", "jmt_keys", restore::STAGING_CF],
                     )?);
 
                     // TODO: For compatibility reasons with Tendermint, we set the "pre-genesis"
@@ -63,7 +68,8 @@ impl Storage {
                     // We discard the receiver here, because we'll construct new ones in subscribe()
                     let (snapshot_tx, _) = watch::channel(latest_snapshot.clone());
 
-                    let snapshots = RwLock::new(SnapshotCache::new(latest_snapshot, 10));
+                    let snapshots =
+                        RwLock::new(SnapshotCache::new(latest_snapshot, SNAPSHOT_CACHE_SIZE));
 
                     Ok(Self(Arc::new(Inner {
                         snapshots,
@@ -202,6 +208,68 @@ impl Storage {
         self.commit_inner(changes, new_version).await
     }
 
+    /// Stages part of the state exported with [`Snapshot::export`], to
+    /// restore this (empty) [`Storage`] from with [`Storage::prepare_restore`].
+    ///
+    /// Staged entries are kept on disk, apart from the chain state, so the
+    /// exported state can be staged in pieces as it arrives. Staging the same
+    /// entry twice has no further effect.
+    pub async fn stage_restore(&self, entries: Vec<ExportedEntry>) -> Result<()> {
+        self.check_empty()?;
+
+        let span = Span::current();
+        let db = self.0.db.clone();
+
+        tokio::task::Builder::new()
+            .name("Storage::stage_restore")
+            .spawn_blocking(move || span.in_scope(|| restore::stage(&db, entries)))?
+            .await?
+    }
+
+    /// Discards all state staged with [`Storage::stage_restore`], e.g., to
+    /// abandon a restore.
+    pub async fn clear_restore(&self) -> Result<()> {
+        let span = Span::current();
+        let db = self.0.db.clone();
+
+        tokio::task::Builder::new()
+            .name("Storage::clear_restore")
+            .spawn_blocking(move || span.in_scope(|| restore::clear(&db)))?
+            .await?
+    }
+
+    /// Prepares to restore this (empty) [`Storage`] at the given `version`
+    /// from the state staged with [`Storage::stage_restore`].
+    ///
+    /// This rebuilds the JMT over the staged consensus state in memory, but
+    /// writes nothing to the backing store until [`Restore::commit`] is called,
+    /// so that the caller can first check the recomputed
+    /// [`Restore::root_hash`] against a trusted value.
+    pub async fn prepare_restore(&self, version: jmt::Version) -> Result<Restore> {
+        self.check_empty()?;
+        if version == u64::MAX {
+            return Err(anyhow::anyhow!("cannot restore the pre-genesis version"));
+        }
+
+        let span = Span::current();
+        let storage = self.clone();
+
+        tokio::task::Builder::new()
+            .name("Storage::prepare_restore")
+            .spawn_blocking(move || span.in_scope(|| Restore::prepare(storage, version)))?
+            .await?
+    }
+
+    fn check_empty(&self) -> Result<()> {
+        if self.latest_version() != u64::MAX {
+            return Err(anyhow::anyhow!(
+                "can only restore into an empty storage, but found state at version {}",
+                self.latest_version()
+            ));
+        }
+        Ok(())
+    }
+
     /// Returns the internal handle to RocksDB, this is useful to test adjacent storage crates.
     #[cfg(test)]
     pub(crate) fn db(&self) -> Arc<DB> {
@@ -0,0 +1,223 @@
+use anyhow::Result;
+use jmt::{
+    storage::{LeafNode, Node, NodeBatch, NodeKey, TreeReader},
+    JellyfishMerkleTree, KeyHash,
+};
+use rocksdb::{IteratorMode, WriteBatch, DB};
+
+use super::{SnapshotCache, Storage, SNAPSHOT_CACHE_SIZE};
+use crate::{snapshot::Snapshot, ExportedEntry, RootHash};
+
+/// The column family holding exported entries staged for a restore.
+pub(super) const STAGING_CF: &str = "restore_staging";
+
+/// Staged consensus entries are keyed by this tag followed by their key.
+const CONSENSUS_TAG: u8 = 0;
+/// Staged nonconsensus entries are keyed by this tag followed by their key.
+const NONCONSENSUS_TAG: u8 = 1;
+
+/// A pending restoration of an empty [`Storage`] from exported state, created
+/// by [`Storage::prepare_restore`].
+///
+/// The JMT has already been rebuilt in memory, so its root hash can be checked
+/// before anything is written to the backing store with [`Restore::commit`].
+pub struct Restore {
+    storage: Storage,
+    version: jmt::Version,
+    root_hash: RootHash,
+    node_batch: NodeBatch,
+    jmt_keys: Vec<(String, KeyHash)>,
+}
+
+impl std::fmt::Debug for Restore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Restore")
+            .field("version", &self.version)
+            .field("root_hash", &self.root_hash)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`TreeReader`] for an empty tree, which reports an empty root node at the
+/// version preceding the restored version.
+///
+/// The JMT can only write a new version on top of the root of the previous
+/// one, so this lets us rebuild the tree directly at the restored version
+/// without writing any placeholder nodes to the backing store.
+struct EmptyTree {
+    version: jmt::Version,
+}
+
+impl TreeReader for EmptyTree {
+    fn get_node_option(&self, node_key: &NodeKey) -> Result<Option<Node>> {
+        if *node_key == NodeKey::new_empty_path(self.version.wrapping_sub(1)) {
+            Ok(Some(Node::Null))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn get_rightmost_leaf(&self) -> Result<Option<(NodeKey, LeafNode)>> {
+        Ok(None)
+    }
+}
+
+/// Writes exported entries to the staging column family.
+///
+/// This performs blocking work, and is called by [`Storage::stage_restore`].
+pub(super) fn stage(db: &DB, entries: Vec<ExportedEntry>) -> Result<()> {
+    let staging_cf = db
+        .cf_handle(STAGING_CF)
+        .expect("restore_staging column family not found");
+    let mut batch = WriteBatch::default();
+    for entry in entries {
+        let (tag, key, value) = match entry {
+            ExportedEntry::Consensus { key, value } => (CONSENSUS_TAG, key.into_bytes(), value),
+            ExportedEntry::Nonconsensus { key, value } => (NONCONSENSUS_TAG, key, value),
+        };
+        let mut staged_key = Vec::with_capacity(1 + key.len());
+        staged_key.push(tag);
+        staged_key.extend(key);
+        batch.put_cf(staging_cf, staged_key, value);
+    }
+    db.write(batch)?;
+    Ok(())
+}
+
+/// Discards all staged entries.
+///
+/// This performs blocking work, and is called by [`Storage::clear_restore`].
+pub(super) fn clear(db: &DB) -> Result<()> {
+    let mut batch = WriteBatch::default();
+    clear_staging(db, &mut batch);
+    db.write(batch)?;
+    Ok(())
+}
+
+/// Adds the deletion of all staged entries to `batch`.
+fn clear_staging(db: &DB, batch: &mut WriteBatch) {
+    let staging_cf = db
+        .cf_handle(STAGING_CF)
+        .expect("restore_staging column family not found");
+    batch.delete_range_cf(staging_cf, [CONSENSUS_TAG], [NONCONSENSUS_TAG + 1]);
+}
+
+impl Restore {
+    /// Rebuilds the JMT over the staged consensus state at the given version.
+    ///
+    /// This performs blocking work, and is called by [`Storage::prepare_restore`].
+    pub(super) fn prepare(storage: Storage, version: jmt::Version) -> Result<Self> {
+        let db = storage.0.db.clone();
+        let staging_cf = db
+            .cf_handle(STAGING_CF)
+            .expect("restore_staging column family not found");
+
+        let mut jmt_keys = Vec::new();
+        let mut values = Vec::new();
+        for entry in db.iterator_cf(staging_cf, IteratorMode::Start) {
+            let (staged_key, value) = entry?;
+            if staged_key.first() != Some(&CONSENSUS_TAG) {
+                // Nonconsensus entries sort after all consensus entries.
+                break;
+            }
+            let key = String::from_utf8(staged_key[1..].to_vec())?;
+            let keyhash = KeyHash::from(&key);
+            jmt_keys.push((key, keyhash));
+            values.push((keyhash, Some(value.into_vec())));
+        }
+
+        let reader = EmptyTree { version };
+        let tree = JellyfishMerkleTree::new(&reader);
+        let (root_hash, batch) = tree.put_value_set(values, version)?;
+        tracing::debug!(?version, ?root_hash, "rebuilt jmt from exported state");
+
+        Ok(Self {
+            storage,
+            version,
+            root_hash,
+            node_batch: batch.node_batch,
+            jmt_keys,
+        })
+    }
+
+    /// The version of the state being restored.
+    pub fn version(&self) -> jmt::Version {
+        self.version
+    }
+
+    /// The root hash of the JMT rebuilt from the exported consensus state.
+    pub fn root_hash(&self) -> RootHash {
+        self.root_hash
+    }
+
+    /// Writes the restored state to the backing store, making it the latest
+    /// version of the [`Storage`].
+    pub async fn commit(self) -> Result<RootHash> {
+        let span = tracing::Span::current();
+
+        tokio::task::Builder::new()
+            .name("Restore::commit")
+            .spawn_blocking(move || span.in_scope(|| self.commit_inner()))?
+            .await?
+    }
+
+    fn commit_inner(self) -> Result<RootHash> {
+        let inner = self.storage.0.clone();
+
+        // Check again, in case the storage was written to since we prepared the restore.
+        if inner.snapshots.read().latest().version() != u64::MAX {
+            return Err(anyhow::anyhow!(
+                "storage was written to while preparing restore"
+            ));
+        }
+
+        // Write the whole restored state in a single batch, so that a crash
+        // can't leave a partially restored state behind.
+        let mut batch = WriteBatch::default();
+
+        let jmt_keys_cf = inner
+            .db
+            .cf_handle("jmt_keys")
+            .expect("jmt_keys column family not found");
+        for (key_preimage, keyhash) in self.jmt_keys.iter() {
+            batch.put_cf(jmt_keys_cf, key_preimage, keyhash.0);
+        }
+
+        let jmt_cf = inner
+            .db
+            .cf_handle("jmt")
+            .expect("jmt column family not found");
+        for (node_key, node) in self.node_batch.iter() {
+            batch.put_cf(jmt_cf, node_key.encode()?, node.encode()?);
+        }
+
+        let staging_cf = inner
+            .db
+            .cf_handle(STAGING_CF)
+            .expect("restore_staging column family not found");
+        let nonconsensus_cf = inner
+            .db
+            .cf_handle("nonconsensus")
+            .expect("nonconsensus column family not found");
+        let mode = IteratorMode::From(&[NONCONSENSUS_TAG], rocksdb::Direction::Forward);
+        for entry in inner.db.iterator_cf(staging_cf, mode) {
+            let (staged_key, value) = entry?;
+            batch.put_cf(nonconsensus_cf, &staged_key[1..], value);
+        }
+
+        clear_staging(&inner.db, &mut batch);
+        inner.db.write(batch)?;
+        tracing::trace!(root_hash = ?self.root_hash, "wrote restored state to backing store");
+
+        // The snapshot cache requires consecutive versions, so rather than
+        // pushing onto it, replace it with one starting at the restored version.
+        let latest_snapshot = Snapshot::new(inner.db.clone(), self.version);
+        *inner.snapshots.write() = SnapshotCache::new(latest_snapshot.clone(), SNAPSHOT_CACHE_SIZE);
+
+        // As in `commit`, ignore the error if there is no one to notify.
+        let _ = inner.state_tx.send(latest_snapshot);
+
+        tracing::info!(version = ?self.version, root_hash = ?self.root_hash, "restored storage");
+        Ok(self.root_hash)
+    }
+}
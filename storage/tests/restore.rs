@@ -0,0 +1,137 @@
+use ::futures::StreamExt;
+use penumbra_storage::*;
+
+#[tokio::test]
+async fn export_and_restore() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+
+    // Version 0: a mix of consensus and nonconsensus writes.
+    let mut state0 = StateDelta::new(storage.latest_snapshot());
+    state0.put_raw("a/aa".to_owned(), b"aa".to_vec());
+    state0.put_raw("a/ab".to_owned(), b"ab".to_vec());
+    state0.put_raw("b/c".to_owned(), b"c".to_vec());
+    state0.nonconsensus_put_raw(b"iA".to_vec(), b"A".to_vec());
+    state0.nonconsensus_put_raw(b"iB".to_vec(), b"B".to_vec());
+    storage.commit(state0).await?;
+
+    // Version 1: overwrite, delete, and add keys, so the exported state is not
+    // simply the writes of a single version.
+    let mut state1 = StateDelta::new(storage.latest_snapshot());
+    state1.put_raw("a/aa".to_owned(), b"aa2".to_vec());
+    state1.delete("a/ab".to_owned());
+    state1.put_raw("c/d".to_owned(), b"d".to_vec());
+    state1.nonconsensus_delete(b"iA".to_vec());
+    let root_hash = storage.commit(state1).await?;
+
+    let snapshot = storage.latest_snapshot();
+    let entries = snapshot
+        .export()
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert_eq!(
+        entries,
+        vec![
+            ExportedEntry::Consensus {
+                key: "a/aa".to_owned(),
+                value: b"aa2".to_vec()
+            },
+            ExportedEntry::Consensus {
+                key: "b/c".to_owned(),
+                value: b"c".to_vec()
+            },
+            ExportedEntry::Consensus {
+                key: "c/d".to_owned(),
+                value: b"d".to_vec()
+            },
+            ExportedEntry::Nonconsensus {
+                key: b"iB".to_vec(),
+                value: b"B".to_vec()
+            },
+        ]
+    );
+
+    // Restoring into non-empty storage is rejected.
+    assert!(storage.stage_restore(entries.clone()).await.is_err());
+    assert!(storage.prepare_restore(snapshot.version()).await.is_err());
+
+    // Restore into a fresh storage, staging the entries in overlapping pieces
+    // and checking the rebuilt root before committing.
+    let restored = TempStorage::new().await?;
+    restored.stage_restore(entries[..3].to_vec()).await?;
+    restored.stage_restore(entries[2..].to_vec()).await?;
+    let restore = restored.prepare_restore(snapshot.version()).await?;
+    assert_eq!(restore.root_hash(), root_hash);
+    assert_eq!(restored.latest_version(), u64::MAX);
+    restore.commit().await?;
+
+    let restored_snapshot = restored.latest_snapshot();
+    assert_eq!(restored_snapshot.version(), snapshot.version());
+    assert_eq!(restored_snapshot.root_hash().await?, root_hash);
+    assert_eq!(
+        restored_snapshot.get_raw("a/aa").await?,
+        Some(b"aa2".to_vec())
+    );
+    assert_eq!(restored_snapshot.get_raw("a/ab").await?, None);
+    assert_eq!(
+        restored_snapshot.nonconsensus_get_raw(b"iB").await?,
+        Some(b"B".to_vec())
+    );
+    assert_eq!(restored_snapshot.nonconsensus_get_raw(b"iA").await?, None);
+
+    // The restored storage can keep committing new versions, in lockstep with the original.
+    let mut state2 = StateDelta::new(storage.latest_snapshot());
+    state2.put_raw("d/e".to_owned(), b"e".to_vec());
+    let root_hash = storage.commit(state2).await?;
+
+    let mut restored_state2 = StateDelta::new(restored.latest_snapshot());
+    restored_state2.put_raw("d/e".to_owned(), b"e".to_vec());
+    let restored_root_hash = restored.commit(restored_state2).await?;
+
+    assert_eq!(restored_root_hash, root_hash);
+    assert_eq!(restored.latest_version(), storage.latest_version());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cleared_restore_is_discarded() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_raw("a/aa".to_owned(), b"aa".to_vec());
+    state.nonconsensus_put_raw(b"iA".to_vec(), b"A".to_vec());
+    let root_hash = storage.commit(state).await?;
+
+    let snapshot = storage.latest_snapshot();
+    let entries = snapshot
+        .export()
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    // Entries staged for an abandoned restore don't leak into the next one.
+    let restored = TempStorage::new().await?;
+    restored
+        .stage_restore(vec![ExportedEntry::Consensus {
+            key: "stale".to_owned(),
+            value: b"stale".to_vec(),
+        }])
+        .await?;
+    restored.clear_restore().await?;
+    restored.stage_restore(entries).await?;
+
+    let restore = restored.prepare_restore(snapshot.version()).await?;
+    assert_eq!(restore.root_hash(), root_hash);
+    restore.commit().await?;
+
+    let restored_snapshot = restored.latest_snapshot();
+    assert_eq!(restored_snapshot.get_raw("stale").await?, None);
+    assert_eq!(
+        restored_snapshot.nonconsensus_get_raw(b"iA").await?,
+        Some(b"A".to_vec())
+    );
+
+    Ok(())
+}
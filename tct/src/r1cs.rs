@@ -28,7 +28,9 @@ impl AllocVar<Position, Fq> for PositionVar {
         let inner: Position = *f()?.borrow();
         match mode {
             AllocationMode::Constant => unimplemented!(),
-            AllocationMode::Input => unimplemented!(),
+            AllocationMode::Input => Ok(Self {
+                inner: FqVar::new_input(cs, || Ok(Fq::from(u64::from(inner))))?,
+            }),
             AllocationMode::Witness => Ok(Self {
                 inner: FqVar::new_witness(cs, || Ok(Fq::from(u64::from(inner))))?,
             }),
@@ -7,7 +7,10 @@ use std::{
 use ark_groth16::{ProvingKey, VerifyingKey};
 use ark_serialize::CanonicalSerialize;
 use decaf377::Bls12_377;
-use penumbra_crypto::proofs::groth16::{OutputCircuit, ParameterSetup, SpendCircuit};
+use penumbra_crypto::proofs::groth16::{
+    DelegatorVoteCircuit, OutputCircuit, ParameterSetup, SpendCircuit, SwapCircuit,
+    SwapClaimCircuit, UndelegateClaimCircuit,
+};
 
 fn main() -> Result<()> {
     let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -26,6 +29,25 @@ fn main() -> Result<()> {
     write_params(&target_dir, "spend", &spend_pk, &spend_vk)?;
     let (output_pk, output_vk) = OutputCircuit::generate_test_parameters();
     write_params(&target_dir, "output", &output_pk, &output_vk)?;
+    let (swap_pk, swap_vk) = SwapCircuit::generate_test_parameters();
+    write_params(&target_dir, "swap", &swap_pk, &swap_vk)?;
+    let (swapclaim_pk, swapclaim_vk) = SwapClaimCircuit::generate_test_parameters();
+    write_params(&target_dir, "swapclaim", &swapclaim_pk, &swapclaim_vk)?;
+    let (undelegateclaim_pk, undelegateclaim_vk) =
+        UndelegateClaimCircuit::generate_test_parameters();
+    write_params(
+        &target_dir,
+        "undelegateclaim",
+        &undelegateclaim_pk,
+        &undelegateclaim_vk,
+    )?;
+    let (delegatorvote_pk, delegatorvote_vk) = DelegatorVoteCircuit::generate_test_parameters();
+    write_params(
+        &target_dir,
+        "delegatorvote",
+        &delegatorvote_pk,
+        &delegatorvote_vk,
+    )?;
     // NOTE: New proofs go here following the approach above.
 
     Ok(())
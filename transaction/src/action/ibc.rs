@@ -30,6 +30,9 @@ pub struct Ics20Withdrawal {
     // we MUST verify a nonexistence proof before accepting the timeout, to
     // prevent relayer censorship attacks. The core IBC implementation does this
     // in its handling of validation of timeouts.
+    //
+    // the height is in the counterparty chain's current revision, as given by
+    // its chain id; zero means the transfer has no height timeout.
     pub timeout_height: u64,
     // the timestamp at which this transfer expires.
     pub timeout_time: u64,
@@ -69,9 +72,6 @@ impl Ics20Withdrawal {
 
     // stateless validation of an Ics20 withdrawal action.
     pub fn validate(&self) -> anyhow::Result<()> {
-        if self.timeout_height == 0 {
-            anyhow::bail!("timeout height must be non-zero");
-        }
         if self.timeout_time == 0 {
             anyhow::bail!("timeout time must be non-zero");
         }
@@ -138,7 +138,9 @@ impl From<Ics20Withdrawal> for pb::FungibleTokenPacketData {
     fn from(w: Ics20Withdrawal) -> Self {
         pb::FungibleTokenPacketData {
             amount: w.value().amount.to_string(),
-            denom: w.value().asset_id.to_string(), // NOTE: should this be a `Denom` instead?
+            // The full denom trace, so that the counterparty chain and any
+            // refund can tell where the tokens originated.
+            denom: w.denom.to_string(),
             receiver: w.destination_chain_address,
             sender: w.return_address.to_string(),
         }
@@ -49,17 +49,7 @@ impl PositionOpen {
             asset_id: LpNft::new(self.position.id(), position::State::Opened).asset_id(),
         };
 
-        let r1 = Value {
-            amount: self.initial_reserves.r1,
-            asset_id: self.position.phi.pair.asset_1(),
-        };
-
-        let r2 = Value {
-            amount: self.initial_reserves.r2,
-            asset_id: self.position.phi.pair.asset_2(),
-        };
-
-        let reserves = Balance::from(r1) + r2;
+        let reserves = self.initial_reserves.balance(&self.position.phi.pair);
 
         // The action consumes the reserves and produces an LP NFT
         Balance::from(opened_position_nft) - reserves
@@ -129,11 +119,18 @@ impl IsAction for PositionWithdraw {
         let closed_position_nft = Value {
             amount: 1u64.into(),
             asset_id: LpNft::new(self.position_id, position::State::Closed).asset_id(),
-        }
-        .commit(Fr::zero());
+        };
+
+        let withdrawn_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
+        };
+
+        let nft_balance = Balance::from(withdrawn_position_nft) - closed_position_nft;
 
-        // The action consumes a closed position and produces the position's reserves.
-        self.reserves_commitment - closed_position_nft
+        // The action consumes a closed position and produces a withdrawn
+        // position and the position's reserves.
+        self.reserves_commitment + nft_balance.commit(Fr::zero())
     }
 
     fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
@@ -161,11 +158,18 @@ impl IsAction for PositionRewardClaim {
         let withdrawn_position_nft = Value {
             amount: 1u64.into(),
             asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
-        }
-        .commit(Fr::zero());
+        };
+
+        let claimed_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Claimed).asset_id(),
+        };
+
+        let nft_balance = Balance::from(claimed_position_nft) - withdrawn_position_nft;
 
-        // The action consumes a closed position and produces the position's reserves.
-        self.rewards_commitment - withdrawn_position_nft
+        // The action consumes a withdrawn position and produces a claimed
+        // position and the position's rewards.
+        self.rewards_commitment + nft_balance.commit(Fr::zero())
     }
 
     fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
@@ -13,6 +13,20 @@ use crate::{plan::TransactionPlan, ActionView, EffectHash, IsAction, Transaction
 /// The protobuf type URL for a transaction plan.
 pub const TRANSACTION_PLAN_TYPE_URL: &str = "/penumbra.core.transaction.v1alpha1.TransactionPlan";
 
+/// Decode a transaction scheduled by a DAO spend proposal into its [`TransactionPlan`].
+pub fn scheduled_transaction_plan(
+    transaction: &pbjson_types::Any,
+) -> anyhow::Result<TransactionPlan> {
+    if transaction.type_url != TRANSACTION_PLAN_TYPE_URL {
+        anyhow::bail!(
+            "scheduled transaction has type {}, expected {}",
+            transaction.type_url,
+            TRANSACTION_PLAN_TYPE_URL
+        );
+    }
+    TransactionPlan::decode(transaction.value.as_ref())
+}
+
 /// A governance proposal.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(try_from = "pb::Proposal", into = "pb::Proposal")]
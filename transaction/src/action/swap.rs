@@ -4,7 +4,7 @@ use decaf377::Fr;
 use penumbra_crypto::asset::Amount;
 use penumbra_crypto::dex::swap::SwapPayload;
 use penumbra_crypto::dex::TradingPair;
-use penumbra_crypto::proofs::transparent::SwapProof;
+use penumbra_crypto::proofs::groth16::SwapProof;
 use penumbra_crypto::Value;
 use penumbra_crypto::{balance, dex::swap::SwapCiphertext, Balance};
 use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
@@ -3,10 +3,14 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::anyhow;
-use decaf377_rdsa::{Signature, SpendAuth};
-use penumbra_crypto::{stake::IdentityKey, GovernanceKey};
+use anyhow::{anyhow, Context};
+use bytes::Bytes;
+use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
+use penumbra_crypto::{
+    proofs::groth16::DelegatorVoteProof, stake::IdentityKey, GovernanceKey, Nullifier, Value,
+};
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType};
+use penumbra_tct as tct;
 use serde::{Deserialize, Serialize};
 
 use crate::{ActionView, IsAction, TransactionPerspective};
@@ -80,7 +84,7 @@ impl TryFrom<pb::Vote> for Vote {
 
     fn try_from(msg: pb::Vote) -> Result<Self, Self::Error> {
         let Some(vote_state) = pb::vote::Vote::from_i32(msg.vote) else {
-            return Err(anyhow!("invalid vote state"))
+            return Err(anyhow!("invalid vote state"));
         };
         match vote_state {
             pb::vote::Vote::Abstain => Ok(Vote::Abstain),
@@ -209,13 +213,124 @@ impl DomainType for ValidatorVoteBody {
     type Proto = pb::ValidatorVoteBody;
 }
 
+/// A vote by a delegator.
 #[derive(Debug, Clone)]
 pub struct DelegatorVote {
-    // TODO: fill this in
+    /// The body of the delegator vote.
     pub body: DelegatorVoteBody,
+    /// The signature authorizing the vote (signed with the randomized spend key over the effect hash).
+    pub auth_sig: Signature<SpendAuth>,
+    /// The proof that the voter controlled the delegation note before voting started.
+    pub proof: DelegatorVoteProof,
+}
+
+impl IsAction for DelegatorVote {
+    fn balance_commitment(&self) -> penumbra_crypto::balance::Commitment {
+        // Voting doesn't consume the delegation note, so it has no effect on the value balance.
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::DelegatorVote(self.to_owned())
+    }
+}
+
+impl DomainType for DelegatorVote {
+    type Proto = pb::DelegatorVote;
+}
+
+impl From<DelegatorVote> for pb::DelegatorVote {
+    fn from(msg: DelegatorVote) -> Self {
+        let proof: Vec<u8> = msg.proof.into();
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+            proof: proof.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::DelegatorVote> for DelegatorVote {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DelegatorVote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing delegator vote body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing delegator auth sig"))?
+                .try_into()?,
+            proof: (msg.proof[..])
+                .try_into()
+                .context("malformed delegator vote proof")?,
+        })
+    }
 }
 
+/// A vote as a delegator, weighted by the delegation tokens it is cast with.
 #[derive(Debug, Clone)]
 pub struct DelegatorVoteBody {
-    // TODO: fill this in
+    /// The proposal ID to vote on.
+    pub proposal: u64,
+    /// The position of the state commitment tree when voting on the proposal started.
+    pub start_position: tct::Position,
+    /// The vote to cast.
+    pub vote: Vote,
+    /// The value of the delegation note being voted with.
+    pub value: Value,
+    /// The nullifier of the delegation note being voted with.
+    pub nullifier: Nullifier,
+    /// The randomized validating key for the vote authorization signature.
+    pub rk: VerificationKey<SpendAuth>,
+}
+
+impl DomainType for DelegatorVoteBody {
+    type Proto = pb::DelegatorVoteBody;
+}
+
+impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
+    fn from(value: DelegatorVoteBody) -> Self {
+        let nullifier_bytes: [u8; 32] = value.nullifier.into();
+        let rk_bytes: [u8; 32] = value.rk.into();
+        pb::DelegatorVoteBody {
+            proposal: value.proposal,
+            start_position: value.start_position.into(),
+            vote: Some(value.vote.into()),
+            value: Some(value.value.into()),
+            nullifier: Bytes::copy_from_slice(&nullifier_bytes),
+            rk: Bytes::copy_from_slice(&rk_bytes),
+        }
+    }
+}
+
+impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DelegatorVoteBody) -> Result<Self, Self::Error> {
+        let rk_bytes: [u8; 32] = (msg.rk[..])
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("expected 32-byte rk"))?;
+
+        Ok(DelegatorVoteBody {
+            proposal: msg.proposal,
+            start_position: msg.start_position.into(),
+            vote: msg
+                .vote
+                .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVote`"))?
+                .try_into()?,
+            value: msg
+                .value
+                .ok_or_else(|| anyhow::anyhow!("missing value in `DelegatorVote`"))?
+                .try_into()?,
+            nullifier: (msg.nullifier[..])
+                .try_into()
+                .context("malformed nullifier in `DelegatorVote`")?,
+            rk: rk_bytes
+                .try_into()
+                .context("malformed rk in `DelegatorVote`")?,
+        })
+    }
 }
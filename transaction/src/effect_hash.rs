@@ -8,10 +8,10 @@ use penumbra_proto::{core::crypto::v1alpha1 as pb_crypto, DomainType, Message};
 
 use crate::{
     action::{
-        output, proposal, spend, swap, swap_claim, Delegate, Ics20Withdrawal, PositionClose,
-        PositionOpen, PositionRewardClaim, PositionWithdraw, Proposal, ProposalDepositClaim,
-        ProposalSubmit, ProposalWithdraw, Undelegate, UndelegateClaimBody, ValidatorVote,
-        ValidatorVoteBody, Vote,
+        output, proposal, spend, swap, swap_claim, Delegate, DelegatorVote, DelegatorVoteBody,
+        Ics20Withdrawal, PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw,
+        Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, Undelegate,
+        UndelegateClaimBody, ValidatorVote, ValidatorVoteBody, Vote,
     },
     plan::TransactionPlan,
     Action, Transaction, TransactionBody,
@@ -187,8 +187,13 @@ impl TransactionPlan {
         for validator_vote in self.validator_votes() {
             state.update(validator_vote.effect_hash().as_bytes());
         }
-        for _delegator_vote in self.delegator_vote_plans() {
-            // TODO: get the effecthash of the delegator vote body for each plan
+        for delegator_vote in self.delegator_vote_plans() {
+            state.update(
+                delegator_vote
+                    .delegator_vote_body(fvk)
+                    .effect_hash()
+                    .as_bytes(),
+            );
         }
         for proposal_deposit_claim in self.proposal_deposit_claims() {
             state.update(proposal_deposit_claim.effect_hash().as_bytes());
@@ -207,6 +212,31 @@ impl TransactionPlan {
                 .hash(&payload.encode_to_vec());
             state.update(effect_hash.as_bytes());
         }
+        for position_open in self.position_openings() {
+            state.update(position_open.effect_hash().as_bytes());
+        }
+        for position_close in self.position_closings() {
+            state.update(position_close.effect_hash().as_bytes());
+        }
+        for position_withdraw in self.position_withdrawals() {
+            state.update(
+                position_withdraw
+                    .position_withdraw()
+                    .effect_hash()
+                    .as_bytes(),
+            );
+        }
+        for position_reward_claim in self.position_reward_claims() {
+            state.update(
+                position_reward_claim
+                    .position_reward_claim()
+                    .effect_hash()
+                    .as_bytes(),
+            );
+        }
+        for withdrawal in self.ics20_withdrawals() {
+            state.update(withdrawal.effect_hash().as_bytes());
+        }
         let num_clues = self.clue_plans.len() as u32;
         state.update(&num_clues.to_le_bytes());
         for clue_plan in self.clue_plans() {
@@ -215,6 +245,22 @@ impl TransactionPlan {
 
         EffectHash(state.finalize().as_array().clone())
     }
+
+    /// Computes the [`EffectHash`] identifying this plan when it is scheduled
+    /// for execution by a DAO spend proposal.
+    ///
+    /// The DAO has no viewing key, so its transactions are never built, and
+    /// the effect hash is computed over the plan itself. This is what a later
+    /// proposal uses to refer to the scheduled transaction to cancel it.
+    pub fn dao_effect_hash(&self) -> EffectHash {
+        EffectHash(
+            Params::default()
+                .personal(b"PAH:dao_tx_plan")
+                .hash(&self.encode_to_vec())
+                .as_array()
+                .clone(),
+        )
+    }
 }
 
 fn chain_id_effect_hash(chain_id: &str) -> Hash {
@@ -235,6 +281,7 @@ impl EffectingData for Action {
             Action::ProposalWithdraw(withdraw) => withdraw.effect_hash(),
             Action::ProposalDepositClaim(claim) => claim.effect_hash(),
             Action::ValidatorVote(vote) => vote.effect_hash(),
+            Action::DelegatorVote(vote) => vote.effect_hash(),
             Action::SwapClaim(swap_claim) => swap_claim.body.effect_hash(),
             Action::Swap(swap) => swap.body.effect_hash(),
             // These are data payloads, so just hash them directly,
@@ -462,6 +509,32 @@ impl EffectingData for ValidatorVoteBody {
     }
 }
 
+impl EffectingData for DelegatorVote {
+    fn effect_hash(&self) -> EffectHash {
+        self.body.effect_hash()
+    }
+}
+
+impl EffectingData for DelegatorVoteBody {
+    fn effect_hash(&self) -> EffectHash {
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"PAH:del_vote")
+            .to_state();
+
+        // All of these fields are fixed-length, so we can just throw them in the hash one after the
+        // other.
+        state.update(&self.proposal.to_le_bytes());
+        state.update(&u64::from(self.start_position).to_le_bytes());
+        state.update(self.vote.effect_hash().as_bytes());
+        state.update(&self.value.amount.to_le_bytes());
+        state.update(&self.value.asset_id.to_bytes());
+        state.update(&self.nullifier.0.to_bytes());
+        state.update(&self.rk.to_bytes());
+
+        EffectHash(state.finalize().as_array().clone())
+    }
+}
+
 impl EffectingData for ProposalDepositClaim {
     fn effect_hash(&self) -> EffectHash {
         let mut state = blake2b_simd::Params::default()
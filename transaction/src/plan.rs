@@ -11,7 +11,8 @@ use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 
 use crate::action::{
-    Delegate, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, Undelegate, ValidatorVote,
+    Delegate, Ics20Withdrawal, PositionClose, PositionOpen, ProposalDepositClaim, ProposalSubmit,
+    ProposalWithdraw, Undelegate, ValidatorVote,
 };
 
 mod action;
@@ -21,8 +22,8 @@ mod clue;
 mod memo;
 
 pub use action::{
-    ActionPlan, DelegatorVotePlan, OutputPlan, SpendPlan, SwapClaimPlan, SwapPlan,
-    UndelegateClaimPlan,
+    ActionPlan, DelegatorVotePlan, OutputPlan, PositionRewardClaimPlan, PositionWithdrawPlan,
+    SpendPlan, SwapClaimPlan, SwapPlan, UndelegateClaimPlan,
 };
 pub use clue::CluePlan;
 pub use memo::MemoPlan;
@@ -186,6 +187,56 @@ impl TransactionPlan {
         })
     }
 
+    pub fn ics20_withdrawals(&self) -> impl Iterator<Item = &Ics20Withdrawal> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Ics20Withdrawal(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn position_openings(&self) -> impl Iterator<Item = &PositionOpen> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionOpen(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn position_closings(&self) -> impl Iterator<Item = &PositionClose> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionClose(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn position_withdrawals(&self) -> impl Iterator<Item = &PositionWithdrawPlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionWithdraw(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn position_reward_claims(&self) -> impl Iterator<Item = &PositionRewardClaimPlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::PositionRewardClaim(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Convenience method to get all the destination addresses for each `OutputPlan`s.
     pub fn dest_addresses(&self) -> Vec<Address> {
         self.output_plans()
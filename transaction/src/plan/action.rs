@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 
 mod delegator_vote;
 mod output;
+mod position_reward_claim;
+mod position_withdraw;
 mod spend;
 mod swap;
 mod swap_claim;
@@ -14,14 +16,16 @@ mod undelegate_claim;
 
 pub use delegator_vote::DelegatorVotePlan;
 pub use output::OutputPlan;
+pub use position_reward_claim::PositionRewardClaimPlan;
+pub use position_withdraw::PositionWithdrawPlan;
 pub use spend::SpendPlan;
 pub use swap::SwapPlan;
 pub use swap_claim::SwapClaimPlan;
 pub use undelegate_claim::UndelegateClaimPlan;
 
 use crate::action::{
-    Delegate, PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw,
-    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, Undelegate, ValidatorVote,
+    Delegate, Ics20Withdrawal, PositionClose, PositionOpen, ProposalDepositClaim, ProposalSubmit,
+    ProposalWithdraw, Undelegate, ValidatorVote,
 };
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -50,6 +54,8 @@ pub enum ActionPlan {
     /// Describes a swap claim.
     SwapClaim(SwapClaimPlan),
     IBCAction(pb_ibc::IbcAction),
+    /// Withdraw funds to a counterparty chain over IBC.
+    Ics20Withdrawal(Ics20Withdrawal),
     /// Propose a governance vote.
     ProposalSubmit(ProposalSubmit),
     /// Withdraw a proposed vote.
@@ -61,10 +67,14 @@ pub enum ActionPlan {
     /// Claim the deposit for a finished proposal.
     ProposalDepositClaim(ProposalDepositClaim),
 
+    /// Open a liquidity position.
     PositionOpen(PositionOpen),
+    /// Close a liquidity position.
     PositionClose(PositionClose),
-    PositionWithdraw(PositionWithdraw),
-    PositionRewardClaim(PositionRewardClaim),
+    /// Withdraw the reserves of a closed liquidity position.
+    PositionWithdraw(PositionWithdrawPlan),
+    /// Claim the rewards of a withdrawn liquidity position.
+    PositionRewardClaim(PositionRewardClaimPlan),
 }
 
 impl ActionPlan {
@@ -82,12 +92,11 @@ impl ActionPlan {
             ProposalSubmit(proposal_submit) => proposal_submit.balance(),
             ProposalWithdraw(proposal_withdraw) => proposal_withdraw.balance(),
             ProposalDepositClaim(proposal_deposit_claim) => proposal_deposit_claim.balance(),
-            PositionOpen(_position_open) => todo!(),
-            PositionClose(_position_close) => todo!(),
-            PositionWithdraw(_position_withdraw) => todo!(),
-            PositionRewardClaim(_position_reward_claim) => {
-                todo!()
-            }
+            PositionOpen(position_open) => position_open.balance(),
+            PositionClose(position_close) => position_close.balance(),
+            PositionWithdraw(position_withdraw) => position_withdraw.balance(),
+            PositionRewardClaim(position_reward_claim) => position_reward_claim.balance(),
+            Ics20Withdrawal(withdrawal) => withdrawal.balance(),
             // None of these contribute to transaction balance:
             IBCAction(_) | ValidatorDefinition(_) | DelegatorVote(_) | ValidatorVote(_) => {
                 Balance::default()
@@ -146,6 +155,12 @@ impl From<pb_ibc::IbcAction> for ActionPlan {
     }
 }
 
+impl From<Ics20Withdrawal> for ActionPlan {
+    fn from(inner: Ics20Withdrawal) -> ActionPlan {
+        ActionPlan::Ics20Withdrawal(inner)
+    }
+}
+
 impl From<ProposalSubmit> for ActionPlan {
     fn from(inner: ProposalSubmit) -> ActionPlan {
         ActionPlan::ProposalSubmit(inner)
@@ -176,14 +191,14 @@ impl From<PositionClose> for ActionPlan {
     }
 }
 
-impl From<PositionWithdraw> for ActionPlan {
-    fn from(inner: PositionWithdraw) -> ActionPlan {
+impl From<PositionWithdrawPlan> for ActionPlan {
+    fn from(inner: PositionWithdrawPlan) -> ActionPlan {
         ActionPlan::PositionWithdraw(inner)
     }
 }
 
-impl From<PositionRewardClaim> for ActionPlan {
-    fn from(inner: PositionRewardClaim) -> ActionPlan {
+impl From<PositionRewardClaimPlan> for ActionPlan {
+    fn from(inner: PositionRewardClaimPlan) -> ActionPlan {
         ActionPlan::PositionRewardClaim(inner)
     }
 }
@@ -222,6 +237,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::IBCAction(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::IbcAction(inner)),
             },
+            ActionPlan::Ics20Withdrawal(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Ics20Withdrawal(inner.into())),
+            },
             ActionPlan::ProposalSubmit(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ProposalSubmit(inner.into())),
             },
@@ -283,6 +301,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
                 Ok(ActionPlan::SwapClaim(inner.try_into()?))
             }
             pb_t::action_plan::Action::IbcAction(inner) => Ok(ActionPlan::IBCAction(inner)),
+            pb_t::action_plan::Action::Ics20Withdrawal(inner) => {
+                Ok(ActionPlan::Ics20Withdrawal(inner.try_into()?))
+            }
             pb_t::action_plan::Action::ProposalSubmit(inner) => {
                 Ok(ActionPlan::ProposalSubmit(inner.try_into()?))
             }
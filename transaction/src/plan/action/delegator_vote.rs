@@ -1,17 +1,25 @@
+use ark_ff::{UniformRand, Zero};
 use decaf377::{FieldExt, Fr};
-use penumbra_crypto::Note;
+use decaf377_rdsa::{Signature, SpendAuth};
+use penumbra_crypto::{proofs::groth16::DelegatorVoteProof, FullViewingKey, Note};
+use penumbra_proof_params::DELEGATORVOTE_PROOF_PROVING_KEY;
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
+use rand_core::{CryptoRng, OsRng, RngCore};
 use serde::{Deserialize, Serialize};
 
+use crate::action::{DelegatorVote, DelegatorVoteBody, Vote};
+
 /// A plan to vote as a delegator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(try_from = "pb::DelegatorVotePlan", into = "pb::DelegatorVotePlan")]
 pub struct DelegatorVotePlan {
     /// The proposal ID to vote on.
     pub proposal: u64,
+    /// The position of the state commitment tree when voting on the proposal started.
+    pub start_position: tct::Position,
     /// The vote to cast.
-    pub vote: crate::action::Vote,
+    pub vote: Vote,
     /// A staked note that was spendable before the proposal started.
     pub staked_note: Note,
     /// The position of the staked note.
@@ -20,6 +28,81 @@ pub struct DelegatorVotePlan {
     pub randomizer: Fr,
 }
 
+impl DelegatorVotePlan {
+    /// Create a new [`DelegatorVotePlan`] that votes using the given positioned `staked_note`.
+    pub fn new<R: CryptoRng + RngCore>(
+        rng: &mut R,
+        proposal: u64,
+        start_position: tct::Position,
+        vote: Vote,
+        staked_note: Note,
+        position: tct::Position,
+    ) -> DelegatorVotePlan {
+        DelegatorVotePlan {
+            proposal,
+            start_position,
+            vote,
+            staked_note,
+            position,
+            randomizer: Fr::rand(rng),
+        }
+    }
+
+    /// Convenience method to construct the [`DelegatorVote`] described by this [`DelegatorVotePlan`].
+    pub fn delegator_vote(
+        &self,
+        fvk: &FullViewingKey,
+        auth_sig: Signature<SpendAuth>,
+        auth_path: tct::Proof,
+        anchor: tct::Root,
+    ) -> DelegatorVote {
+        DelegatorVote {
+            body: self.delegator_vote_body(fvk),
+            auth_sig,
+            proof: self.delegator_vote_proof(fvk, auth_path, anchor),
+        }
+    }
+
+    /// Construct the [`DelegatorVoteBody`] described by this [`DelegatorVotePlan`].
+    pub fn delegator_vote_body(&self, fvk: &FullViewingKey) -> DelegatorVoteBody {
+        DelegatorVoteBody {
+            proposal: self.proposal,
+            start_position: self.start_position,
+            vote: self.vote,
+            value: self.staked_note.value(),
+            nullifier: fvk.derive_nullifier(self.position, &self.staked_note.commit()),
+            rk: fvk.spend_verification_key().randomize(&self.randomizer),
+        }
+    }
+
+    /// Construct the [`DelegatorVoteProof`] required by the [`DelegatorVoteBody`] described by this [`DelegatorVotePlan`].
+    pub fn delegator_vote_proof(
+        &self,
+        fvk: &FullViewingKey,
+        state_commitment_proof: tct::Proof,
+        anchor: tct::Root,
+    ) -> DelegatorVoteProof {
+        // The vote reveals its value, so it's committed to with a zero blinding factor.
+        let v_blinding = Fr::zero();
+        DelegatorVoteProof::prove(
+            &mut OsRng,
+            &DELEGATORVOTE_PROOF_PROVING_KEY,
+            state_commitment_proof,
+            self.staked_note.clone(),
+            v_blinding,
+            self.randomizer,
+            *fvk.spend_verification_key(),
+            *fvk.nullifier_key(),
+            anchor,
+            self.staked_note.value().commit(v_blinding),
+            fvk.derive_nullifier(self.position, &self.staked_note.commit()),
+            fvk.spend_verification_key().randomize(&self.randomizer),
+            self.start_position,
+        )
+        .expect("can generate ZK delegator vote proof")
+    }
+}
+
 impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
     fn from(inner: DelegatorVotePlan) -> Self {
         pb::DelegatorVotePlan {
@@ -28,6 +111,7 @@ impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
             staked_note: Some(inner.staked_note.into()),
             position: inner.position.into(),
             randomizer: inner.randomizer.to_bytes().to_vec().into(),
+            start_position: inner.start_position.into(),
         }
     }
 }
@@ -38,6 +122,7 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
     fn try_from(value: pb::DelegatorVotePlan) -> Result<Self, Self::Error> {
         Ok(DelegatorVotePlan {
             proposal: value.proposal,
+            start_position: value.start_position.into(),
             vote: value
                 .vote
                 .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVotePlan`"))?
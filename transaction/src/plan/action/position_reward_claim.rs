@@ -0,0 +1,110 @@
+use anyhow::{anyhow, Context, Result};
+use penumbra_crypto::{
+    balance,
+    dex::{
+        lp::{position, LpNft, Reserves},
+        TradingPair,
+    },
+    Balance, Fr, Value, Zero,
+};
+use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::action::PositionRewardClaim;
+
+/// A planned [`PositionRewardClaim`](PositionRewardClaim).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "pb::PositionRewardClaimPlan",
+    into = "pb::PositionRewardClaimPlan"
+)]
+pub struct PositionRewardClaimPlan {
+    /// The rewards accumulated by the position, which will be claimed.
+    pub rewards: Reserves,
+    pub position_id: position::Id,
+    /// The trading pair of the position, which determines the asset IDs of the rewards.
+    pub pair: TradingPair,
+}
+
+impl PositionRewardClaimPlan {
+    /// Create a new [`PositionRewardClaimPlan`] for a position with no accumulated rewards.
+    ///
+    /// There are no liquidity incentives yet, so this is currently the only
+    /// kind of reward claim the chain will accept.
+    pub fn new(position_id: position::Id, pair: TradingPair) -> PositionRewardClaimPlan {
+        PositionRewardClaimPlan {
+            rewards: Reserves::zero(),
+            position_id,
+            pair,
+        }
+    }
+
+    /// Convenience method to construct the [`PositionRewardClaim`] described by this [`PositionRewardClaimPlan`].
+    pub fn position_reward_claim(&self) -> PositionRewardClaim {
+        PositionRewardClaim {
+            position_id: self.position_id,
+            rewards_commitment: self.rewards_commitment(),
+        }
+    }
+
+    /// Construct the transparent commitment to the rewards being claimed,
+    /// which the chain will check against its own record of the position.
+    pub fn rewards_commitment(&self) -> balance::Commitment {
+        self.rewards.balance(&self.pair).commit(Fr::zero())
+    }
+
+    pub fn balance(&self) -> Balance {
+        // PositionRewardClaim actions consume a withdrawn position NFT and
+        // produce a claimed position NFT, as well as the position's rewards.
+        let withdrawn_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
+        };
+        let claimed_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Claimed).asset_id(),
+        };
+
+        let mut balance = self.rewards.balance(&self.pair);
+        balance -= withdrawn_position_nft;
+        balance += claimed_position_nft;
+        balance
+    }
+}
+
+impl DomainType for PositionRewardClaimPlan {
+    type Proto = pb::PositionRewardClaimPlan;
+}
+
+impl From<PositionRewardClaimPlan> for pb::PositionRewardClaimPlan {
+    fn from(msg: PositionRewardClaimPlan) -> Self {
+        Self {
+            rewards: Some(msg.rewards.into()),
+            position_id: Some(msg.position_id.into()),
+            pair: Some(msg.pair.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRewardClaimPlan> for PositionRewardClaimPlan {
+    type Error = anyhow::Error;
+    fn try_from(msg: pb::PositionRewardClaimPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rewards: msg
+                .rewards
+                .ok_or_else(|| anyhow!("missing rewards"))?
+                .try_into()
+                .context("rewards malformed")?,
+            position_id: msg
+                .position_id
+                .ok_or_else(|| anyhow!("missing position_id"))?
+                .try_into()
+                .context("position_id malformed")?,
+            pair: msg
+                .pair
+                .ok_or_else(|| anyhow!("missing pair"))?
+                .try_into()
+                .context("pair malformed")?,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+use anyhow::{anyhow, Context, Result};
+use penumbra_crypto::{
+    balance,
+    dex::{
+        lp::{position, LpNft, Reserves},
+        TradingPair,
+    },
+    Balance, Fr, Value, Zero,
+};
+use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::action::PositionWithdraw;
+
+/// A planned [`PositionWithdraw`](PositionWithdraw).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "pb::PositionWithdrawPlan",
+    into = "pb::PositionWithdrawPlan"
+)]
+pub struct PositionWithdrawPlan {
+    /// The final reserves of the position, which will be withdrawn.
+    pub reserves: Reserves,
+    pub position_id: position::Id,
+    /// The trading pair of the position, which determines the asset IDs of the reserves.
+    pub pair: TradingPair,
+}
+
+impl PositionWithdrawPlan {
+    /// Convenience method to construct the [`PositionWithdraw`] described by this [`PositionWithdrawPlan`].
+    pub fn position_withdraw(&self) -> PositionWithdraw {
+        PositionWithdraw {
+            position_id: self.position_id,
+            reserves_commitment: self.reserves_commitment(),
+        }
+    }
+
+    /// Construct the transparent commitment to the reserves being withdrawn,
+    /// which the chain will check against its own record of the position.
+    pub fn reserves_commitment(&self) -> balance::Commitment {
+        self.reserves.balance(&self.pair).commit(Fr::zero())
+    }
+
+    pub fn balance(&self) -> Balance {
+        // PositionWithdraw actions consume a closed position NFT and produce
+        // a withdrawn position NFT, as well as the position's final reserves.
+        let closed_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Closed).asset_id(),
+        };
+        let withdrawn_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
+        };
+
+        let mut balance = self.reserves.balance(&self.pair);
+        balance -= closed_position_nft;
+        balance += withdrawn_position_nft;
+        balance
+    }
+}
+
+impl DomainType for PositionWithdrawPlan {
+    type Proto = pb::PositionWithdrawPlan;
+}
+
+impl From<PositionWithdrawPlan> for pb::PositionWithdrawPlan {
+    fn from(msg: PositionWithdrawPlan) -> Self {
+        Self {
+            reserves: Some(msg.reserves.into()),
+            position_id: Some(msg.position_id.into()),
+            pair: Some(msg.pair.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionWithdrawPlan> for PositionWithdrawPlan {
+    type Error = anyhow::Error;
+    fn try_from(msg: pb::PositionWithdrawPlan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            reserves: msg
+                .reserves
+                .ok_or_else(|| anyhow!("missing reserves"))?
+                .try_into()
+                .context("reserves malformed")?,
+            position_id: msg
+                .position_id
+                .ok_or_else(|| anyhow!("missing position_id"))?
+                .try_into()
+                .context("position_id malformed")?,
+            pair: msg
+                .pair
+                .ok_or_else(|| anyhow!("missing pair"))?
+                .try_into()
+                .context("pair malformed")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use penumbra_crypto::{
+        asset,
+        dex::{
+            lp::{position::Position, Reserves, TradingFunction},
+            TradingPair,
+        },
+        Fr, Zero,
+    };
+
+    use super::PositionWithdrawPlan;
+    use crate::action::IsAction;
+
+    #[test]
+    /// Check that the balance the planner tracks for a withdrawal matches the
+    /// balance commitment of the action it builds, so the transaction balances.
+    fn withdrawal_balance_matches_action() {
+        let gm = asset::REGISTRY.parse_unit("gm");
+        let gn = asset::REGISTRY.parse_unit("gn");
+        let pair = TradingPair::new(gm.id(), gn.id());
+        let position = Position {
+            phi: TradingFunction::new(pair, 1u32, 1u64.into(), 1u64.into()),
+            nonce: [0u8; 32],
+        };
+
+        let plan = PositionWithdrawPlan {
+            reserves: Reserves {
+                r1: 100u64.into(),
+                r2: 50u64.into(),
+            },
+            position_id: position.id(),
+            pair,
+        };
+
+        assert_eq!(
+            plan.position_withdraw().balance_commitment(),
+            plan.balance().commit(Fr::zero())
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use penumbra_crypto::{
     dex::{swap::SwapPlaintext, BatchSwapOutputData},
     keys::{IncomingViewingKey, NullifierKey},
-    proofs::transparent::SwapClaimProof,
+    proofs::groth16::SwapClaimProof,
     FullViewingKey, Value,
 };
+use penumbra_proof_params::SWAPCLAIM_PROOF_PROVING_KEY;
 use penumbra_proto::{core::dex::v1alpha1 as pb, DomainType};
 use penumbra_tct as tct;
 
+use rand_core::OsRng;
 use serde::{Deserialize, Serialize};
 use tct::Position;
 
@@ -29,10 +31,11 @@ impl SwapClaimPlan {
         &self,
         fvk: &FullViewingKey,
         state_commitment_proof: &tct::Proof,
+        anchor: tct::Root,
     ) -> SwapClaim {
         SwapClaim {
             body: self.swap_claim_body(fvk),
-            proof: self.swap_claim_proof(state_commitment_proof, fvk.nullifier_key()),
+            proof: self.swap_claim_proof(state_commitment_proof, fvk.nullifier_key(), anchor),
             epoch_duration: self.epoch_duration,
         }
     }
@@ -43,19 +46,25 @@ impl SwapClaimPlan {
         &self,
         state_commitment_proof: &tct::Proof,
         nk: &NullifierKey,
+        anchor: tct::Root,
     ) -> SwapClaimProof {
-        let (lambda_1_i, lambda_2_i) = self.output_data.pro_rata_outputs((
-            self.swap_plaintext.delta_1_i.into(),
-            self.swap_plaintext.delta_2_i.into(),
-        ));
-
-        SwapClaimProof {
-            swap_plaintext: self.swap_plaintext.clone(),
-            lambda_1_i,
-            lambda_2_i,
-            nk: nk.clone(),
-            swap_commitment_proof: state_commitment_proof.clone(),
-        }
+        let (output_1_note, output_2_note) = self.swap_plaintext.output_notes(&self.output_data);
+        let nullifier = nk.derive_nullifier(self.position, &self.swap_plaintext.swap_commitment());
+
+        SwapClaimProof::prove(
+            &mut OsRng,
+            &SWAPCLAIM_PROOF_PROVING_KEY,
+            self.swap_plaintext.clone(),
+            state_commitment_proof.clone(),
+            *nk,
+            anchor,
+            nullifier,
+            self.output_data,
+            self.epoch_duration,
+            output_1_note.commit(),
+            output_2_note.commit(),
+        )
+        .expect("can generate ZK swap claim proof")
     }
 
     /// Construct the [`swap_claim::Body`] described by this plan.
@@ -19,9 +19,18 @@ impl TransactionPlan {
             let auth_sig = rsk.sign(&mut rng, effect_hash.as_ref());
             spend_auths.push(auth_sig);
         }
+        let mut delegator_vote_auths = Vec::new();
+        for delegator_vote_plan in self.delegator_vote_plans() {
+            let rsk = sk
+                .spend_auth_key()
+                .randomize(&delegator_vote_plan.randomizer);
+            let auth_sig = rsk.sign(&mut rng, effect_hash.as_ref());
+            delegator_vote_auths.push(auth_sig);
+        }
         AuthorizationData {
             effect_hash,
             spend_auths,
+            delegator_vote_auths,
         }
     }
 }
@@ -33,6 +33,14 @@ impl TransactionPlan {
                 auth_data.spend_auths.len()
             ));
         }
+        let delegator_vote_count = self.delegator_vote_plans().count();
+        if auth_data.delegator_vote_auths.len() != delegator_vote_count {
+            return Err(anyhow::anyhow!(
+                "expected {} delegator vote auths but got {}",
+                delegator_vote_count,
+                auth_data.delegator_vote_auths.len()
+            ));
+        }
 
         let mut actions = Vec::new();
         let mut fmd_clues = Vec::new();
@@ -95,9 +103,11 @@ impl TransactionPlan {
                 .get(&note_commitment)
                 .context(format!("could not get proof for {:?}", note_commitment))?;
 
-            actions.push(Action::SwapClaim(
-                swap_claim_plan.swap_claim(fvk, auth_path),
-            ));
+            actions.push(Action::SwapClaim(swap_claim_plan.swap_claim(
+                fvk,
+                auth_path,
+                witness_data.anchor,
+            )));
         }
 
         // Build the clue plans.
@@ -133,7 +143,23 @@ impl TransactionPlan {
         for validator_vote in self.validator_votes().cloned() {
             actions.push(Action::ValidatorVote(validator_vote))
         }
-        // TODO: delegator vote
+        for (delegator_vote_plan, auth_sig) in self
+            .delegator_vote_plans()
+            .zip(auth_data.delegator_vote_auths.iter())
+        {
+            let note_commitment = delegator_vote_plan.staked_note.commit();
+            let auth_path = witness_data
+                .state_commitment_proofs
+                .get(&note_commitment)
+                .context(format!("could not get proof for {:?}", note_commitment))?;
+
+            actions.push(Action::DelegatorVote(delegator_vote_plan.delegator_vote(
+                fvk,
+                *auth_sig,
+                auth_path.clone(),
+                witness_data.anchor,
+            )));
+        }
         for proposal_deposit_claim in self.proposal_deposit_claims().cloned() {
             actions.push(Action::ProposalDepositClaim(proposal_deposit_claim))
         }
@@ -143,6 +169,25 @@ impl TransactionPlan {
         for ibc_action in self.ibc_actions().cloned() {
             actions.push(Action::IBCAction(ibc_action))
         }
+        for position_open in self.position_openings().cloned() {
+            actions.push(Action::PositionOpen(position_open))
+        }
+        for position_close in self.position_closings().cloned() {
+            actions.push(Action::PositionClose(position_close))
+        }
+        for position_withdraw in self.position_withdrawals() {
+            actions.push(Action::PositionWithdraw(
+                position_withdraw.position_withdraw(),
+            ))
+        }
+        for position_reward_claim in self.position_reward_claims() {
+            actions.push(Action::PositionRewardClaim(
+                position_reward_claim.position_reward_claim(),
+            ))
+        }
+        for withdrawal in self.ics20_withdrawals().cloned() {
+            actions.push(Action::Ics20Withdrawal(withdrawal))
+        }
 
         let transaction_body = TransactionBody {
             actions,
@@ -186,6 +231,14 @@ impl TransactionPlan {
                 auth_data.spend_auths.len()
             ));
         }
+        let delegator_vote_count = self.delegator_vote_plans().count();
+        if auth_data.delegator_vote_auths.len() != delegator_vote_count {
+            return Err(anyhow::anyhow!(
+                "expected {} delegator vote auths but got {}",
+                delegator_vote_count,
+                auth_data.delegator_vote_auths.len()
+            ));
+        }
 
         let mut fmd_clues = Vec::new();
         let mut synthetic_blinding_factor = Fr::zero();
@@ -258,9 +311,10 @@ impl TransactionPlan {
                 .context(format!("could not get proof for {:?}", note_commitment))?
                 .clone();
             let fvk_ = fvk.clone();
+            let anchor = witness_data.anchor;
 
             in_progress_swap_claim_actions.push(tokio::spawn(async move {
-                swap_claim_plan.swap_claim(&fvk_, &auth_path)
+                swap_claim_plan.swap_claim(&fvk_, &auth_path, anchor)
             }));
         }
 
@@ -316,7 +370,23 @@ impl TransactionPlan {
         for validator_vote in self.validator_votes().cloned() {
             actions.push(Action::ValidatorVote(validator_vote))
         }
-        // TODO: delegator vote
+        for (delegator_vote_plan, auth_sig) in self
+            .delegator_vote_plans()
+            .zip(auth_data.delegator_vote_auths.iter())
+        {
+            let note_commitment = delegator_vote_plan.staked_note.commit();
+            let auth_path = witness_data
+                .state_commitment_proofs
+                .get(&note_commitment)
+                .context(format!("could not get proof for {:?}", note_commitment))?;
+
+            actions.push(Action::DelegatorVote(delegator_vote_plan.delegator_vote(
+                fvk,
+                *auth_sig,
+                auth_path.clone(),
+                witness_data.anchor,
+            )));
+        }
         for proposal_deposit_claim in self.proposal_deposit_claims().cloned() {
             actions.push(Action::ProposalDepositClaim(proposal_deposit_claim))
         }
@@ -326,6 +396,25 @@ impl TransactionPlan {
         for ibc_action in self.ibc_actions().cloned() {
             actions.push(Action::IBCAction(ibc_action))
         }
+        for position_open in self.position_openings().cloned() {
+            actions.push(Action::PositionOpen(position_open))
+        }
+        for position_close in self.position_closings().cloned() {
+            actions.push(Action::PositionClose(position_close))
+        }
+        for position_withdraw in self.position_withdrawals() {
+            actions.push(Action::PositionWithdraw(
+                position_withdraw.position_withdraw(),
+            ))
+        }
+        for position_reward_claim in self.position_reward_claims() {
+            actions.push(Action::PositionRewardClaim(
+                position_reward_claim.position_reward_claim(),
+            ))
+        }
+        for withdrawal in self.ics20_withdrawals().cloned() {
+            actions.push(Action::Ics20Withdrawal(withdrawal))
+        }
 
         let transaction_body = TransactionBody {
             actions,
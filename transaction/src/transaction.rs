@@ -22,7 +22,10 @@ use penumbra_tct as tct;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    action::{Delegate, Output, ProposalSubmit, ProposalWithdraw, Swap, Undelegate, ValidatorVote},
+    action::{
+        Delegate, DelegatorVote, Output, ProposalSubmit, ProposalWithdraw, Swap, Undelegate,
+        ValidatorVote,
+    },
     view::action_view::OutputView,
     Action, ActionView, IsAction, TransactionPerspective, TransactionView,
 };
@@ -109,6 +112,7 @@ impl Transaction {
                 | Action::ProposalSubmit(_)
                 | Action::ProposalWithdraw(_)
                 | Action::ValidatorVote(_)
+                | Action::DelegatorVote(_)
                 | Action::ProposalDepositClaim(_)
                 | Action::PositionOpen(_)
                 | Action::PositionClose(_)
@@ -212,15 +216,15 @@ impl Transaction {
         })
     }
 
-    // pub fn delegator_votes(&self) -> impl Iterator<Item = &DelegatorVote> {
-    //     self.actions().filter_map(|action| {
-    //         if let Action::DelegatorVote(v) = action {
-    //             Some(v)
-    //         } else {
-    //             None
-    //         }
-    //     })
-    // }
+    pub fn delegator_votes(&self) -> impl Iterator<Item = &DelegatorVote> {
+        self.actions().filter_map(|action| {
+            if let Action::DelegatorVote(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
 
     pub fn ibc_actions(&self) -> impl Iterator<Item = &pb_ibc::IbcAction> {
         self.actions().filter_map(|action| {
@@ -274,6 +278,11 @@ impl Transaction {
         })
     }
 
+    /// The clues attached to this transaction for fuzzy message detection.
+    pub fn fmd_clues(&self) -> &[Clue] {
+        &self.transaction_body.fmd_clues
+    }
+
     pub fn transaction_body(&self) -> TransactionBody {
         self.transaction_body.clone()
     }
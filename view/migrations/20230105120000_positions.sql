@@ -0,0 +1,9 @@
+-- This table records the position ID and state of each LPNFT note we control.
+-- The note itself is recorded in the notes and spendable_notes tables.
+CREATE TABLE positions (
+    note_commitment         BLOB PRIMARY KEY NOT NULL,
+    position_id             BLOB NOT NULL,
+    position_state          TEXT NOT NULL
+);
+
+CREATE INDEX positions_position_id_idx ON positions (position_id);
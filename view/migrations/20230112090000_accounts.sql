@@ -0,0 +1,8 @@
+-- The view database can track several full viewing keys, so record which
+-- account each of our notes and swaps belongs to. Existing rows are
+-- attributed to their account when the database is loaded, see
+-- `Storage::backfill_account_ids`.
+ALTER TABLE spendable_notes ADD COLUMN account_id BLOB;
+ALTER TABLE swaps ADD COLUMN account_id BLOB;
+
+CREATE INDEX spendable_notes_by_account_idx ON spendable_notes (account_id);
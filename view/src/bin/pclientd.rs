@@ -10,7 +10,7 @@ use penumbra_proto::client::v1alpha1::oblivious_query_service_client::ObliviousQ
 use penumbra_proto::client::v1alpha1::ChainParametersRequest;
 use penumbra_proto::custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer;
 use penumbra_proto::view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer;
-use penumbra_view::ViewService;
+use penumbra_view::{SyncMode, ViewService};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use std::fs::File;
@@ -47,6 +47,13 @@ struct Opt {
     /// The port to use to speak to pd's gRPC server.
     #[clap(long, default_value = "8080")]
     pd_port: u16,
+    /// If set, delegate note detection to this detection server, and only
+    /// download and scan the transactions it flags.
+    #[clap(long)]
+    detection_server: Option<String>,
+    /// The number of accounts to detect notes for, when using a detection server.
+    #[clap(long, default_value = "1")]
+    detection_accounts: u32,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -98,8 +105,8 @@ async fn main() -> Result<()> {
 
             penumbra_view::Storage::initialize(
                 opt.sqlite_path.as_path(),
-                FullViewingKey::from_str(full_viewing_key.as_ref())
-                    .context("The provided string is not a valid FullViewingKey")?,
+                vec![FullViewingKey::from_str(full_viewing_key.as_ref())
+                    .context("The provided string is not a valid FullViewingKey")?],
                 params,
             )
             .await?;
@@ -151,6 +158,7 @@ async fn main() -> Result<()> {
                     Some(soft_kms::Config {
                         spend_key: spend_key.clone(),
                         auth_policy,
+                        spend_history_path: None,
                     })
                 }
                 None => None,
@@ -187,7 +195,14 @@ async fn main() -> Result<()> {
 
             let storage = penumbra_view::Storage::load(opt.sqlite_path).await?;
 
-            let service = ViewService::new(storage, opt.node, opt.pd_port).await?;
+            let sync_mode = match opt.detection_server {
+                Some(url) => SyncMode::Detection {
+                    url,
+                    num_accounts: opt.detection_accounts,
+                },
+                None => SyncMode::TrialDecryption,
+            };
+            let service = ViewService::new(storage, opt.node, opt.pd_port, sync_mode).await?;
 
             let config_contents = match fs::read_to_string("./client_data/pclientd/config.toml") {
                 // If successful return the files text as `contents`.
@@ -226,9 +241,7 @@ async fn main() -> Result<()> {
 
                     println!("Spend key found in config, starting pclientd in Custody mode.");
 
-                    let spend_key = kms_config.spend_key;
-
-                    let soft_kms = SoftKms::new(spend_key.clone().into());
+                    let soft_kms = SoftKms::new(kms_config)?;
 
                     let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
 
@@ -19,7 +19,7 @@ use tonic::async_trait;
 use tonic::codegen::Bytes;
 use tracing::instrument;
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord};
+use crate::{PositionRecord, SpendableNoteRecord, StakingReport, StatusStreamResponse, SwapRecord};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -53,6 +53,13 @@ pub trait ViewClient {
     /// Queries for notes.
     async fn notes(&mut self, request: pb::NotesRequest) -> Result<Vec<SpendableNoteRecord>>;
 
+    /// Queries for the LPNFT notes recording ownership of liquidity positions.
+    async fn positions(&mut self, request: pb::PositionsRequest) -> Result<Vec<PositionRecord>>;
+
+    /// Queries for the staking rewards of an account's delegations, and the
+    /// state of its unbonding tokens.
+    async fn staking_report(&mut self, account_id: AccountID) -> Result<StakingReport>;
+
     /// Queries for account balance by address
     async fn balance_by_address(&mut self, address: Address) -> Result<Vec<(Id, Amount)>>;
 
@@ -121,9 +128,11 @@ pub trait ViewClient {
         tx_hash: tendermint::hash::Hash,
     ) -> Result<Option<Transaction>>;
 
-    /// Generates a full perspective for a selected transaction using a full viewing key
+    /// Generates a full perspective for a selected transaction using the full
+    /// viewing key of the given account
     async fn transaction_perspective(
         &mut self,
+        account_id: AccountID,
         tx_hash: tendermint::hash::Hash,
     ) -> Result<TransactionPerspective>;
 
@@ -289,6 +298,37 @@ where
         Ok(notes?)
     }
 
+    async fn positions(&mut self, request: pb::PositionsRequest) -> Result<Vec<PositionRecord>> {
+        let pb_positions: Vec<_> = self
+            .positions(tonic::Request::new(request))
+            .await?
+            .into_inner()
+            .try_collect()
+            .await?;
+
+        pb_positions
+            .into_iter()
+            .map(|position_rsp| {
+                position_rsp
+                    .position_record
+                    .ok_or_else(|| anyhow::anyhow!("empty PositionsResponse message"))?
+                    .try_into()
+            })
+            .collect()
+    }
+
+    async fn staking_report(&mut self, account_id: AccountID) -> Result<StakingReport> {
+        self.staking(tonic::Request::new(pb::StakingRequest {
+            account_id: Some(account_id.into()),
+            ..Default::default()
+        }))
+        .await?
+        .into_inner()
+        .report
+        .ok_or_else(|| anyhow::anyhow!("empty StakingResponse message"))?
+        .try_into()
+    }
+
     async fn note_by_commitment(
         &mut self,
         account_id: AccountID,
@@ -444,6 +484,10 @@ where
                 plan.swap_claim_plans()
                     .map(|swap_claim| swap_claim.swap_plaintext.swap_commitment().into()),
             )
+            .chain(
+                plan.delegator_vote_plans()
+                    .map(|vote| vote.staked_note.commit().into()),
+            )
             .collect();
 
         let request = WitnessRequest {
@@ -524,12 +568,14 @@ where
 
     async fn transaction_perspective(
         &mut self,
+        account_id: AccountID,
         tx_hash: tendermint::hash::Hash,
     ) -> Result<TransactionPerspective> {
         ViewProtocolServiceClient::transaction_perspective(
             self,
             tonic::Request::new(pb::TransactionPerspectiveRequest {
                 tx_hash: tx_hash.as_bytes().to_vec(),
+                account_id: Some(account_id.into()),
             }),
         )
         .await?
@@ -2,7 +2,9 @@
 mod client;
 mod metrics;
 mod note_record;
+mod position_record;
 mod service;
+mod staking_report;
 mod status;
 mod storage;
 mod swap_record;
@@ -14,7 +16,10 @@ use worker::Worker;
 pub use crate::metrics::register_metrics;
 pub use client::ViewClient;
 pub use note_record::SpendableNoteRecord;
+pub use position_record::PositionRecord;
 pub use service::ViewService;
+pub use staking_report::{DelegationReport, StakingReport, UnbondingReport};
 pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
+pub use sync::SyncMode;
@@ -0,0 +1,70 @@
+use penumbra_crypto::dex::lp::{position, LpNft};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Row};
+
+use crate::SpendableNoteRecord;
+
+/// Corresponds to the PositionRecord proto
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::PositionRecord", into = "pb::PositionRecord")]
+pub struct PositionRecord {
+    /// The LPNFT note recording ownership of the position.
+    pub note_record: SpendableNoteRecord,
+    /// The LPNFT, identifying the position and its state.
+    pub lpnft: LpNft,
+}
+
+impl DomainType for PositionRecord {
+    type Proto = pb::PositionRecord;
+}
+impl From<PositionRecord> for pb::PositionRecord {
+    fn from(v: PositionRecord) -> Self {
+        pb::PositionRecord {
+            note_record: Some(v.note_record.into()),
+            lpnft: Some(v.lpnft.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionRecord> for PositionRecord {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::PositionRecord) -> Result<Self, Self::Error> {
+        Ok(PositionRecord {
+            note_record: v
+                .note_record
+                .ok_or_else(|| anyhow::anyhow!("missing note record"))?
+                .try_into()?,
+            lpnft: v
+                .lpnft
+                .ok_or_else(|| anyhow::anyhow!("missing lpnft"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl<'r> FromRow<'r, sqlx::sqlite::SqliteRow> for PositionRecord {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        let note_record = SpendableNoteRecord::from_row(row)?;
+
+        let position_id = position::Id(row.get::<'r, &[u8], _>("position_id").try_into().map_err(
+            |e: std::array::TryFromSliceError| sqlx::Error::ColumnDecode {
+                index: "position_id".to_string(),
+                source: e.into(),
+            },
+        )?);
+        let state = row
+            .get::<'r, &str, _>("position_state")
+            .parse::<position::State>()
+            .map_err(|e| sqlx::Error::ColumnDecode {
+                index: "position_state".to_string(),
+                source: e.into(),
+            })?;
+
+        Ok(PositionRecord {
+            note_record,
+            lpnft: LpNft::new(position_id, state),
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     pin::Pin,
     sync::{Arc, Mutex},
 };
@@ -8,16 +8,20 @@ use anyhow::anyhow;
 use async_stream::try_stream;
 use camino::Utf8Path;
 use futures::stream::{StreamExt, TryStreamExt};
+use penumbra_chain::Epoch;
 use penumbra_crypto::{
     asset,
-    dex::{swap::SwapPlaintext, TradingPair},
+    dex::{lp::position, swap::SwapPlaintext, TradingPair},
     keys::{AccountID, AddressIndex, FullViewingKey},
+    stake::{DelegationToken, IdentityKey, UnbondingToken},
     transaction::Fee,
     Address, Amount, Value,
 };
 use penumbra_proto::{
     client::v1alpha1::{
+        oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+        ValidatorInfoRequest,
     },
     core::crypto::v1alpha1 as pbc,
     view::v1alpha1::{
@@ -38,7 +42,10 @@ use tokio_stream::wrappers::WatchStream;
 use tonic::async_trait;
 use tracing::instrument;
 
-use crate::{Storage, Worker};
+use crate::{
+    staking_report::{self, StakingReport, UnbondingReport},
+    Storage, SyncMode, Worker,
+};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -54,7 +61,10 @@ pub struct ViewService {
     // A shared error slot for errors bubbled up by the worker. This is a regular Mutex
     // rather than a Tokio Mutex because it should be uncontended.
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
-    account_id: AccountID,
+    // The full viewing keys tracked by the view service, by account ID.
+    fvks: Arc<BTreeMap<AccountID, FullViewingKey>>,
+    // The account used for requests that don't specify one.
+    default_account_id: AccountID,
     // A copy of the SCT used by the worker task.
     state_commitment_tree: Arc<RwLock<penumbra_tct::Tree>>,
     // The address of the pd+tendermint node.
@@ -67,36 +77,52 @@ pub struct ViewService {
 
 impl ViewService {
     /// Convenience method that calls [`Storage::load_or_initialize`] and then [`Self::new`].
+    ///
+    /// The first of the `fvks` is the default account, for requests that don't
+    /// specify one.
     pub async fn load_or_initialize(
         storage_path: impl AsRef<Utf8Path>,
-        fvk: &FullViewingKey,
+        fvks: &[FullViewingKey],
         node: String,
         pd_port: u16,
+        sync_mode: SyncMode,
     ) -> anyhow::Result<Self> {
-        let storage = Storage::load_or_initialize(storage_path, fvk, node.clone(), pd_port).await?;
+        let storage =
+            Storage::load_or_initialize(storage_path, fvks, node.clone(), pd_port).await?;
 
-        Self::new(storage, node, pd_port).await
+        Self::new(storage, node, pd_port, sync_mode).await
     }
 
     /// Constructs a new [`ViewService`], spawning a sync task internally.
     ///
-    /// The sync task uses the provided `client` to sync with the chain.
+    /// The sync task uses the provided `client` to sync with the chain, finding
+    /// notes as specified by the `sync_mode`.
     ///
     /// To create multiple [`ViewService`]s, clone the [`ViewService`] returned
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
-    pub async fn new(storage: Storage, node: String, pd_port: u16) -> Result<Self, anyhow::Error> {
+    pub async fn new(
+        storage: Storage,
+        node: String,
+        pd_port: u16,
+        sync_mode: SyncMode,
+    ) -> Result<Self, anyhow::Error> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone(), pd_port).await?;
+            Worker::new(storage.clone(), node.clone(), pd_port, sync_mode).await?;
 
         tokio::spawn(worker.run());
 
-        let fvk = storage.full_viewing_key().await?;
-        let account_id = fvk.hash();
+        let fvks = storage.full_viewing_keys().await?;
+        let default_account_id = fvks
+            .first()
+            .ok_or_else(|| anyhow!("view database has no full viewing keys"))?
+            .hash();
+        let fvks = fvks.into_iter().map(|fvk| (fvk.hash(), fvk)).collect();
 
         Ok(Self {
             storage,
-            account_id,
+            fvks: Arc::new(fvks),
+            default_account_id,
             error_slot,
             sync_height_rx,
             state_commitment_tree: sct,
@@ -105,20 +131,20 @@ impl ViewService {
         })
     }
 
-    async fn check_fvk(&self, fvk: Option<&pbc::AccountId>) -> Result<(), tonic::Status> {
+    async fn check_fvk(
+        &self,
+        fvk: Option<&pbc::AccountId>,
+    ) -> Result<FullViewingKey, tonic::Status> {
         // Takes an Option to avoid making the caller handle missing fields,
-        // should error on None or wrong account ID
+        // should error on None or unknown account ID
         match fvk {
-            Some(fvk) => {
-                if fvk != &self.account_id.into() {
-                    return Err(tonic::Status::new(
-                        tonic::Code::InvalidArgument,
-                        "Invalid account ID",
-                    ));
-                }
-
-                Ok(())
-            }
+            Some(fvk) => AccountID::try_from(fvk.clone())
+                .ok()
+                .and_then(|account_id| self.fvks.get(&account_id))
+                .cloned()
+                .ok_or_else(|| {
+                    tonic::Status::new(tonic::Code::InvalidArgument, "Invalid account ID")
+                }),
             None => Err(tonic::Status::new(
                 tonic::Code::InvalidArgument,
                 "Missing FVK",
@@ -126,6 +152,18 @@ impl ViewService {
         }
     }
 
+    /// Like [`Self::check_fvk`], but falls back to the default account if none
+    /// is specified.
+    async fn fvk_or_default(
+        &self,
+        fvk: Option<&pbc::AccountId>,
+    ) -> Result<FullViewingKey, tonic::Status> {
+        match fvk {
+            Some(_) => self.check_fvk(fvk).await,
+            None => Ok(self.fvks[&self.default_account_id].clone()),
+        }
+    }
+
     async fn check_worker(&self) -> Result<(), tonic::Status> {
         // If the shared error slot is set, then an error has occurred in the worker
         // that we should bubble up.
@@ -180,6 +218,109 @@ impl ViewService {
         Ok((latest_known_block_height, node_catching_up))
     }
 
+    /// Computes the staking rewards of the delegations made by the given
+    /// account, and the state of its unbonding tokens.
+    #[instrument(skip(self))]
+    pub async fn staking_report(&self, account_id: AccountID) -> anyhow::Result<StakingReport> {
+        let params = self.storage.chain_params().await?;
+        let sync_height = self.storage.last_sync_height().await?.unwrap_or(0);
+        let epoch_index = Epoch::from_height(sync_height, params.epoch_duration).index;
+
+        // Value the delegation tokens still held at each validator's current exchange rate.
+        let mut client =
+            ObliviousQueryServiceClient::connect(format!("http://{}:{}", self.node, self.pd_port))
+                .await?;
+        let exchange_rates = client
+            .validator_info(ValidatorInfoRequest {
+                chain_id: params.chain_id,
+                show_inactive: true,
+            })
+            .await?
+            .into_inner()
+            .map_err(anyhow::Error::from)
+            .and_then(|rsp| async move {
+                let rate_data = rsp
+                    .validator_info
+                    .and_then(|info| info.rate_data)
+                    .ok_or_else(|| anyhow!("missing rate data in ValidatorInfoResponse"))?;
+                let identity_key = IdentityKey::try_from(
+                    rate_data
+                        .identity_key
+                        .ok_or_else(|| anyhow!("missing identity key in RateData"))?,
+                )?;
+                Ok((identity_key, rate_data.validator_exchange_rate))
+            })
+            .try_collect::<BTreeMap<_, _>>()
+            .await?;
+
+        // Each unbonding token is one unit of stake, and its denomination
+        // records the validator and the unbonding period.
+        let denoms = self
+            .storage
+            .assets()
+            .await?
+            .into_iter()
+            .map(|asset| (asset.id, asset.denom))
+            .collect::<BTreeMap<_, _>>();
+        // The account's notes, both spent and unspent.
+        let records = self
+            .storage
+            .notes(Some(account_id), true, None, None, 0)
+            .await?;
+        // The amount of each asset held in unspent notes.
+        let mut held_amounts = BTreeMap::<asset::Id, Amount>::new();
+        for record in records
+            .iter()
+            .filter(|record| record.height_spent.is_none())
+        {
+            let amount = held_amounts
+                .entry(record.note.asset_id())
+                .or_insert_with(Amount::zero);
+            *amount = *amount + record.note.amount();
+        }
+        // The delegation tokens held are counted from the unspent notes, since
+        // they can be transferred in and out as well as delegated and undelegated.
+        let held = held_amounts
+            .iter()
+            .filter_map(|(asset_id, amount)| {
+                let token = DelegationToken::try_from(denoms.get(asset_id)?.clone()).ok()?;
+                Some((token.validator(), *amount))
+            })
+            .collect::<BTreeMap<_, _>>();
+        // Only transactions spending the account's notes were made by it.
+        let nullifiers = records
+            .iter()
+            .map(|record| record.nullifier)
+            .collect::<BTreeSet<_>>();
+        let transactions = self.storage.transactions_by_account(account_id).await?;
+        let delegations = staking_report::delegation_reports(
+            transactions.iter().map(|(_, tx)| tx),
+            &nullifiers,
+            &held,
+            &exchange_rates,
+        )?;
+
+        let unbondings = held_amounts
+            .into_iter()
+            .filter_map(|(asset_id, amount)| {
+                let token = UnbondingToken::try_from(denoms.get(&asset_id)?.clone()).ok()?;
+                Some(UnbondingReport {
+                    identity_key: token.validator(),
+                    start_epoch_index: token.start_epoch_index(),
+                    end_epoch_index: token.end_epoch_index(),
+                    amount,
+                    epochs_remaining: token.end_epoch_index().saturating_sub(epoch_index),
+                })
+            })
+            .collect();
+
+        Ok(StakingReport {
+            epoch_index,
+            delegations,
+            unbondings,
+        })
+    }
+
     #[instrument(skip(self))]
     pub async fn status(&self) -> Result<StatusResponse, anyhow::Error> {
         let sync_height = self.storage.last_sync_height().await?.unwrap_or(0);
@@ -213,6 +354,8 @@ impl ViewService {
 impl ViewProtocolService for ViewService {
     type NotesStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::NotesResponse, tonic::Status>> + Send>>;
+    type PositionsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::PositionsResponse, tonic::Status>> + Send>>;
     type AssetsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AssetsResponse, tonic::Status>> + Send>>;
     type StatusStreamStream = Pin<
@@ -405,6 +548,12 @@ impl ViewProtocolService for ViewService {
             ));
         }
 
+        // Add one clue per output, so that recipients can use detection.
+        let fmd_params = self.storage.fmd_parameters().await.map_err(|e| {
+            tonic::Status::unavailable(format!("Could not retrieve FMD parameters: {:#}", e))
+        })?;
+        plan.add_all_clue_plans(&mut OsRng, fmd_params.precision_bits.into());
+
         Ok(tonic::Response::new(TransactionPlannerResponse {
             plan: Some(plan.into()),
         }))
@@ -414,10 +563,9 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::AddressByIndexRequest>,
     ) -> Result<tonic::Response<pb::AddressByIndexResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let fvk = self
+            .fvk_or_default(request.get_ref().account_id.as_ref())
+            .await?;
 
         let address_index = request
             .into_inner()
@@ -437,10 +585,9 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::IndexByAddressRequest>,
     ) -> Result<tonic::Response<pb::IndexByAddressResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let fvk = self
+            .fvk_or_default(request.get_ref().account_id.as_ref())
+            .await?;
 
         let address: penumbra_crypto::Address = request
             .into_inner()
@@ -460,10 +607,9 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::EphemeralAddressRequest>,
     ) -> Result<tonic::Response<pb::EphemeralAddressResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let fvk = self
+            .fvk_or_default(request.get_ref().account_id.as_ref())
+            .await?;
 
         let address_index = request
             .into_inner()
@@ -485,12 +631,11 @@ impl ViewProtocolService for ViewService {
     ) -> Result<tonic::Response<pb::TransactionPerspectiveResponse>, tonic::Status> {
         self.check_worker().await?;
 
-        let request = request.into_inner();
+        let fvk = self
+            .fvk_or_default(request.get_ref().account_id.as_ref())
+            .await?;
 
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let request = request.into_inner();
 
         let tx = self
             .storage
@@ -721,8 +866,10 @@ impl ViewProtocolService for ViewService {
         request: tonic::Request<pb::NotesRequest>,
     ) -> Result<tonic::Response<Self::NotesStream>, tonic::Status> {
         self.check_worker().await?;
-        self.check_fvk(request.get_ref().account_id.as_ref())
-            .await?;
+        let account_id = self
+            .check_fvk(request.get_ref().account_id.as_ref())
+            .await?
+            .hash();
 
         let include_spent = request.get_ref().include_spent;
         let asset_id = request
@@ -743,7 +890,13 @@ impl ViewProtocolService for ViewService {
 
         let notes = self
             .storage
-            .notes(include_spent, asset_id, address_index, amount_to_spend)
+            .notes(
+                Some(account_id),
+                include_spent,
+                asset_id,
+                address_index,
+                amount_to_spend,
+            )
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {}", e)))?;
 
@@ -764,6 +917,67 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn positions(
+        &self,
+        request: tonic::Request<pb::PositionsRequest>,
+    ) -> Result<tonic::Response<Self::PositionsStream>, tonic::Status> {
+        self.check_worker().await?;
+        let account_id = self
+            .check_fvk(request.get_ref().account_id.as_ref())
+            .await?
+            .hash();
+
+        let include_spent = request.get_ref().include_spent;
+        let position_state = request
+            .get_ref()
+            .position_state
+            .to_owned()
+            .map(position::State::try_from)
+            .map_or(Ok(None), |v| v.map(Some))
+            .map_err(|_| tonic::Status::invalid_argument("invalid position state"))?;
+
+        let positions = self
+            .storage
+            .positions(Some(account_id), include_spent, position_state)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching positions: {}", e)))?;
+
+        let stream = try_stream! {
+            for position in positions {
+                yield pb::PositionsResponse {
+                    position_record: Some(position.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting positions: {}", e))
+                })
+                .boxed(),
+        ))
+    }
+
+    async fn staking(
+        &self,
+        request: tonic::Request<pb::StakingRequest>,
+    ) -> Result<tonic::Response<pb::StakingResponse>, tonic::Status> {
+        self.check_worker().await?;
+        let account_id = self
+            .fvk_or_default(request.get_ref().account_id.as_ref())
+            .await?
+            .hash();
+
+        let report = self.staking_report(account_id).await.map_err(|e| {
+            tonic::Status::unavailable(format!("error computing staking report: {}", e))
+        })?;
+
+        Ok(tonic::Response::new(pb::StakingResponse {
+            report: Some(report.into()),
+        }))
+    }
+
     async fn assets(
         &self,
         _request: tonic::Request<pb::AssetsRequest>,
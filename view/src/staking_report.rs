@@ -0,0 +1,440 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use penumbra_crypto::{stake::IdentityKey, Amount, Nullifier};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use penumbra_transaction::{Action, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// Corresponds to the StakingReport proto
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::StakingReport", into = "pb::StakingReport")]
+pub struct StakingReport {
+    /// The index of the epoch the report was computed in.
+    pub epoch_index: u64,
+    /// The account's delegations, by validator.
+    pub delegations: Vec<DelegationReport>,
+    /// The account's unbonding tokens.
+    pub unbondings: Vec<UnbondingReport>,
+}
+
+/// Corresponds to the DelegationReport proto
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::DelegationReport", into = "pb::DelegationReport")]
+pub struct DelegationReport {
+    /// The identity key of the validator delegated to.
+    pub identity_key: IdentityKey,
+    /// The amount of the validator's delegation tokens currently held.
+    pub delegation_amount: Amount,
+    /// The stake paid for the delegation tokens currently held.
+    pub cost_basis: Amount,
+    /// The stake the delegation tokens currently held would unbond to.
+    pub current_value: Amount,
+    /// The rewards realized by undelegating from the validator.
+    pub realized_rewards: i64,
+    /// The rewards accrued by the delegation tokens currently held.
+    pub unrealized_rewards: i64,
+}
+
+/// Corresponds to the UnbondingReport proto
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::UnbondingReport", into = "pb::UnbondingReport")]
+pub struct UnbondingReport {
+    /// The identity key of the validator undelegated from.
+    pub identity_key: IdentityKey,
+    /// The index of the epoch in which unbonding started.
+    pub start_epoch_index: u64,
+    /// The index of the epoch in which unbonding completes.
+    pub end_epoch_index: u64,
+    /// The amount of unbonding tokens held.
+    pub amount: Amount,
+    /// The number of epochs until the tokens can be claimed, or zero if they
+    /// can be claimed now.
+    pub epochs_remaining: u64,
+}
+
+/// The stake paid for and received from an account's delegations to a single
+/// validator.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Ledger {
+    delegation_amount: u128,
+    cost_basis: u128,
+    realized_rewards: i128,
+}
+
+impl Ledger {
+    fn delegate(&mut self, unbonded_amount: u128, delegation_amount: u128) -> anyhow::Result<()> {
+        self.delegation_amount = checked(self.delegation_amount.checked_add(delegation_amount))?;
+        self.cost_basis = checked(self.cost_basis.checked_add(unbonded_amount))?;
+        Ok(())
+    }
+
+    /// Undelegated tokens are assumed to have been paid for at the average
+    /// price of the tokens held, so the rewards realized are the stake
+    /// received less their share of the cost basis.
+    fn undelegate(&mut self, unbonded_amount: u128, delegation_amount: u128) -> anyhow::Result<()> {
+        let delegation_amount = delegation_amount.min(self.delegation_amount);
+        let basis = self.basis_of(delegation_amount)?;
+        let rewards = difference(unbonded_amount, basis)?;
+        self.realized_rewards = checked(self.realized_rewards.checked_add(rewards))?;
+        self.delegation_amount -= delegation_amount;
+        self.cost_basis -= basis;
+        Ok(())
+    }
+
+    /// Brings the ledger in line with the delegation tokens actually held,
+    /// which differ from the ones delegated and undelegated if tokens were
+    /// transferred.
+    ///
+    /// Tokens sent away leave with their share of the cost basis, realizing
+    /// no rewards. Tokens received were paid for by someone else, so they're
+    /// added at their current value, accruing no rewards until the exchange
+    /// rate changes.
+    fn reconcile(&mut self, held: u128, exchange_rate: u64) -> anyhow::Result<()> {
+        if held < self.delegation_amount {
+            let basis = self.basis_of(self.delegation_amount - held)?;
+            self.cost_basis -= basis;
+        } else {
+            let value = unbonded_value(held - self.delegation_amount, exchange_rate)?;
+            self.cost_basis = checked(self.cost_basis.checked_add(value))?;
+        }
+        self.delegation_amount = held;
+        Ok(())
+    }
+
+    /// The share of the cost basis paid for `delegation_amount` of the tokens held.
+    fn basis_of(&self, delegation_amount: u128) -> anyhow::Result<u128> {
+        if self.delegation_amount == 0 {
+            return Ok(0);
+        }
+        checked(
+            self.cost_basis
+                .checked_mul(delegation_amount)
+                .map(|product| product / self.delegation_amount),
+        )
+    }
+}
+
+/// The stake `delegation_amount` delegation tokens unbond to at `exchange_rate`.
+///
+/// This is the same computation as `RateData::unbonded_amount`.
+fn unbonded_value(delegation_amount: u128, exchange_rate: u64) -> anyhow::Result<u128> {
+    checked(
+        delegation_amount
+            .checked_mul(exchange_rate.into())
+            .map(|product| product / 1_0000_0000),
+    )
+}
+
+/// The signed difference `a - b`.
+fn difference(a: u128, b: u128) -> anyhow::Result<i128> {
+    checked(
+        i128::try_from(a)
+            .ok()
+            .zip(i128::try_from(b).ok())
+            .and_then(|(a, b)| a.checked_sub(b)),
+    )
+}
+
+fn checked<T>(value: Option<T>) -> anyhow::Result<T> {
+    value.ok_or_else(|| anyhow::anyhow!("overflow computing delegation rewards"))
+}
+
+/// Computes the rewards of the delegations made by `transactions`, given in
+/// chain order, valuing the delegation tokens `held` at the current
+/// `exchange_rates` of each validator.
+///
+/// Each delegation and undelegation records the validator's exchange rate in
+/// the epoch it was made, as the ratio of the stake paid or received to the
+/// delegation tokens produced or consumed, so the historical rates don't need
+/// to be looked up separately. The ledger built from them is then reconciled
+/// with the tokens actually held, to account for tokens transferred in or out.
+///
+/// Only the transactions spending one of `our_nullifiers` were made by the
+/// account, so any others, such as inbound transfers, are skipped.
+pub(crate) fn delegation_reports<'a>(
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+    our_nullifiers: &BTreeSet<Nullifier>,
+    held: &BTreeMap<IdentityKey, Amount>,
+    exchange_rates: &BTreeMap<IdentityKey, u64>,
+) -> anyhow::Result<Vec<DelegationReport>> {
+    let mut ledgers = BTreeMap::<IdentityKey, Ledger>::new();
+    for action in transactions
+        .into_iter()
+        .filter(|transaction| {
+            transaction
+                .spent_nullifiers()
+                .any(|nf| our_nullifiers.contains(&nf))
+        })
+        .flat_map(Transaction::actions)
+    {
+        match action {
+            Action::Delegate(delegate) => ledgers
+                .entry(delegate.validator_identity)
+                .or_default()
+                .delegate(
+                delegate.unbonded_amount.into(),
+                delegate.delegation_amount.into(),
+            )?,
+            Action::Undelegate(undelegate) => ledgers
+                .entry(undelegate.validator_identity)
+                .or_default()
+                .undelegate(
+                    undelegate.unbonded_amount.into(),
+                    undelegate.delegation_amount.into(),
+                )?,
+            _ => {}
+        }
+    }
+    for identity_key in held.keys() {
+        ledgers.entry(identity_key.clone()).or_default();
+    }
+
+    ledgers
+        .into_iter()
+        .map(|(identity_key, mut ledger)| {
+            let exchange_rate = *exchange_rates.get(&identity_key).ok_or_else(|| {
+                anyhow::anyhow!("missing exchange rate for validator {}", identity_key)
+            })?;
+            let held = held
+                .get(&identity_key)
+                .copied()
+                .unwrap_or_else(Amount::zero);
+            ledger.reconcile(held.into(), exchange_rate)?;
+
+            let current_value = unbonded_value(ledger.delegation_amount, exchange_rate)?;
+            let rewards = |rewards: i128| {
+                i64::try_from(rewards).map_err(|_| {
+                    anyhow::anyhow!(
+                        "rewards for validator {} out of range: {}",
+                        identity_key,
+                        rewards
+                    )
+                })
+            };
+            Ok(DelegationReport {
+                identity_key: identity_key.clone(),
+                delegation_amount: ledger.delegation_amount.into(),
+                cost_basis: ledger.cost_basis.into(),
+                current_value: current_value.into(),
+                realized_rewards: rewards(ledger.realized_rewards)?,
+                unrealized_rewards: rewards(difference(current_value, ledger.cost_basis)?)?,
+            })
+        })
+        .collect()
+}
+
+impl DomainType for StakingReport {
+    type Proto = pb::StakingReport;
+}
+impl From<StakingReport> for pb::StakingReport {
+    fn from(v: StakingReport) -> Self {
+        pb::StakingReport {
+            epoch_index: v.epoch_index,
+            delegations: v.delegations.into_iter().map(Into::into).collect(),
+            unbondings: v.unbondings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::StakingReport> for StakingReport {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::StakingReport) -> Result<Self, Self::Error> {
+        Ok(StakingReport {
+            epoch_index: v.epoch_index,
+            delegations: v
+                .delegations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            unbondings: v
+                .unbondings
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl DomainType for DelegationReport {
+    type Proto = pb::DelegationReport;
+}
+impl From<DelegationReport> for pb::DelegationReport {
+    fn from(v: DelegationReport) -> Self {
+        pb::DelegationReport {
+            identity_key: Some(v.identity_key.into()),
+            delegation_amount: Some(v.delegation_amount.into()),
+            cost_basis: Some(v.cost_basis.into()),
+            current_value: Some(v.current_value.into()),
+            realized_rewards: v.realized_rewards,
+            unrealized_rewards: v.unrealized_rewards,
+        }
+    }
+}
+
+impl TryFrom<pb::DelegationReport> for DelegationReport {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::DelegationReport) -> Result<Self, Self::Error> {
+        Ok(DelegationReport {
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                .try_into()?,
+            delegation_amount: v
+                .delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing delegation amount"))?
+                .try_into()?,
+            cost_basis: v
+                .cost_basis
+                .ok_or_else(|| anyhow::anyhow!("missing cost basis"))?
+                .try_into()?,
+            current_value: v
+                .current_value
+                .ok_or_else(|| anyhow::anyhow!("missing current value"))?
+                .try_into()?,
+            realized_rewards: v.realized_rewards,
+            unrealized_rewards: v.unrealized_rewards,
+        })
+    }
+}
+
+impl DomainType for UnbondingReport {
+    type Proto = pb::UnbondingReport;
+}
+impl From<UnbondingReport> for pb::UnbondingReport {
+    fn from(v: UnbondingReport) -> Self {
+        pb::UnbondingReport {
+            identity_key: Some(v.identity_key.into()),
+            start_epoch_index: v.start_epoch_index,
+            end_epoch_index: v.end_epoch_index,
+            amount: Some(v.amount.into()),
+            epochs_remaining: v.epochs_remaining,
+        }
+    }
+}
+
+impl TryFrom<pb::UnbondingReport> for UnbondingReport {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::UnbondingReport) -> Result<Self, Self::Error> {
+        Ok(UnbondingReport {
+            identity_key: v
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                .try_into()?,
+            start_epoch_index: v.start_epoch_index,
+            end_epoch_index: v.end_epoch_index,
+            amount: v
+                .amount
+                .ok_or_else(|| anyhow::anyhow!("missing amount"))?
+                .try_into()?,
+            epochs_remaining: v.epochs_remaining,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::rdsa::{SigningKey, SpendAuth};
+    use penumbra_transaction::{action::Undelegate, TransactionBody};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn undelegating_realizes_rewards_at_the_average_cost() -> anyhow::Result<()> {
+        let mut ledger = Ledger::default();
+        // Delegate at exchange rates of 1 and 2.
+        ledger.delegate(100, 100)?;
+        ledger.delegate(200, 100)?;
+        assert_eq!(ledger.delegation_amount, 200);
+        assert_eq!(ledger.cost_basis, 300);
+
+        // Half the tokens were paid for with half the cost basis, 150, so
+        // receiving 300 for them realizes 150 of rewards.
+        ledger.undelegate(300, 100)?;
+        assert_eq!(
+            ledger,
+            Ledger {
+                delegation_amount: 100,
+                cost_basis: 150,
+                realized_rewards: 150,
+            }
+        );
+
+        // Undelegating more than is held only consumes what is held.
+        ledger.undelegate(100, 200)?;
+        assert_eq!(
+            ledger,
+            Ledger {
+                delegation_amount: 0,
+                cost_basis: 0,
+                realized_rewards: 100,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn transferred_tokens_are_reconciled() -> anyhow::Result<()> {
+        let mut ledger = Ledger::default();
+        ledger.delegate(100, 100)?;
+
+        // Sending half the tokens away takes half the cost basis with them,
+        // without realizing any rewards.
+        ledger.reconcile(50, 2_0000_0000)?;
+        assert_eq!(
+            ledger,
+            Ledger {
+                delegation_amount: 50,
+                cost_basis: 50,
+                realized_rewards: 0,
+            }
+        );
+
+        // Received tokens are added at their current value.
+        ledger.reconcile(150, 2_0000_0000)?;
+        assert_eq!(
+            ledger,
+            Ledger {
+                delegation_amount: 150,
+                cost_basis: 250,
+                realized_rewards: 0,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn foreign_undelegations_are_ignored() -> anyhow::Result<()> {
+        let identity_key = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        // Someone else's undelegation, which spends none of our notes, was
+        // recorded because it also sent us delegation tokens.
+        let foreign = Transaction {
+            transaction_body: TransactionBody {
+                actions: vec![Action::Undelegate(Undelegate {
+                    validator_identity: identity_key,
+                    start_epoch_index: 0,
+                    end_epoch_index: 1,
+                    unbonded_amount: 300u64.into(),
+                    delegation_amount: 100u64.into(),
+                })],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let held = [(identity_key, 100u64.into())].into_iter().collect();
+        let exchange_rates = [(identity_key, 2_0000_0000)].into_iter().collect();
+
+        let reports = delegation_reports([&foreign], &BTreeSet::new(), &held, &exchange_rates)?;
+        assert_eq!(reports.len(), 1);
+        // The received tokens are valued when they're reconciled, and their
+        // sender's undelegation realizes no rewards for us.
+        assert_eq!(reports[0].delegation_amount, 100u64.into());
+        assert_eq!(reports[0].cost_basis, 200u64.into());
+        assert_eq!(reports[0].realized_rewards, 0);
+        assert_eq!(reports[0].unrealized_rewards, 0);
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ use parking_lot::Mutex;
 use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_crypto::{
     asset::{self, Id},
+    dex::lp::position,
+    keys::AccountID,
     note, Address, Amount, Asset, FieldExt, Fq, FullViewingKey, Note, Nullifier, Rseed, Value,
 };
 use penumbra_proto::{
@@ -21,7 +23,7 @@ use std::{collections::BTreeMap, num::NonZeroU64, sync::Arc};
 use tct::Commitment;
 use tokio::sync::broadcast::{self, error::RecvError};
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{sync::FilteredBlock, PositionRecord, SpendableNoteRecord, SwapRecord};
 
 mod sct;
 use sct::TreeStore;
@@ -45,15 +47,20 @@ pub struct Storage {
 
 impl Storage {
     /// If the database at `storage_path` exists, [`Self::load`] it, otherwise, [`Self::initialize`] it.
+    ///
+    /// If the existing database doesn't track all of the given full viewing
+    /// keys yet, they're added to it, see [`Self::add_full_viewing_keys`].
     pub async fn load_or_initialize(
         storage_path: impl AsRef<Utf8Path>,
-        fvk: &FullViewingKey,
+        fvks: &[FullViewingKey],
         node: String,
         pd_port: u16,
     ) -> anyhow::Result<Self> {
         let storage_path = storage_path.as_ref();
         if storage_path.exists() {
-            Self::load(storage_path.as_str()).await
+            let storage = Self::load(storage_path.as_str()).await?;
+            storage.add_full_viewing_keys(fvks).await?;
+            Ok(storage)
         } else {
             let mut client =
                 ObliviousQueryServiceClient::connect(format!("http://{}:{}", node, pd_port))
@@ -66,7 +73,7 @@ impl Storage {
                 .into_inner()
                 .try_into()?;
 
-            Self::initialize(storage_path, fvk.clone(), params).await
+            Self::initialize(storage_path, fvks.to_vec(), params).await
         }
     }
 
@@ -97,21 +104,93 @@ impl Storage {
     }
 
     pub async fn load(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
-        Ok(Self {
+        let storage = Self {
             pool: Self::connect(path.as_ref().as_str()).await?,
             uncommitted_height: Arc::new(Mutex::new(None)),
             scanned_notes_tx: broadcast::channel(10).0,
             scanned_nullifiers_tx: broadcast::channel(10).0,
-        })
+        };
+
+        // Bring databases created by older versions up to date.
+        sqlx::migrate!().run(&storage.pool).await?;
+        storage.backfill_account_ids().await?;
+
+        Ok(storage)
+    }
+
+    /// Attribute the notes and swaps recorded before the database tracked
+    /// accounts to the account they belong to.
+    ///
+    /// Such databases held a single full viewing key, which owns all of their
+    /// records. If there's more than one key, the owner of each record isn't
+    /// known, so the scanned state is discarded and the chain is rescanned.
+    async fn backfill_account_ids(&self) -> anyhow::Result<()> {
+        let unattributed: i64 = sqlx::query(
+            "SELECT EXISTS (SELECT 1 FROM spendable_notes WHERE account_id IS NULL)
+                OR EXISTS (SELECT 1 FROM swaps WHERE account_id IS NULL)",
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .get(0);
+        if unattributed == 0 {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+        match self.full_viewing_keys().await?.as_slice() {
+            [fvk] => {
+                let account_id = fvk.hash().0.to_vec();
+                tracing::info!(account_id = ?fvk.hash(), "attributing scanned notes and swaps to account");
+                for statement in [
+                    "UPDATE spendable_notes SET account_id = ? WHERE account_id IS NULL",
+                    "UPDATE swaps SET account_id = ? WHERE account_id IS NULL",
+                ] {
+                    sqlx::query(statement)
+                        .bind(&account_id)
+                        .execute(&mut tx)
+                        .await?;
+                }
+            }
+            _ => {
+                tracing::info!("discarding scanned state to rescan notes and swaps by account");
+                Self::discard_scanned_state(&mut tx).await?;
+            }
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Discard everything learned by scanning the chain, so that it's rescanned from genesis.
+    async fn discard_scanned_state(tx: &mut sqlx::Transaction<'_, Sqlite>) -> anyhow::Result<()> {
+        // Keep the notes table, since it also records advice.
+        for statement in [
+            "DELETE FROM spendable_notes",
+            "DELETE FROM swaps",
+            "DELETE FROM positions",
+            "DELETE FROM tx",
+            "DELETE FROM tx_by_nullifier",
+            "DELETE FROM sct_hashes",
+            "DELETE FROM sct_commitments",
+            "UPDATE sct_position SET position = 0",
+            "UPDATE sct_forgotten SET forgotten = 0",
+            "UPDATE sync_height SET height = -1",
+        ] {
+            sqlx::query(statement).execute(&mut *tx).await?;
+        }
+        Ok(())
     }
 
     pub async fn initialize(
         storage_path: impl AsRef<Utf8Path>,
-        fvk: FullViewingKey,
+        fvks: Vec<FullViewingKey>,
         params: ChainParameters,
     ) -> anyhow::Result<Self> {
         let storage_path = storage_path.as_ref();
-        tracing::debug!(%storage_path, ?fvk, ?params);
+        tracing::debug!(%storage_path, ?fvks, ?params);
+        if fvks.is_empty() {
+            return Err(anyhow!("at least one full viewing key is required"));
+        }
         // We don't want to overwrite existing data,
         // but also, SQLX will complain if the file doesn't already exist
         if storage_path.exists() {
@@ -127,11 +206,10 @@ impl Storage {
         // Run migrations
         sqlx::migrate!().run(&pool).await?;
 
-        // Initialize the database state with: empty SCT, chain params, FVK
+        // Initialize the database state with: empty SCT, chain params, FVKs
         let mut tx = pool.begin().await?;
 
         let chain_params_bytes = &ChainParameters::encode_to_vec(&params)[..];
-        let fvk_bytes = &FullViewingKey::encode_to_vec(&fvk)[..];
 
         sqlx::query!(
             "INSERT INTO chain_params (bytes) VALUES (?)",
@@ -140,9 +218,12 @@ impl Storage {
         .execute(&mut tx)
         .await?;
 
-        sqlx::query!("INSERT INTO full_viewing_key (bytes) VALUES (?)", fvk_bytes)
-            .execute(&mut tx)
-            .await?;
+        for fvk in &fvks {
+            let fvk_bytes = &FullViewingKey::encode_to_vec(fvk)[..];
+            sqlx::query!("INSERT INTO full_viewing_key (bytes) VALUES (?)", fvk_bytes)
+                .execute(&mut tx)
+                .await?;
+        }
 
         // Insert -1 as a signaling value for pre-genesis.
         // We just have to be careful to treat negative values as None
@@ -394,18 +475,63 @@ impl Storage {
         FmdParameters::decode(result.bytes.as_slice())
     }
 
-    pub async fn full_viewing_key(&self) -> anyhow::Result<FullViewingKey> {
+    /// The full viewing keys tracked by this database, in the order they were added.
+    pub async fn full_viewing_keys(&self) -> anyhow::Result<Vec<FullViewingKey>> {
         let result = query!(
             r#"
             SELECT bytes
             FROM full_viewing_key
-            LIMIT 1
+            ORDER BY rowid
             "#
         )
-        .fetch_one(&self.pool)
+        .fetch_all(&self.pool)
         .await?;
 
-        FullViewingKey::decode(result.bytes.as_slice())
+        result
+            .into_iter()
+            .map(|record| FullViewingKey::decode(record.bytes.as_slice()))
+            .collect()
+    }
+
+    /// Start tracking any of the given full viewing keys that aren't tracked yet.
+    ///
+    /// Notes sent to a newly added key may have been created in blocks that
+    /// were already scanned, so adding a key to a database that has started
+    /// syncing discards the scanned state, and the chain is rescanned from
+    /// genesis.
+    pub async fn add_full_viewing_keys(&self, fvks: &[FullViewingKey]) -> anyhow::Result<()> {
+        let known = self
+            .full_viewing_keys()
+            .await?
+            .iter()
+            .map(FullViewingKey::hash)
+            .collect::<Vec<_>>();
+        let new_fvks = fvks
+            .iter()
+            .filter(|fvk| !known.contains(&fvk.hash()))
+            .collect::<Vec<_>>();
+        if new_fvks.is_empty() {
+            return Ok(());
+        }
+
+        let rescan = self.last_sync_height().await?.is_some();
+        let mut tx = self.pool.begin().await?;
+
+        for fvk in new_fvks {
+            tracing::info!(account_id = ?fvk.hash(), "tracking new full viewing key");
+            let fvk_bytes = &FullViewingKey::encode_to_vec(fvk)[..];
+            sqlx::query!("INSERT INTO full_viewing_key (bytes) VALUES (?)", fvk_bytes)
+                .execute(&mut tx)
+                .await?;
+        }
+
+        if rescan {
+            tracing::info!("discarding scanned state to rescan for new full viewing keys");
+            Self::discard_scanned_state(&mut tx).await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
     }
 
     pub async fn state_commitment_tree(&self) -> anyhow::Result<tct::Tree> {
@@ -489,6 +615,34 @@ impl Storage {
         })
     }
 
+    /// Returns a tuple of (block height, transaction) for all transactions spending notes
+    /// belonging to the given account, in the order they were included in the chain.
+    pub async fn transactions_by_account(
+        &self,
+        account_id: AccountID,
+    ) -> anyhow::Result<Vec<(u64, Transaction)>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT tx.block_height, tx.rowid, tx.tx_bytes
+            FROM tx
+            JOIN tx_by_nullifier ON tx.tx_hash = tx_by_nullifier.tx_hash
+            JOIN spendable_notes ON tx_by_nullifier.nullifier = spendable_notes.nullifier
+            WHERE spendable_notes.account_id = ?
+            ORDER BY tx.block_height, tx.rowid",
+        )
+        .bind(account_id.0.to_vec())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok((
+                    row.get::<i64, _>("block_height") as u64,
+                    Transaction::decode(row.get::<&[u8], _>("tx_bytes"))?,
+                ))
+            })
+            .collect()
+    }
+
     // Query for a note by its note commitment, optionally waiting until the note is detected.
     pub fn note_by_nullifier(
         &self,
@@ -595,6 +749,7 @@ impl Storage {
 
     pub async fn notes(
         &self,
+        account_id: Option<AccountID>,
         include_spent: bool,
         asset_id: Option<asset::Id>,
         address_index: Option<penumbra_crypto::keys::AddressIndex>,
@@ -620,6 +775,11 @@ impl Storage {
             .map(|d| format!("x'{}'", hex::encode(d.to_bytes())))
             .unwrap_or_else(|| "address_index".to_string());
 
+        // If set, only return notes belonging to the specified account.
+        let account_clause = account_id
+            .map(|id| format!("x'{}'", hex::encode(id.0)))
+            .unwrap_or_else(|| "account_id".to_string());
+
         let result = sqlx::query_as::<_, SpendableNoteRecord>(
             format!(
                 "SELECT notes.note_commitment,
@@ -637,8 +797,9 @@ impl Storage {
             JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
             WHERE spendable_notes.height_spent IS {}
             AND notes.asset_id IS {}
-            AND spendable_notes.address_index IS {}",
-                spent_clause, asset_clause, address_clause
+            AND spendable_notes.address_index IS {}
+            AND spendable_notes.account_id IS {}",
+                spent_clause, asset_clause, address_clause, account_clause
             )
             .as_str(),
         )
@@ -759,6 +920,59 @@ impl Storage {
         Ok(())
     }
 
+    /// Query for the LPNFT notes we control, optionally including spent ones,
+    /// and optionally only those for positions in the given state or belonging
+    /// to the given account.
+    pub async fn positions(
+        &self,
+        account_id: Option<AccountID>,
+        include_spent: bool,
+        position_state: Option<position::State>,
+    ) -> anyhow::Result<Vec<PositionRecord>> {
+        let spent_clause = match include_spent {
+            false => "NULL",
+            true => "height_spent",
+        };
+
+        let state_clause = position_state
+            .map(|state| format!("'{}'", state))
+            .unwrap_or_else(|| "position_state".to_string());
+
+        let account_clause = account_id
+            .map(|id| format!("x'{}'", hex::encode(id.0)))
+            .unwrap_or_else(|| "account_id".to_string());
+
+        let result = sqlx::query_as::<_, PositionRecord>(
+            format!(
+                "SELECT notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position,
+                        positions.position_id,
+                        positions.position_state
+            FROM positions
+            JOIN notes ON positions.note_commitment = notes.note_commitment
+            JOIN spendable_notes ON positions.note_commitment = spendable_notes.note_commitment
+            WHERE spendable_notes.height_spent IS {}
+            AND positions.position_state IS {}
+            AND spendable_notes.account_id IS {}",
+                spent_clause, state_clause, account_clause
+            )
+            .as_str(),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
     /// Return advice about note contents for use in scanning.
     ///
     /// Given a list of note commitments, this method checks whether any of them
@@ -892,6 +1106,10 @@ impl Storage {
             let nullifier = note_record.nullifier.to_bytes().to_vec();
             let position = (u64::from(note_record.position)) as i64;
             let source = note_record.source.to_bytes().to_vec();
+            let account_id = filtered_block
+                .account_id(&note_record.note_commitment)?
+                .0
+                .to_vec();
 
             // We might have already seen the notes in the form of advice,
             // so we use ON CONFLICT DO NOTHING to skip re-inserting them
@@ -925,10 +1143,11 @@ impl Storage {
                         height_created,
                         address_index,
                         source,
-                        height_spent
+                        height_spent,
+                        account_id
                     )
                     VALUES
-                    (?, ?, ?, ?, ?, ?, NULL)",
+                    (?, ?, ?, ?, ?, ?, NULL, ?)",
                 note_commitment,
                 nullifier,
                 position,
@@ -936,7 +1155,29 @@ impl Storage {
                 address_index,
                 source,
                 // height_spent is NULL
+                account_id,
+            )
+            .execute(&mut dbtx)
+            .await?;
+        }
+
+        for position_record in &filtered_block.new_positions {
+            let note_commitment = position_record
+                .note_record
+                .note_commitment
+                .0
+                .to_bytes()
+                .to_vec();
+            let position_id = position_record.lpnft.position_id().0.to_vec();
+            let position_state = position_record.lpnft.state().to_string();
+
+            sqlx::query(
+                "INSERT INTO positions (note_commitment, position_id, position_state)
+                VALUES (?, ?, ?)",
             )
+            .bind(note_commitment)
+            .bind(position_id)
+            .bind(position_state)
             .execute(&mut dbtx)
             .await?;
         }
@@ -948,10 +1189,11 @@ impl Storage {
             let nullifier = swap.nullifier.to_bytes().to_vec();
             let source = swap.source.to_bytes().to_vec();
             let output_data = swap.output_data.encode_to_vec();
+            let account_id = filtered_block.account_id(&swap.swap_commitment)?.0.to_vec();
 
             sqlx::query!(
-                "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
-                VALUES (?, ?, ?, ?, ?, NULL, ?)",
+                "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source, account_id)
+                VALUES (?, ?, ?, ?, ?, NULL, ?, ?)",
                 swap_commitment,
                 swap_bytes,
                 position,
@@ -959,6 +1201,7 @@ impl Storage {
                 output_data,
                 // height_claimed is NULL
                 source,
+                account_id,
             ).execute(&mut dbtx).await?;
         }
 
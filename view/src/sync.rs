@@ -2,24 +2,59 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use penumbra_chain::{params::FmdParameters, CompactBlock, Epoch, NoteSource, StatePayload};
 use penumbra_crypto::{
-    dex::swap::{SwapPayload, SwapPlaintext},
-    FullViewingKey, Note, NotePayload, Nullifier,
+    dex::{
+        lp::LpNft,
+        swap::{SwapPayload, SwapPlaintext},
+    },
+    keys::AccountID,
+    Address, FullViewingKey, Note, NotePayload, Nullifier,
 };
 use penumbra_tct as tct;
 
-use crate::{SpendableNoteRecord, Storage, SwapRecord};
+use crate::{PositionRecord, SpendableNoteRecord, Storage, SwapRecord};
+
+/// How the view service finds the notes and swaps sent to it.
+#[derive(Clone, Debug)]
+pub enum SyncMode {
+    /// Trial-decrypt every note and swap payload in every block.
+    TrialDecryption,
+    /// Delegate detection to an untrusted detection server, and only download
+    /// and trial-decrypt the transactions it flags.
+    ///
+    /// The server is given the detection keys for the default addresses of the
+    /// first `num_accounts` accounts of each full viewing key.  Detection keys
+    /// are per-address, so notes sent to other addresses, such as ephemeral
+    /// addresses, are not detected.
+    Detection {
+        /// The URL of the detection server.
+        url: String,
+        /// The number of accounts to provide detection keys for.
+        num_accounts: u32,
+    },
+}
 
 /// Contains the results of scanning a single block.
 #[derive(Debug, Clone)]
 pub struct FilteredBlock {
     pub new_notes: Vec<SpendableNoteRecord>,
     pub new_swaps: Vec<SwapRecord>,
+    pub new_positions: Vec<PositionRecord>,
+    /// The account each new note and swap belongs to, by commitment.
+    pub accounts: BTreeMap<tct::Commitment, AccountID>,
     pub spent_nullifiers: Vec<Nullifier>,
     pub height: u64,
     pub fmd_parameters: Option<FmdParameters>,
 }
 
 impl FilteredBlock {
+    /// The account the new note or swap with the given commitment belongs to.
+    pub fn account_id(&self, commitment: &tct::Commitment) -> anyhow::Result<AccountID> {
+        self.accounts
+            .get(commitment)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("no account recorded for commitment {}", commitment))
+    }
+
     pub fn inbound_transaction_ids(&self) -> BTreeSet<[u8; 32]> {
         let mut ids = BTreeSet::new();
         let sources = self.new_notes.iter().map(|n| n.source);
@@ -32,9 +67,20 @@ impl FilteredBlock {
     }
 }
 
-#[tracing::instrument(skip(fvk, state_commitment_tree, state_payloads, nullifiers, storage))]
+/// Scan a block for notes and swaps sent to any of the given full viewing keys.
+///
+/// If `detected` is provided, only the payloads created by those transactions,
+/// or by the chain itself, are trial-decrypted.
+#[tracing::instrument(skip(
+    fvks,
+    state_commitment_tree,
+    state_payloads,
+    nullifiers,
+    storage,
+    detected
+))]
 pub async fn scan_block(
-    fvk: &FullViewingKey,
+    fvks: &[FullViewingKey],
     state_commitment_tree: &mut tct::Tree,
     CompactBlock {
         height,
@@ -48,22 +94,35 @@ pub async fn scan_block(
     }: CompactBlock,
     epoch_duration: u64,
     storage: &Storage,
+    detected: Option<&BTreeSet<[u8; 32]>>,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
+    // Notes minted by the chain have no clues, so only skip payloads created
+    // by transactions that the detection server didn't flag.
+    let should_decrypt = |source: &NoteSource| match (detected, source) {
+        (Some(detected), NoteSource::Transaction { id }) => detected.contains(id),
+        _ => true,
+    };
+
+    // Trial-decrypt a note with each of our viewing keys
     let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
+        // TODO: change fvks to Arc<[FVK]> in Worker and pass to scan_block as Arc
         // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(async move { note_payload.trial_decrypt(&fvk2) })
+        let fvks2 = fvks.to_vec();
+        tokio::spawn(async move { fvks2.iter().find_map(|fvk| note_payload.trial_decrypt(fvk)) })
     };
-    // Trial-decrypt a swap with our own specific viewing key
+    // Trial-decrypt a swap with each of our viewing keys
     let trial_decrypt_swap =
         |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
+            // TODO: change fvks to Arc<[FVK]> in Worker and pass to scan_block as Arc
             // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(async move { swap_payload.trial_decrypt(&fvk2) })
+            let fvks2 = fvks.to_vec();
+            tokio::spawn(
+                async move { fvks2.iter().find_map(|fvk| swap_payload.trial_decrypt(fvk)) },
+            )
         };
+    // Find the viewing key controlling an address, if any of ours does.
+    let fvk_for_address =
+        |address: &Address| fvks.iter().find(|fvk| fvk.address_index(address).is_some());
 
     // Nullifiers we've found in this block
     let spent_nullifiers: Vec<Nullifier> = nullifiers;
@@ -72,20 +131,27 @@ pub async fn scan_block(
     let mut note_decryptions = Vec::new();
     let mut swap_decryptions = Vec::new();
     let mut unknown_commitments = Vec::new();
+    // Position payloads carry no ciphertext, so we can only recognize their
+    // LPNFT notes using scanning advice.
+    let mut lpnfts: BTreeMap<tct::Commitment, LpNft> = BTreeMap::new();
 
     for payload in state_payloads.iter() {
         match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note(note.clone()));
+            StatePayload::Note { note, source } => {
+                if should_decrypt(source) {
+                    note_decryptions.push(trial_decrypt_note(note.clone()));
+                }
             }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap(swap.clone()));
+            StatePayload::Swap { swap, source } => {
+                if should_decrypt(source) {
+                    swap_decryptions.push(trial_decrypt_swap(swap.clone()));
+                }
             }
             StatePayload::RolledUp(commitment) => unknown_commitments.push(commitment.clone()),
-            StatePayload::Position {
-                lpnft: _,
-                commitment: _,
-            } => todo!(),
+            StatePayload::Position { lpnft, commitment } => {
+                unknown_commitments.push(commitment.clone());
+                lpnfts.insert(commitment.clone(), lpnft.clone());
+            }
         }
     }
     // Having started trial decryption in the background, ask the Storage for scanning advice:
@@ -106,6 +172,10 @@ pub async fn scan_block(
     let mut new_notes = Vec::new();
     // Newly detected claimable swaps.
     let mut new_swaps = Vec::new();
+    // Newly detected LPNFT notes.
+    let mut new_positions = Vec::new();
+    // The accounts the new notes and swaps belong to.
+    let mut accounts = BTreeMap::new();
 
     if note_advice.is_empty() && swap_advice.is_empty() {
         // If there are no notes we care about in this block, just insert the block root into the
@@ -119,11 +189,14 @@ pub async fn scan_block(
         for payload in state_payloads.into_iter() {
             // We need to insert each commitment, so use a match statement to ensure we
             // exhaustively cover all possible cases.
-            match (
-                note_advice.get(payload.commitment()),
-                swap_advice.get(payload.commitment()),
-            ) {
-                (Some(note), None) => {
+            let note = note_advice
+                .get(payload.commitment())
+                .and_then(|note| Some((note, fvk_for_address(&note.address())?)));
+            let swap = swap_advice
+                .get(payload.commitment())
+                .and_then(|swap| Some((swap, fvk_for_address(&swap.claim_address)?)));
+            match (note, swap) {
+                (Some((note, fvk)), None) => {
                     // Keep track of this commitment for later witnessing
                     let position = state_commitment_tree
                         .insert(tct::Witness::Keep, payload.commitment().clone())
@@ -133,7 +206,7 @@ pub async fn scan_block(
                     let nullifier = fvk.derive_nullifier(position, payload.commitment());
                     let address_index = fvk.incoming().index_for_diversifier(note.diversifier());
 
-                    new_notes.push(SpendableNoteRecord {
+                    let note_record = SpendableNoteRecord {
                         note_commitment: payload.commitment().clone(),
                         height_spent: None,
                         height_created: height,
@@ -142,9 +215,26 @@ pub async fn scan_block(
                         nullifier,
                         position,
                         source,
-                    });
+                    };
+
+                    if let Some(lpnft) = lpnfts.get(payload.commitment()) {
+                        if lpnft.asset_id() == note.asset_id() {
+                            new_positions.push(PositionRecord {
+                                note_record: note_record.clone(),
+                                lpnft: lpnft.clone(),
+                            });
+                        } else {
+                            tracing::warn!(
+                                %lpnft,
+                                "position payload does not match the asset of its note"
+                            );
+                        }
+                    }
+
+                    new_notes.push(note_record);
+                    accounts.insert(payload.commitment().clone(), fvk.hash());
                 }
-                (None, Some(swap)) => {
+                (None, Some((swap, fvk))) => {
                     // Keep track of this commitment for later witnessing
                     let position = state_commitment_tree
                         .insert(tct::Witness::Keep, payload.commitment().clone())
@@ -179,6 +269,7 @@ pub async fn scan_block(
                         output_data,
                         height_claimed: None,
                     });
+                    accounts.insert(payload.commitment().clone(), fvk.hash());
                 }
                 (None, None) => {
                     // Don't remember this commitment; it wasn't ours
@@ -215,6 +306,8 @@ pub async fn scan_block(
     let result = FilteredBlock {
         new_notes,
         new_swaps,
+        new_positions,
+        accounts,
         spent_nullifiers: filtered_nullifiers,
         height,
         fmd_parameters,
@@ -222,3 +315,76 @@ pub async fn scan_block(
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use camino::Utf8PathBuf;
+    use penumbra_chain::{params::ChainParameters, test_keys};
+    use penumbra_crypto::{Value, STAKING_TOKEN_ASSET_ID};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn note_payload(source: NoteSource) -> StatePayload {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 1u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        StatePayload::Note {
+            source,
+            note: note.payload(),
+        }
+    }
+
+    #[tokio::test]
+    async fn detection_skips_undetected_transactions_but_not_chain_outputs() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = Utf8PathBuf::try_from(dir.path().join("storage.sqlite"))?;
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let storage =
+            Storage::initialize(path, vec![fvk.clone()], ChainParameters::default()).await?;
+
+        let detected_id = [1u8; 32];
+        let undetected_id = [2u8; 32];
+        let block = CompactBlock {
+            height: 1,
+            state_payloads: vec![
+                note_payload(NoteSource::Transaction { id: detected_id }),
+                note_payload(NoteSource::Transaction { id: undetected_id }),
+                // Notes minted by the chain have no clues, so they're never detected.
+                note_payload(NoteSource::DaoOutput { height: 1 }),
+            ],
+            ..Default::default()
+        };
+
+        let detected = [detected_id].into_iter().collect::<BTreeSet<_>>();
+        let filtered_block = scan_block(
+            &[fvk],
+            &mut tct::Tree::new(),
+            block,
+            719,
+            &storage,
+            Some(&detected),
+        )
+        .await?;
+
+        let sources = filtered_block
+            .new_notes
+            .iter()
+            .map(|record| record.source)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![
+                NoteSource::Transaction { id: detected_id },
+                NoteSource::DaoOutput { height: 1 },
+            ]
+        );
+
+        Ok(())
+    }
+}
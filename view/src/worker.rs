@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     sync::{Arc, Mutex},
 };
 
@@ -8,9 +8,10 @@ use penumbra_crypto::{Asset, FullViewingKey, Nullifier};
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
+        detection_service_client::DetectionServiceClient,
         oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, AssetListRequest,
-        CompactBlockRangeRequest, GetBlockByHeightRequest,
+        CompactBlockRangeRequest, DetectedTransactionsRequest, GetBlockByHeightRequest,
     },
     DomainType,
 };
@@ -24,17 +25,18 @@ use penumbra_proto::client::v1alpha1::specific_query_service_client::SpecificQue
 
 use crate::{
     sync::{scan_block, FilteredBlock},
-    Storage,
+    Storage, SyncMode,
 };
 
 pub struct Worker {
     storage: Storage,
     client: ObliviousQueryServiceClient<Channel>,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
-    fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    fvks: Vec<FullViewingKey>, // TODO: notifications (see TODOs on ViewService)
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     tm_client: TendermintProxyServiceClient<Channel>,
+    sync_mode: SyncMode,
     #[cfg(feature = "sct-divergence-check")]
     specific_client: SpecificQueryServiceClient<Channel>,
 }
@@ -50,6 +52,7 @@ impl Worker {
         storage: Storage,
         node: String,
         pd_port: u16,
+        sync_mode: SyncMode,
     ) -> Result<
         (
             Self,
@@ -59,7 +62,7 @@ impl Worker {
         ),
         anyhow::Error,
     > {
-        let fvk = storage.full_viewing_key().await?;
+        let fvks = storage.full_viewing_keys().await?;
 
         // Create a shared, in-memory SCT.
         let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
@@ -85,10 +88,11 @@ impl Worker {
                 storage,
                 client,
                 sct: sct.clone(),
-                fvk,
+                fvks,
                 error_slot: error_slot.clone(),
                 sync_height_tx,
                 tm_client,
+                sync_mode,
                 #[cfg(feature = "sct-divergence-check")]
                 specific_client,
             },
@@ -136,9 +140,16 @@ impl Worker {
         Ok(())
     }
 
+    /// Fetch the transactions that created our new notes or spent our
+    /// nullifiers in the given block.
+    ///
+    /// If the transactions flagged by a detection server are provided, they're
+    /// used instead of downloading the full block, unless they don't account
+    /// for all the notes and nullifiers we found.
     pub async fn fetch_transactions(
         &self,
         filtered_block: &FilteredBlock,
+        detected: Option<&BTreeMap<[u8; 32], Transaction>>,
     ) -> anyhow::Result<Vec<Transaction>> {
         let inbound_transaction_ids = filtered_block.inbound_transaction_ids();
         let spent_nullifiers = filtered_block
@@ -153,6 +164,21 @@ impl Worker {
             return Ok(Vec::new());
         }
 
+        if let Some(detected) = detected {
+            if let Some(transactions) =
+                detected_transactions(detected, &inbound_transaction_ids, &spent_nullifiers)
+            {
+                return Ok(transactions);
+            }
+
+            // A spend with no outputs to us carries no clues of ours, so it
+            // can only be found by looking at the whole block.
+            tracing::debug!(
+                height = filtered_block.height,
+                "detected transactions are incomplete"
+            );
+        }
+
         tracing::debug!(
             height = filtered_block.height,
             "fetching full transaction data"
@@ -201,6 +227,40 @@ impl Worker {
 
         let epoch_duration = self.storage.chain_params().await?.epoch_duration;
 
+        // In detection mode, request the flagged transactions for the same
+        // range of blocks, and consume them in lockstep with the compact blocks.
+        let mut detected_stream = match &self.sync_mode {
+            SyncMode::TrialDecryption => None,
+            SyncMode::Detection { url, num_accounts } => {
+                tracing::info!(%url, %num_accounts, "delegating detection to detection server");
+                let detection_keys = self
+                    .fvks
+                    .iter()
+                    .flat_map(|fvk| {
+                        (0..*num_accounts).map(move |account| {
+                            fvk.incoming()
+                                .payment_address(account.into())
+                                .1
+                                .to_bytes()
+                                .to_vec()
+                        })
+                    })
+                    .collect();
+                let stream = DetectionServiceClient::connect(url.clone())
+                    .await?
+                    .detected_transactions(tonic::Request::new(DetectedTransactionsRequest {
+                        chain_id: self.storage.chain_params().await?.chain_id,
+                        start_height,
+                        end_height: 0,
+                        keep_alive: true,
+                        detection_keys,
+                    }))
+                    .await?
+                    .into_inner();
+                Some(stream)
+            }
+        };
+
         let mut stream = self
             .client
             .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
@@ -232,6 +292,34 @@ impl Worker {
 
             let height = block.height;
 
+            let detected = match detected_stream.as_mut() {
+                Some(detected_stream) => {
+                    let response = detected_stream.message().await?.ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "detection server closed the stream before height {}",
+                            height
+                        )
+                    })?;
+                    if response.height != height {
+                        return Err(anyhow::anyhow!(
+                            "detection server sent height {}, expected height {}",
+                            response.height,
+                            height
+                        ));
+                    }
+                    let transactions = response
+                        .transactions
+                        .into_iter()
+                        .map(|transaction| {
+                            let transaction = Transaction::try_from(transaction)?;
+                            Ok((transaction.id(), transaction))
+                        })
+                        .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+                    Some(transactions)
+                }
+                None => None,
+            };
+
             // Lock the SCT only while processing this block.
             let mut sct_guard = self.sct.write().await;
 
@@ -251,17 +339,23 @@ impl Worker {
                 self.sync_height_tx.send(height)?;
             } else {
                 // Otherwise, scan the block and commit its changes:
+                let detected_ids = detected
+                    .as_ref()
+                    .map(|detected| detected.keys().cloned().collect::<BTreeSet<_>>());
                 let filtered_block = scan_block(
-                    &self.fvk,
+                    &self.fvks,
                     &mut sct_guard,
                     block,
                     epoch_duration,
                     &self.storage,
+                    detected_ids.as_ref(),
                 )
                 .await?;
 
                 // Download any transactions we detected.
-                let transactions = self.fetch_transactions(&filtered_block).await?;
+                let transactions = self
+                    .fetch_transactions(&filtered_block, detected.as_ref())
+                    .await?;
 
                 self.storage
                     .record_block(filtered_block.clone(), transactions, &mut sct_guard)
@@ -303,6 +397,39 @@ impl Worker {
     }
 }
 
+/// Select the transactions that created our new notes or spent our nullifiers
+/// from the transactions flagged by a detection server.
+///
+/// Returns `None` if they don't account for all of the notes and nullifiers,
+/// in which case the full block needs to be fetched.
+fn detected_transactions(
+    detected: &BTreeMap<[u8; 32], Transaction>,
+    inbound_transaction_ids: &BTreeSet<[u8; 32]>,
+    spent_nullifiers: &BTreeSet<Nullifier>,
+) -> Option<Vec<Transaction>> {
+    let transactions = detected
+        .iter()
+        .filter(|(id, transaction)| {
+            inbound_transaction_ids.contains(*id)
+                || transaction
+                    .spent_nullifiers()
+                    .any(|nf| spent_nullifiers.contains(&nf))
+        })
+        .map(|(_, transaction)| transaction.clone())
+        .collect::<Vec<_>>();
+
+    let found_all_inbound = inbound_transaction_ids
+        .iter()
+        .all(|id| detected.contains_key(id));
+    let found_all_spends = spent_nullifiers.iter().all(|nf| {
+        transactions
+            .iter()
+            .any(|transaction| transaction.spent_nullifiers().any(|spent| spent == *nf))
+    });
+
+    (found_all_inbound && found_all_spends).then_some(transactions)
+}
+
 async fn fetch_block(
     client: &mut TendermintProxyServiceClient<Channel>,
     height: i64,
@@ -347,3 +474,42 @@ async fn sct_divergence_check(
         Err(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::Fq;
+
+    use super::*;
+
+    #[test]
+    fn detected_transactions_fall_back_when_a_spend_is_missing() {
+        let inbound = Transaction::default();
+        let inbound_id = inbound.id();
+        let detected = [(inbound_id, inbound)]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let inbound_transaction_ids = [inbound_id].into_iter().collect::<BTreeSet<_>>();
+
+        // The detected transactions account for all our new notes.
+        let transactions =
+            detected_transactions(&detected, &inbound_transaction_ids, &BTreeSet::new())
+                .expect("detected transactions are complete");
+        assert_eq!(
+            transactions.iter().map(Transaction::id).collect::<Vec<_>>(),
+            vec![inbound_id]
+        );
+
+        // A spend of one of our notes with no outputs to us isn't detected, so
+        // the full block has to be fetched to find it.
+        let spent_nullifiers = [Nullifier(Fq::from(1u64))]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert!(
+            detected_transactions(&detected, &inbound_transaction_ids, &spent_nullifiers).is_none()
+        );
+
+        // Likewise for a new note created by an undetected transaction.
+        let missing_ids = [inbound_id, [0u8; 32]].into_iter().collect::<BTreeSet<_>>();
+        assert!(detected_transactions(&detected, &missing_ids, &BTreeSet::new()).is_none());
+    }
+}
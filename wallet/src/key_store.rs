@@ -1,42 +1,342 @@
+use std::fmt;
+
 use penumbra_crypto::keys::{SeedPhrase, SpendKey};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
-/// A wallet file storing a single spend authority.
+mod encrypted;
+pub use encrypted::{EncryptedKeyStore, KdfParams};
+
+/// The name of the account created along with a new wallet.
+pub const DEFAULT_ACCOUNT_NAME: &str = "default";
+
+/// A named spend authority stored in a [`KeyStore`].
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KeyStore {
+pub struct Account {
+    /// The name of the account, unique within the wallet.
+    pub name: String,
+    /// The index used to derive the spend key from the wallet's seed phrase,
+    /// or `None` if the spend key was not derived from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<u64>,
     #[serde_as(as = "DisplayFromStr")]
     pub spend_key: SpendKey,
 }
 
+/// A wallet file storing one or more named spend authorities.
+///
+/// Accounts are derived from the wallet's seed phrase, and one of them is
+/// selected as the active account used by default.
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "serde_helpers::KeyStoreHelper")]
+pub struct KeyStore {
+    /// The seed phrase the accounts are derived from, if it is known.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed_phrase: Option<SeedPhrase>,
+    accounts: Vec<Account>,
+    active_account: String,
+}
+
 impl KeyStore {
-    /// Write the wallet data to the provided path.
+    /// Write the wallet data to the provided path, unencrypted.
     pub fn save(&self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
-        if path.as_ref().exists() {
+        write_new(path.as_ref(), &serde_json::to_vec(self)?)
+    }
+
+    /// Write the wallet data to the provided path, encrypted under the passphrase.
+    pub fn save_encrypted(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        passphrase: &str,
+    ) -> anyhow::Result<()> {
+        let encrypted = EncryptedKeyStore::encrypt(self, passphrase)?;
+        write_new(path.as_ref(), &serde_json::to_vec(&encrypted)?)
+    }
+
+    /// Replace the wallet data at the provided path, which must already exist,
+    /// with the unencrypted wallet data.
+    pub fn update(&self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+        replace(path.as_ref(), &serde_json::to_vec(self)?)
+    }
+
+    /// Replace the wallet data at the provided path, which must already exist,
+    /// with the wallet data encrypted under the passphrase.
+    pub fn update_encrypted(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        passphrase: &str,
+    ) -> anyhow::Result<()> {
+        let encrypted = EncryptedKeyStore::encrypt(self, passphrase)?;
+        replace(path.as_ref(), &serde_json::to_vec(&encrypted)?)
+    }
+
+    /// Check whether the wallet file at the provided path is encrypted.
+    pub fn is_encrypted(path: impl AsRef<std::path::Path>) -> anyhow::Result<bool> {
+        let data = std::fs::read(path)?;
+        Ok(serde_json::from_slice::<EncryptedKeyStore>(&data).is_ok())
+    }
+
+    /// Read the unencrypted wallet data from the provided path.
+    pub fn load(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)?;
+        if serde_json::from_slice::<EncryptedKeyStore>(&data).is_ok() {
             return Err(anyhow::anyhow!(
-                "Wallet file already exists, refusing to overwrite it"
+                "Wallet file is encrypted, a passphrase is required to load it"
             ));
         }
-        use std::io::Write;
-        let path = path.as_ref();
-        let mut file = std::fs::File::create(path)?;
-        let data = serde_json::to_vec(self)?;
-        file.write_all(&data)?;
-        Ok(())
+        serde_json::from_slice(data.as_slice()).map_err(Into::into)
     }
 
-    /// Read the wallet data from the provided path.
-    pub fn load(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
-        serde_json::from_slice(std::fs::read(path)?.as_slice()).map_err(Into::into)
+    /// Read the encrypted wallet data from the provided path, decrypting it
+    /// with the passphrase.
+    pub fn load_encrypted(
+        path: impl AsRef<std::path::Path>,
+        passphrase: &str,
+    ) -> anyhow::Result<Self> {
+        let encrypted: EncryptedKeyStore = serde_json::from_slice(&std::fs::read(path)?)
+            .map_err(|_| anyhow::anyhow!("Wallet file is not encrypted"))?;
+        encrypted.decrypt(passphrase)
     }
 
-    /// Create a new wallet.
+    /// Create a new wallet, with a single account derived from the seed phrase.
     pub fn from_seed_phrase(seed_phrase: SeedPhrase) -> Self {
-        // Currently we support a single spend authority per wallet. In the future,
-        // we can derive multiple spend seeds from a single seed phrase.
-        let spend_key = SpendKey::from_seed_phrase(seed_phrase, 0);
+        let spend_key = SpendKey::from_seed_phrase(seed_phrase.clone(), 0);
+
+        Self {
+            seed_phrase: Some(seed_phrase),
+            accounts: vec![Account {
+                name: DEFAULT_ACCOUNT_NAME.to_string(),
+                index: Some(0),
+                spend_key,
+            }],
+            active_account: DEFAULT_ACCOUNT_NAME.to_string(),
+        }
+    }
+
+    /// Create a new wallet holding a single spend key, with no seed phrase.
+    ///
+    /// Since there's no seed phrase, no further accounts can be derived.
+    pub fn from_spend_key(spend_key: SpendKey) -> Self {
+        Self {
+            seed_phrase: None,
+            accounts: vec![Account {
+                name: DEFAULT_ACCOUNT_NAME.to_string(),
+                index: None,
+                spend_key,
+            }],
+            active_account: DEFAULT_ACCOUNT_NAME.to_string(),
+        }
+    }
+
+    /// All the accounts in the wallet, in the order they were added.
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// Look up an account by name.
+    pub fn account(&self, name: &str) -> Option<&Account> {
+        self.accounts.iter().find(|account| account.name == name)
+    }
+
+    /// The currently selected account.
+    pub fn active_account(&self) -> &Account {
+        self.account(&self.active_account)
+            .expect("the active account is checked to be present when loading")
+    }
+
+    /// The spend key of the currently selected account.
+    pub fn spend_key(&self) -> &SpendKey {
+        &self.active_account().spend_key
+    }
+
+    /// Derive a new account from the wallet's seed phrase, using the next
+    /// unused derivation index.
+    pub fn add_account(&mut self, name: impl Into<String>) -> anyhow::Result<&Account> {
+        let name = name.into();
+        if self.account(&name).is_some() {
+            return Err(anyhow::anyhow!("an account named {} already exists", name));
+        }
+        let seed_phrase = self.seed_phrase.clone().ok_or_else(|| {
+            anyhow::anyhow!("this wallet has no seed phrase to derive new accounts from")
+        })?;
+
+        let index = self
+            .accounts
+            .iter()
+            .filter_map(|account| account.index)
+            .max()
+            .map_or(0, |index| index + 1);
+        self.accounts.push(Account {
+            name,
+            index: Some(index),
+            spend_key: SpendKey::from_seed_phrase(seed_phrase, index),
+        });
+
+        Ok(self.accounts.last().expect("just pushed an account"))
+    }
+
+    /// Select the account with the given name as the active account.
+    pub fn select_account(&mut self, name: &str) -> anyhow::Result<()> {
+        if self.account(name).is_none() {
+            return Err(anyhow::anyhow!("no account named {}", name));
+        }
+        self.active_account = name.to_string();
+        Ok(())
+    }
+}
+
+/// Write a new wallet file, refusing to overwrite an existing one.
+fn write_new(path: &std::path::Path, data: &[u8]) -> anyhow::Result<()> {
+    use std::io::Write;
+    if path.exists() {
+        return Err(anyhow::anyhow!(
+            "Wallet file already exists, refusing to overwrite it"
+        ));
+    }
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(data)?;
+    Ok(())
+}
+
+/// Replace an existing wallet file.
+///
+/// The data is written to a temporary file and then moved into place, so
+/// that a crash while saving can't leave a corrupted wallet behind.
+fn replace(path: &std::path::Path, data: &[u8]) -> anyhow::Result<()> {
+    use std::io::Write;
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "No wallet file exists at {}",
+            path.display()
+        ));
+    }
+    let tmp_path = path.with_extension("tmp");
+    let mut file = std::fs::File::create(&tmp_path)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+impl fmt::Debug for KeyStore {
+    // Don't print the seed phrase.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyStore")
+            .field("accounts", &self.accounts)
+            .field("active_account", &self.active_account)
+            .finish_non_exhaustive()
+    }
+}
 
-        Self { spend_key }
+mod serde_helpers {
+    use super::*;
+
+    /// Accepts both the current wallet format and the older format, which
+    /// stored a single spend key.
+    #[serde_as]
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub enum KeyStoreHelper {
+        Accounts {
+            #[serde_as(as = "Option<DisplayFromStr>")]
+            #[serde(default)]
+            seed_phrase: Option<SeedPhrase>,
+            accounts: Vec<Account>,
+            active_account: String,
+        },
+        SingleKey {
+            #[serde_as(as = "DisplayFromStr")]
+            spend_key: SpendKey,
+        },
+    }
+
+    impl TryFrom<KeyStoreHelper> for KeyStore {
+        type Error = anyhow::Error;
+
+        fn try_from(helper: KeyStoreHelper) -> anyhow::Result<Self> {
+            match helper {
+                KeyStoreHelper::Accounts {
+                    seed_phrase,
+                    accounts,
+                    active_account,
+                } => {
+                    if !accounts
+                        .iter()
+                        .any(|account| account.name == active_account)
+                    {
+                        return Err(anyhow::anyhow!(
+                            "the active account {} is not one of the wallet's accounts",
+                            active_account
+                        ));
+                    }
+                    Ok(KeyStore {
+                        seed_phrase,
+                        accounts,
+                        active_account,
+                    })
+                }
+                KeyStoreHelper::SingleKey { spend_key } => Ok(KeyStore::from_spend_key(spend_key)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn accounts_are_derived_from_the_seed_phrase() -> anyhow::Result<()> {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let phrase = seed_phrase.to_string();
+        let mut wallet = KeyStore::from_seed_phrase(seed_phrase);
+
+        let savings = wallet.add_account("savings")?.spend_key.clone();
+        assert!(wallet.add_account("savings").is_err());
+        assert_eq!(
+            savings.to_bytes().0,
+            SpendKey::from_seed_phrase(phrase.parse()?, 1).to_bytes().0
+        );
+
+        // Selecting an account changes the active spend key, and survives a
+        // round trip through the wallet file format.
+        wallet.select_account("savings")?;
+        assert!(wallet.select_account("checking").is_err());
+        let wallet: KeyStore = serde_json::from_slice(&serde_json::to_vec(&wallet)?)?;
+        assert_eq!(wallet.active_account().name, "savings");
+        assert_eq!(wallet.spend_key().to_bytes().0, savings.to_bytes().0);
+        assert_eq!(wallet.accounts().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn single_key_wallet_files_can_be_loaded() -> anyhow::Result<()> {
+        let spend_key = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let data = serde_json::json!({ "spend_key": spend_key.to_string() });
+
+        let mut wallet: KeyStore = serde_json::from_value(data)?;
+        assert_eq!(wallet.active_account().name, DEFAULT_ACCOUNT_NAME);
+        assert_eq!(wallet.spend_key().to_bytes().0, spend_key.to_bytes().0);
+        // Without a seed phrase, no further accounts can be derived.
+        assert!(wallet.add_account("savings").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_active_account_is_rejected() -> anyhow::Result<()> {
+        let wallet = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        let mut data = serde_json::to_value(&wallet)?;
+        data["active_account"] = "savings".into();
+
+        assert!(serde_json::from_value::<KeyStore>(data).is_err());
+
+        Ok(())
     }
 }
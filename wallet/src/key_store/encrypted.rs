@@ -0,0 +1,177 @@
+use anyhow::anyhow;
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, NewAead},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use zeroize::Zeroizing;
+
+use super::KeyStore;
+
+/// Parameters for deriving the wallet encryption key from a passphrase with Argon2id.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KdfParams {
+    /// Memory cost, in KiB.
+    pub m_cost: u32,
+    /// Number of iterations.
+    pub t_cost: u32,
+    /// Degree of parallelism.
+    pub p_cost: u32,
+    #[serde_as(as = "Hex")]
+    pub salt: [u8; 16],
+}
+
+impl KdfParams {
+    /// The largest memory cost accepted from a wallet file, in KiB (4 GiB).
+    pub const MAX_M_COST: u32 = 4 * 1024 * 1024;
+    /// The largest number of iterations accepted from a wallet file.
+    pub const MAX_T_COST: u32 = 64;
+    /// The largest degree of parallelism accepted from a wallet file.
+    pub const MAX_P_COST: u32 = 64;
+
+    /// Generate parameters with a fresh random salt, using 64 MiB of memory
+    /// and three passes.
+    pub fn generate() -> Self {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        Self {
+            m_cost: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+            salt,
+        }
+    }
+
+    fn derive_key(&self, passphrase: &str) -> anyhow::Result<Zeroizing<[u8; 32]>> {
+        // The parameters are read from the wallet file, so refuse ones that
+        // would make deriving the key exhaust memory or run indefinitely.
+        if self.m_cost > Self::MAX_M_COST
+            || self.t_cost > Self::MAX_T_COST
+            || self.p_cost > Self::MAX_P_COST
+        {
+            return Err(anyhow!(
+                "key derivation parameters exceed the supported maximum: {:?}",
+                (self.m_cost, self.t_cost, self.p_cost)
+            ));
+        }
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(32))
+            .map_err(|e| anyhow!("invalid key derivation parameters: {}", e))?;
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), &self.salt, key.as_mut())
+            .map_err(|e| anyhow!("could not derive wallet encryption key: {}", e))?;
+        Ok(key)
+    }
+}
+
+/// A [`KeyStore`] encrypted under a passphrase, as stored on disk.
+///
+/// The serialized key store is encrypted with ChaCha20-Poly1305, using a key
+/// derived from the passphrase with Argon2id.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EncryptedKeyStore {
+    kdf: KdfParams,
+    #[serde_as(as = "Hex")]
+    nonce: [u8; 12],
+    #[serde_as(as = "Hex")]
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedKeyStore {
+    /// Encrypt the key store under the passphrase, with freshly generated
+    /// key derivation parameters.
+    pub fn encrypt(key_store: &KeyStore, passphrase: &str) -> anyhow::Result<Self> {
+        Self::encrypt_with_params(key_store, passphrase, KdfParams::generate())
+    }
+
+    /// Encrypt the key store under the passphrase, using the given key
+    /// derivation parameters.
+    pub fn encrypt_with_params(
+        key_store: &KeyStore,
+        passphrase: &str,
+        kdf: KdfParams,
+    ) -> anyhow::Result<Self> {
+        let key = kdf.derive_key(passphrase)?;
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+
+        let plaintext = Zeroizing::new(serde_json::to_vec(key_store)?);
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| anyhow!("could not encrypt wallet"))?;
+
+        Ok(Self {
+            kdf,
+            nonce,
+            ciphertext,
+        })
+    }
+
+    /// Decrypt the key store with the passphrase.
+    pub fn decrypt(&self, passphrase: &str) -> anyhow::Result<KeyStore> {
+        let key = self.kdf.derive_key(passphrase)?;
+        let plaintext = Zeroizing::new(
+            ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+                .decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+                .map_err(|_| anyhow!("could not decrypt wallet: incorrect passphrase?"))?,
+        );
+        serde_json::from_slice(&plaintext).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::keys::SeedPhrase;
+
+    use super::*;
+
+    #[test]
+    fn encrypted_key_store_round_trip() -> anyhow::Result<()> {
+        let wallet = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        // Use cheap parameters so that the test runs quickly.
+        let kdf = KdfParams {
+            m_cost: 64,
+            t_cost: 1,
+            ..KdfParams::generate()
+        };
+
+        let encrypted = EncryptedKeyStore::encrypt_with_params(&wallet, "hunter2", kdf)?;
+        let encrypted: EncryptedKeyStore =
+            serde_json::from_slice(&serde_json::to_vec(&encrypted)?)?;
+
+        let decrypted = encrypted.decrypt("hunter2")?;
+        assert_eq!(
+            decrypted.spend_key().to_bytes().0,
+            wallet.spend_key().to_bytes().0
+        );
+        assert!(encrypted.decrypt("hunter3").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn excessive_kdf_params_are_rejected() -> anyhow::Result<()> {
+        let wallet = KeyStore::from_seed_phrase(SeedPhrase::generate(OsRng));
+        let kdf = KdfParams {
+            m_cost: 64,
+            t_cost: 1,
+            ..KdfParams::generate()
+        };
+        let mut encrypted = EncryptedKeyStore::encrypt_with_params(&wallet, "hunter2", kdf)?;
+
+        encrypted.kdf.m_cost = KdfParams::MAX_M_COST + 1;
+        assert!(encrypted.decrypt("hunter2").is_err());
+        encrypted.kdf.m_cost = 64;
+        encrypted.kdf.t_cost = u32::MAX;
+        assert!(encrypted.decrypt("hunter2").is_err());
+
+        Ok(())
+    }
+}
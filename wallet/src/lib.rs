@@ -1,6 +1,6 @@
 mod build;
 mod key_store;
 pub use build::build_transaction;
-pub use key_store::KeyStore;
+pub use key_store::{Account, EncryptedKeyStore, KdfParams, KeyStore, DEFAULT_ACCOUNT_NAME};
 
 pub mod plan;
@@ -5,14 +5,16 @@ use anyhow::{Context, Result};
 use penumbra_component::stake::validator;
 use penumbra_component::{governance::proposal::Outcome, stake::rate::RateData};
 use penumbra_crypto::{
-    keys::AddressIndex, transaction::Fee, Address, Amount, FullViewingKey, Value,
+    keys::AddressIndex, stake::DelegationToken, transaction::Fee, Address, Amount, FullViewingKey,
+    Value,
 };
 use penumbra_proto::{
     client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient,
     view::v1alpha1::NotesRequest,
 };
+use penumbra_tct as tct;
 use penumbra_transaction::{
-    action::{Proposal, ValidatorVote},
+    action::{Proposal, ValidatorVote, Vote},
     plan::TransactionPlan,
 };
 use penumbra_view::{SpendableNoteRecord, ViewClient};
@@ -62,6 +64,74 @@ where
         .context("can't build validator vote plan")
 }
 
+/// Generate a new transaction plan voting on a proposal as a delegator.
+///
+/// Every delegation note that was committed before `start_position` and still unspent at
+/// `start_height`, when voting on the proposal started, is used to vote.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip(fvk, view, rng))]
+pub async fn delegator_vote<V, R>(
+    fvk: &FullViewingKey,
+    view: &mut V,
+    rng: R,
+    proposal_id: u64,
+    vote: Vote,
+    start_position: tct::Position,
+    start_height: u64,
+    fee: Fee,
+    source_address: AddressIndex,
+) -> Result<TransactionPlan>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    let asset_cache = view.assets().await?;
+    let notes = view
+        .notes(NotesRequest {
+            account_id: Some(fvk.hash().into()),
+            include_spent: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let mut planner = Planner::new(rng);
+    planner.fee(fee);
+
+    let mut has_votes = false;
+    for record in notes {
+        let is_delegation_note = asset_cache
+            .get(&record.note.asset_id())
+            .map(|denom| DelegationToken::try_from(denom.clone()).is_ok())
+            .unwrap_or(false);
+        let unspent_at_start = record
+            .height_spent
+            .map_or(true, |height_spent| height_spent >= start_height);
+
+        if is_delegation_note && unspent_at_start && record.position < start_position {
+            planner.delegator_vote(
+                proposal_id,
+                start_position,
+                vote,
+                record.note,
+                record.position,
+            );
+            has_votes = true;
+        }
+    }
+
+    if !has_votes {
+        anyhow::bail!(
+            "no delegation notes were held when voting on proposal {} started",
+            proposal_id
+        );
+    }
+
+    planner
+        .plan(view, fvk, source_address)
+        .await
+        .context("can't build delegator vote plan")
+}
+
 /// Generate a new transaction plan delegating stake
 #[instrument(skip(fvk, view, rng, rate_data, unbonded_amount, fee, source_address))]
 pub async fn delegate<V, R>(
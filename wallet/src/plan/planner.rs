@@ -13,7 +13,14 @@ use penumbra_component::{
 use penumbra_crypto::{
     asset::Amount,
     asset::Denom,
-    dex::{swap::SwapPlaintext, TradingPair},
+    dex::{
+        lp::{
+            position::{self, Position},
+            Reserves,
+        },
+        swap::SwapPlaintext,
+        TradingPair,
+    },
     keys::AddressIndex,
     transaction::Fee,
     Address, FullViewingKey, Note, Value,
@@ -21,9 +28,13 @@ use penumbra_crypto::{
 use penumbra_proto::view::v1alpha1::NotesRequest;
 use penumbra_tct as tct;
 use penumbra_transaction::{
-    action::{Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote},
+    action::{
+        Ics20Withdrawal, PositionClose, PositionOpen, Proposal, ProposalDepositClaim,
+        ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote,
+    },
     plan::{
-        ActionPlan, MemoPlan, OutputPlan, SpendPlan, SwapClaimPlan, SwapPlan, TransactionPlan,
+        ActionPlan, DelegatorVotePlan, MemoPlan, OutputPlan, PositionRewardClaimPlan,
+        PositionWithdrawPlan, SpendPlan, SwapClaimPlan, SwapPlan, TransactionPlan,
         UndelegateClaimPlan,
     },
 };
@@ -174,6 +185,78 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         Ok(self)
     }
 
+    /// Withdraw funds to a counterparty chain over IBC.
+    ///
+    /// The withdrawn value will be spent from notes in the transaction.
+    #[instrument(skip(self))]
+    pub fn ics20_withdrawal(&mut self, withdrawal: Ics20Withdrawal) -> &mut Self {
+        self.action(ActionPlan::Ics20Withdrawal(withdrawal));
+        self
+    }
+
+    /// Open a liquidity position in the order book.
+    ///
+    /// The initial reserves will be spent from notes in the transaction, and
+    /// an opened position NFT will be produced, to be used to manage the
+    /// position later.
+    #[instrument(skip(self))]
+    pub fn position_open(&mut self, position: Position, initial_reserves: Reserves) -> &mut Self {
+        self.action(ActionPlan::PositionOpen(PositionOpen {
+            position,
+            initial_reserves,
+        }));
+        self
+    }
+
+    /// Close a liquidity position in the order book.
+    ///
+    /// This consumes the position's opened NFT and produces a closed NFT,
+    /// which can be used to withdraw the position's reserves once it has
+    /// been deactivated.
+    #[instrument(skip(self))]
+    pub fn position_close(&mut self, position_id: position::Id) -> &mut Self {
+        self.action(ActionPlan::PositionClose(PositionClose { position_id }));
+        self
+    }
+
+    /// Withdraw the final reserves of a closed liquidity position.
+    ///
+    /// The `reserves` must be the position's reserves as recorded on-chain,
+    /// since the chain will reject a withdrawal of any other amount.
+    #[instrument(skip(self))]
+    pub fn position_withdraw(
+        &mut self,
+        position_id: position::Id,
+        pair: TradingPair,
+        reserves: Reserves,
+    ) -> &mut Self {
+        self.action(ActionPlan::PositionWithdraw(PositionWithdrawPlan {
+            reserves,
+            position_id,
+            pair,
+        }));
+        self
+    }
+
+    /// Claim the rewards accumulated by a withdrawn liquidity position.
+    ///
+    /// The `rewards` must be the position's rewards as recorded on-chain,
+    /// since the chain will reject a claim of any other amount.
+    #[instrument(skip(self))]
+    pub fn position_reward_claim(
+        &mut self,
+        position_id: position::Id,
+        pair: TradingPair,
+        rewards: Reserves,
+    ) -> &mut Self {
+        self.action(ActionPlan::PositionRewardClaim(PositionRewardClaimPlan {
+            rewards,
+            position_id,
+            pair,
+        }));
+        self
+    }
+
     /// Add an output note from this transaction.
     ///
     /// Any unused output value will be redirected back to the originating address as change notes
@@ -212,6 +295,29 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction, moving `delegation_amount` of
+    /// delegation tokens from the validator described by `from_rate_data` to
+    /// the one described by `to_rate_data`.
+    ///
+    /// The stake undelegated from the first validator is still subject to the
+    /// unbonding period, so the new delegation is funded with the same amount
+    /// of spendable stake, and the unbonding tokens for the old delegation can
+    /// be claimed once `end_epoch_index` is reached.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+        end_epoch_index: u64,
+    ) -> &mut Self {
+        let undelegation = from_rate_data.build_undelegate(delegation_amount, end_epoch_index);
+        let delegation = to_rate_data.build_delegate(undelegation.unbonded_amount.into());
+        self.action(undelegation.into());
+        self.action(delegation.into());
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -269,6 +375,32 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Cast a delegator vote in this transaction, using the given positioned delegation note.
+    ///
+    /// The note must have been committed before `start_position`, and unspent when voting on the
+    /// proposal started.
+    #[instrument(skip(self))]
+    pub fn delegator_vote(
+        &mut self,
+        proposal: u64,
+        start_position: tct::Position,
+        vote: Vote,
+        note: Note,
+        position: tct::Position,
+    ) -> &mut Self {
+        let vote = DelegatorVotePlan::new(
+            &mut self.rng,
+            proposal,
+            start_position,
+            vote,
+            note,
+            position,
+        )
+        .into();
+        self.action(vote);
+        self
+    }
+
     fn action(&mut self, action: ActionPlan) -> &mut Self {
         // Track the contribution of the action to the transaction's balance
         self.balance += action.balance();